@@ -8,7 +8,7 @@ except Exception as e:
     print("Failed to load PARQUET file")
     exit(1)
 
-expected_columns = {'id', 'first_name', 'last_name', 'company_email', 'created', 'connection', 'code', 'code_between_5_and_15', 'is_subscribed', 'score', 'percentage', 'constant_string', 'constant_string_list', 'constant_string_weighted_list', 'external_data'}
+expected_columns = {'id', 'first_name', 'last_name', 'company_email', 'created', 'connection', 'code', 'code_between_5_and_15', 'is_subscribed', 'score', 'event_id', 'amount_cents', 'orders', 'load', 'price', 'percentage', 'constant_string', 'constant_string_list', 'constant_string_weighted_list', 'external_data'}
 if not expected_columns.issubset(data.columns):
     print("Issues with the colums in the parquet file")
     print("Expected:", expected_columns)
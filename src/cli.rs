@@ -21,5 +21,9 @@ pub enum Commands {
         /// Path to YAML config file(s)
         #[arg(required = true)]
         path_to_config: Vec<PathBuf>,
+
+        /// Write the generated data to standard output instead of files
+        #[arg(long, action)]
+        stdout: bool,
     },
 }
@@ -4,8 +4,19 @@ use log::warn;
 use yaml_rust::{Yaml, YamlLoader};
 
 use crate::errors::FakeLakeError;
+use crate::generate::compression::OutputCompression;
+use crate::generate::csv::options::CsvOptions;
+use crate::generate::fixed_width::options::FixedWidthOptions;
+use crate::generate::json::options::JsonOptions;
+use crate::generate::parquet::options::ParquetOptions;
+use crate::generate::sql::SqlDialect;
+use crate::generate::xml::options::XmlOptions;
 use crate::options::presence;
 use crate::providers::provider::{CorruptedProvider, Provider, ProviderBuilder};
+use crate::providers::timeseries::timestamp::with_timestamp_column;
+
+/// output_name writing the generated data to standard output
+pub const STDOUT_OUTPUT_NAME: &str = "-";
 
 #[derive(Debug)]
 pub struct Config {
@@ -14,7 +25,18 @@ pub struct Config {
 }
 
 impl Config {
+    pub fn is_stdout(&self) -> bool {
+        match &self.info {
+            Some(info) => info.output_name.as_deref() == Some(STDOUT_OUTPUT_NAME),
+            None => false,
+        }
+    }
+
     pub fn get_output_file_name(&self, extension: &str) -> String {
+        if self.is_stdout() {
+            return STDOUT_OUTPUT_NAME.to_string();
+        }
+
         let file_name = match &self.info {
             Some(info) => match &info.output_name {
                 Some(name) => name,
@@ -23,7 +45,7 @@ impl Config {
             None => "output",
         };
 
-        if file_name.contains(extension) {
+        if !extension.is_empty() && file_name.contains(extension) {
             warn!("output_name parameter contains the file extension. It has not been added a second time.");
             return file_name.to_string();
         }
@@ -31,6 +53,21 @@ impl Config {
         format!("{}{}", file_name, extension)
     }
 
+    pub fn get_partition_by(&self) -> Vec<String> {
+        match &self.info {
+            Some(info) => info.partition_by.clone().unwrap_or_default(),
+            None => vec![],
+        }
+    }
+
+    pub fn get_max_rows_per_file(&self) -> Option<u64> {
+        self.info.as_ref().and_then(|info| info.max_rows_per_file)
+    }
+
+    pub fn get_max_bytes_per_file(&self) -> Option<u64> {
+        self.info.as_ref().and_then(|info| info.max_bytes_per_file)
+    }
+
     pub fn get_number_of_rows(&self) -> u32 {
         match &self.info {
             Some(info) => info.rows.unwrap_or(1_000_000),
@@ -44,6 +81,8 @@ pub struct Column {
     pub name: String,
     pub provider: Box<dyn Provider>,
     pub presence: Box<dyn presence::Presence>,
+    /// Keys of the value in nested outputs, instead of the dotted name
+    pub path: Option<Vec<String>>,
 }
 
 impl Clone for Column {
@@ -52,6 +91,7 @@ impl Clone for Column {
             name: self.name.clone(),
             provider: self.provider.clone_box(),
             presence: self.presence.clone_box(),
+            path: self.path.clone(),
         }
     }
 }
@@ -64,6 +104,14 @@ impl Column {
         self.presence.can_be_null()
     }
 
+    /// Keys of the value in nested outputs, the name is split on dots without explicit path
+    pub fn get_path(&self) -> Vec<&str> {
+        match &self.path {
+            Some(path) => path.iter().map(|key| key.as_str()).collect(),
+            None => self.name.split('.').collect(),
+        }
+    }
+
     pub fn generate_columns(parsed_yaml: &[Yaml]) -> Result<Vec<Column>, FakeLakeError> {
         let mut columns = Vec::new();
 
@@ -104,8 +152,37 @@ impl Column {
 
             let presence = presence::new_from_yaml(column);
 
+            // path could be a list of keys or a single key
+            let path = match &column["path"] {
+                Yaml::Array(keys) => {
+                    let path: Vec<String> = keys
+                        .iter()
+                        .filter_map(|key| key.as_str().map(|key| key.to_string()))
+                        .collect();
+                    if path.is_empty() || path.len() != keys.len() {
+                        warn!(
+                            "path of column {} should be a list of keys. It is ignored.",
+                            name
+                        );
+                        None
+                    } else {
+                        Some(path)
+                    }
+                }
+                Yaml::String(key) => Some(vec![key.to_string()]),
+                Yaml::BadValue => None,
+                _ => {
+                    warn!(
+                        "path of column {} should be a list of keys. It is ignored.",
+                        name
+                    );
+                    None
+                }
+            };
+
+            let timeline_column = with_timestamp_column(column, yaml_columns);
             let provider: Box<dyn Provider> =
-                match ProviderBuilder::get_corresponding_provider(provider, column) {
+                match ProviderBuilder::get_corresponding_provider(provider, &timeline_column) {
                     Ok(value) => CorruptedProvider::new_from_yaml(column, value),
                     Err(e) => return Err(FakeLakeError::BadYAMLFormat(e.to_string())),
                 };
@@ -114,6 +191,7 @@ impl Column {
                 name: name.to_string(),
                 provider,
                 presence,
+                path,
             };
             columns.push(column);
         }
@@ -124,9 +202,22 @@ impl Column {
 
 #[derive(Debug, PartialEq)]
 pub enum OutputType {
-    Parquet(),
-    Csv(u8),
-    Json(bool),
+    Parquet(ParquetOptions),
+    Csv(CsvOptions),
+    Json(JsonOptions),
+    Avro(),
+    Orc(bool),
+    Arrow(bool),
+    Sql(SqlDialect, Option<String>),
+    PgCopy(bool),
+    Xml(XmlOptions),
+    FixedWidth(FixedWidthOptions),
+    Xlsx(Option<String>),
+    Sqlite(Option<String>),
+    Delta(ParquetOptions),
+    Iceberg(ParquetOptions),
+    Protobuf(Option<String>),
+    MessagePack(),
 }
 
 #[derive(Debug)]
@@ -138,6 +229,12 @@ pub struct Info {
     pub rows: Option<u32>,
     /// Seed for deterministic random generation
     pub seed: Option<u64>,
+    /// Columns used to write rows in Hive-style partition directories
+    pub partition_by: Option<Vec<String>>,
+    /// Rows are split in multiple files of at most this number of rows
+    pub max_rows_per_file: Option<u64>,
+    /// Rows are split in multiple files of approximately this size
+    pub max_bytes_per_file: Option<u64>,
 }
 
 impl Info {
@@ -159,33 +256,77 @@ impl Info {
             .as_str()
             .map(|format| format.to_string())
         {
-            Some(value) if value == "parquet" => Some(OutputType::Parquet()),
+            Some(value) if value == "parquet" => Some(OutputType::Parquet(
+                ParquetOptions::new_from_yaml(&section_info["parquet"]),
+            )),
+            Some(value) if value == "delta" => Some(OutputType::Delta(
+                ParquetOptions::new_from_yaml(&section_info["parquet"]),
+            )),
+            Some(value) if value == "iceberg" => Some(OutputType::Iceberg(
+                ParquetOptions::new_from_yaml(&section_info["parquet"]),
+            )),
             Some(value) if value == "csv" => {
-                let delimiter = match section_info["delimiter"].as_str() {
-                    Some(delimiter_param) => {
-                        match delimiter_param.len() {
-                            value if value > 1 => {
-                                warn!("Delimiter given for CSV should be one char. Default ',' is taken.");
-                                b','
-                            }
-                            1 => *delimiter_param.as_bytes().first().unwrap(),
-                            _ => b',',
-                        }
-                    }
-                    _ => b',',
-                };
-                Some(OutputType::Csv(delimiter))
+                Some(OutputType::Csv(CsvOptions::new_from_yaml(section_info)))
             }
             Some(value) if value == "json" => {
-                let wrap_up = match section_info["wrap_up"] {
+                Some(OutputType::Json(JsonOptions::new_from_yaml(section_info)))
+            }
+            Some(value) if value == "avro" => Some(OutputType::Avro()),
+            Some(value) if value == "orc" => {
+                let strict_types = match section_info["strict_types"] {
                     Yaml::Boolean(value) => value,
                     Yaml::BadValue => false,
                     _ => {
-                        warn!("Wrap up should be a bool. Default value 'false' is taken.");
+                        warn!("ORC strict_types should be a bool. Default value 'false' is taken.");
                         false
                     }
                 };
-                Some(OutputType::Json(wrap_up))
+                Some(OutputType::Orc(strict_types))
+            }
+            Some(value) if value == "arrow" => Some(OutputType::Arrow(false)),
+            Some(value) if value == "arrow_stream" => Some(OutputType::Arrow(true)),
+            Some(value) if value == "sql" => {
+                let dialect = match section_info["dialect"].as_str() {
+                    Some(dialect_param) => match SqlDialect::from_name(dialect_param) {
+                        Some(dialect) => dialect,
+                        None => {
+                            warn!("Dialect should be postgresql, mysql or sqlite. Default 'postgresql' is taken.");
+                            SqlDialect::PostgreSql
+                        }
+                    },
+                    None => SqlDialect::PostgreSql,
+                };
+                let table_name = section_info["table_name"]
+                    .as_str()
+                    .map(|name| name.to_string());
+                Some(OutputType::Sql(dialect, table_name))
+            }
+            Some(value) if value == "pg_copy" => Some(OutputType::PgCopy(false)),
+            Some(value) if value == "pg_copy_binary" => Some(OutputType::PgCopy(true)),
+            Some(value) if value == "fixed_width" => Some(OutputType::FixedWidth(
+                FixedWidthOptions::new_from_yaml(section_info),
+            )),
+            Some(value) if value == "sqlite" => {
+                let table_name = section_info["table_name"]
+                    .as_str()
+                    .map(|name| name.to_string());
+                Some(OutputType::Sqlite(table_name))
+            }
+            Some(value) if value == "xlsx" => {
+                let sheet_name = section_info["sheet_name"]
+                    .as_str()
+                    .map(|name| name.to_string());
+                Some(OutputType::Xlsx(sheet_name))
+            }
+            Some(value) if value == "protobuf" => {
+                let message_name = section_info["message_name"]
+                    .as_str()
+                    .map(|name| name.to_string());
+                Some(OutputType::Protobuf(message_name))
+            }
+            Some(value) if value == "msgpack" => Some(OutputType::MessagePack()),
+            Some(value) if value == "xml" => {
+                Some(OutputType::Xml(XmlOptions::new_from_yaml(section_info)))
             }
             _ => None,
         };
@@ -208,31 +349,129 @@ impl Info {
             },
         };
 
+        // partition_by could be a list of column names or a single column name
+        let partition_by = match &section_info["partition_by"] {
+            Yaml::Array(values) => {
+                let mut columns = Vec::new();
+                for value in values {
+                    match value.as_str() {
+                        Some(column) => columns.push(column.to_string()),
+                        None => warn!(
+                            "partition_by should only contain column names. {:?} is ignored.",
+                            value
+                        ),
+                    }
+                }
+                Some(columns)
+            }
+            Yaml::String(column) => Some(vec![column.to_string()]),
+            Yaml::BadValue => None,
+            _ => {
+                warn!("partition_by should be a list of column names. No partitioning is done.");
+                None
+            }
+        };
+
+        let max_rows_per_file = parse_file_limit(&section_info["max_rows_per_file"], false);
+        let max_bytes_per_file = parse_file_limit(&section_info["max_bytes_per_file"], true);
+
         Ok(Info {
             output_name,
             output_format,
             rows,
             seed,
+            partition_by,
+            max_rows_per_file,
+            max_bytes_per_file,
         })
     }
 }
 
+/// Positive integer could be i64 or str (i64 with _ separators).
+/// With allow_units, sizes can also end with a KB, MB or GB unit.
+pub fn parse_positive_integer(value: &Yaml, allow_units: bool) -> Option<u64> {
+    let integer = match value {
+        Yaml::Integer(integer) => u64::try_from(*integer).ok(),
+        Yaml::String(integer) => {
+            let integer = integer.replace('_', "").trim().to_uppercase();
+            let (number, unit) = match allow_units {
+                true => match integer.find(|c: char| !c.is_ascii_digit()) {
+                    Some(position) => integer.split_at(position),
+                    None => (integer.as_str(), ""),
+                },
+                false => (integer.as_str(), ""),
+            };
+            let multiplier = match unit.trim() {
+                "" | "B" => Some(1),
+                "KB" => Some(1024),
+                "MB" => Some(1024 * 1024),
+                "GB" => Some(1024 * 1024 * 1024),
+                _ => None,
+            };
+            match (number.parse::<u64>().ok(), multiplier) {
+                (Some(number), Some(multiplier)) => number.checked_mul(multiplier),
+                _ => None,
+            }
+        }
+        _ => None,
+    };
+
+    match integer {
+        Some(0) => None,
+        integer => integer,
+    }
+}
+
+pub fn parse_compression(value: &Yaml) -> Option<OutputCompression> {
+    match value.as_str() {
+        Some("none") => None,
+        Some(name) => match OutputCompression::from_name(name) {
+            Some(compression) => Some(compression),
+            None => {
+                warn!("Compression should be gzip, zstd, bzip2 or xz. The file is not compressed.");
+                None
+            }
+        },
+        None if value.is_badvalue() => None,
+        None => {
+            warn!("Compression should be gzip, zstd, bzip2 or xz. The file is not compressed.");
+            None
+        }
+    }
+}
+
+fn parse_file_limit(value: &Yaml, is_size: bool) -> Option<u64> {
+    if value.is_badvalue() {
+        return None;
+    }
+
+    let limit = parse_positive_integer(value, is_size);
+    if limit.is_none() {
+        warn!(
+            "File limit {:?} should be a positive integer. The output is not split.",
+            value
+        );
+    }
+    limit
+}
+
 pub fn get_config_from_string(file_content: String) -> Result<Config, FakeLakeError> {
     let parsed_yaml = match YamlLoader::load_from_str(&file_content) {
         Ok(docs) => docs,
         Err(e) => return Err(FakeLakeError::BadYAMLFormat(e.to_string())),
     };
 
+    let info = Info::parse_info_section(&parsed_yaml)?;
+
+    // Initialize the global RNG with the seed from the config,
+    // before the columns as some providers draw their own seed
+    crate::rng::initialize_rng(info.seed);
+
     let columns = match Column::generate_columns(&parsed_yaml) {
         Ok(value) => value,
         Err(e) => return Err(FakeLakeError::BadYAMLFormat(e.to_string())),
     };
 
-    let info = Info::parse_info_section(&parsed_yaml).unwrap();
-
-    // Initialize the global RNG with the seed from the config
-    crate::rng::initialize_rng(info.seed);
-
     let config = Config {
         columns,
         info: Some(info),
@@ -288,6 +527,7 @@ mod tests {
             name: "Testing column".to_string(),
             provider,
             presence,
+            path: None,
         }
     }
 
@@ -404,6 +644,31 @@ mod tests {
         expecting_ok(&columns);
     }
 
+    #[test]
+    fn given_dotted_name_or_path_should_return_keys() {
+        let yaml = "
+        columns:
+            - name: address.city
+              provider: Increment.integer
+            - name: zip
+              provider: Increment.integer
+              path: [address, zip_code]
+            - name: version.number
+              provider: Increment.integer
+              path: version.number
+            - name: id
+              provider: Increment.integer
+              path: 1
+        ";
+        let columns = generate_columns_from_yaml(yaml).unwrap();
+        assert_eq!(columns[0].path, None);
+        assert_eq!(columns[0].get_path(), vec!["address", "city"]);
+        assert_eq!(columns[1].get_path(), vec!["address", "zip_code"]);
+        assert_eq!(columns[2].get_path(), vec!["version.number"]);
+        assert_eq!(columns[3].path, None);
+        assert_eq!(columns[3].get_path(), vec!["id"]);
+    }
+
     fn generate_info_from_yaml(yaml_str: &str) -> Result<Info, FakeLakeError> {
         let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
 
@@ -470,7 +735,10 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Parquet()));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Parquet(ParquetOptions::default()))
+        );
         assert_eq!(info.rows, None);
     }
 
@@ -484,7 +752,10 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Csv(b',')));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Csv(CsvOptions::default()))
+        );
         assert_eq!(info.rows, None);
     }
 
@@ -499,7 +770,13 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Csv(b'|')));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Csv(CsvOptions {
+                delimiter: b'|',
+                ..Default::default()
+            }))
+        );
         assert_eq!(info.rows, None);
     }
 
@@ -514,7 +791,10 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Csv(b',')));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Csv(CsvOptions::default()))
+        );
         assert_eq!(info.rows, None);
     }
 
@@ -529,7 +809,10 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Csv(b',')));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Csv(CsvOptions::default()))
+        );
         assert_eq!(info.rows, None);
     }
 
@@ -543,7 +826,10 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Json(false)));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Json(JsonOptions::default()))
+        );
         assert_eq!(info.rows, None);
     }
 
@@ -558,7 +844,13 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Json(true)));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Json(JsonOptions {
+                wrap_up: true,
+                ..Default::default()
+            }))
+        );
         assert_eq!(info.rows, None);
     }
 
@@ -573,10 +865,353 @@ mod tests {
         expecting_ok(&info);
         let info = &info.unwrap();
         assert_eq!(info.output_name, None);
-        assert_eq!(info.output_format, Some(OutputType::Json(false)));
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Json(JsonOptions::default()))
+        );
         assert_eq!(info.rows, None);
     }
 
+    #[test]
+    fn given_csv_and_json_formats_with_compression_should_use_compression() {
+        let yaml = "
+        info:
+            output_format: csv
+            compression: gzip
+        ";
+        let info = generate_info_from_yaml(yaml).unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Csv(CsvOptions {
+                compression: Some(OutputCompression::Gzip),
+                ..Default::default()
+            }))
+        );
+
+        let yaml = "
+        info:
+            output_format: json
+            compression: zstd
+        ";
+        let info = generate_info_from_yaml(yaml).unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Json(JsonOptions {
+                compression: Some(OutputCompression::Zstd),
+                ..Default::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn given_unknown_compression_should_not_compress() {
+        let yaml = "
+        info:
+            output_format: csv
+            compression: rar
+        ";
+        let info = generate_info_from_yaml(yaml).unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Csv(CsvOptions::default()))
+        );
+    }
+
+    #[test]
+    fn given_avro_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: avro
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.output_name, None);
+        assert_eq!(info.output_format, Some(OutputType::Avro()));
+        assert_eq!(info.rows, None);
+    }
+
+    #[test]
+    fn given_orc_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: orc
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.output_name, None);
+        assert_eq!(info.output_format, Some(OutputType::Orc(false)));
+        assert_eq!(info.rows, None);
+    }
+
+    #[test]
+    fn given_orc_format_with_strict_types_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: orc
+            strict_types: true
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        assert_eq!(info.unwrap().output_format, Some(OutputType::Orc(true)));
+
+        let yaml = "
+        info:
+            output_format: orc
+            strict_types: yes please
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        assert_eq!(info.unwrap().output_format, Some(OutputType::Orc(false)));
+    }
+
+    #[test]
+    fn given_arrow_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: arrow
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.output_name, None);
+        assert_eq!(info.output_format, Some(OutputType::Arrow(false)));
+        assert_eq!(info.rows, None);
+    }
+
+    #[test]
+    fn given_arrow_stream_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: arrow_stream
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.output_name, None);
+        assert_eq!(info.output_format, Some(OutputType::Arrow(true)));
+        assert_eq!(info.rows, None);
+    }
+
+    #[test]
+    fn given_sql_format_should_use_default_dialect() {
+        let yaml = "
+        info:
+            output_format: sql
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Sql(SqlDialect::PostgreSql, None))
+        );
+    }
+
+    #[test]
+    fn given_sql_format_with_dialect_and_table_name_should_use_them() {
+        let yaml = "
+        info:
+            output_format: sql
+            dialect: mysql
+            table_name: users
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Sql(
+                SqlDialect::MySql,
+                Some("users".to_string())
+            ))
+        );
+    }
+
+    #[test]
+    fn given_sql_format_with_unknown_dialect_should_use_default_dialect() {
+        let yaml = "
+        info:
+            output_format: sql
+            dialect: oracle
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Sql(SqlDialect::PostgreSql, None))
+        );
+    }
+
+    #[test]
+    fn given_pg_copy_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: pg_copy
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.output_format, Some(OutputType::PgCopy(false)));
+    }
+
+    #[test]
+    fn given_pg_copy_binary_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: pg_copy_binary
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.output_format, Some(OutputType::PgCopy(true)));
+    }
+
+    #[test]
+    fn given_fixed_width_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: fixed_width
+        ";
+        let info = generate_info_from_yaml(yaml).unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::FixedWidth(FixedWidthOptions::default()))
+        );
+
+        let yaml = "
+        info:
+            output_format: fixed_width
+            overflow: truncate
+            columns:
+                id:
+                    width: 5
+        ";
+        let info = generate_info_from_yaml(yaml).unwrap();
+        match &info.output_format {
+            Some(OutputType::FixedWidth(options)) => {
+                assert!(options.truncate);
+                assert_eq!(options.get_column_width("id").unwrap().width, 5);
+            }
+            _ => panic!("Should be fixed width"),
+        }
+    }
+
+    #[test]
+    fn given_delta_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: delta
+            parquet:
+                compression: zstd
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        match &info.output_format {
+            Some(OutputType::Delta(options)) => assert_ne!(options, &ParquetOptions::default()),
+            _ => panic!("Should be delta"),
+        }
+    }
+
+    #[test]
+    fn given_iceberg_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: iceberg
+            parquet:
+                compression: zstd
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        match &info.output_format {
+            Some(OutputType::Iceberg(options)) => {
+                assert_ne!(options, &ParquetOptions::default())
+            }
+            _ => panic!("Should be iceberg"),
+        }
+    }
+
+    #[test]
+    fn given_protobuf_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: protobuf
+            message_name: User
+        ";
+        let info = generate_info_from_yaml(yaml).unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Protobuf(Some("User".to_string())))
+        );
+    }
+
+    #[test]
+    fn given_msgpack_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: msgpack
+        ";
+        let info = generate_info_from_yaml(yaml).unwrap();
+        assert_eq!(info.output_format, Some(OutputType::MessagePack()));
+    }
+
+    #[test]
+    fn given_sqlite_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: sqlite
+            table_name: users
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Sqlite(Some("users".to_string())))
+        );
+    }
+
+    #[test]
+    fn given_xlsx_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: xlsx
+            sheet_name: users
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Xlsx(Some("users".to_string())))
+        );
+    }
+
+    #[test]
+    fn given_xml_format_should_config_return_in_output_format() {
+        let yaml = "
+        info:
+            output_format: xml
+            row_element: user
+            attributes: [id]
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(
+            info.output_format,
+            Some(OutputType::Xml(XmlOptions {
+                row_element: "user".to_string(),
+                attributes: vec!["id".to_string()],
+                ..Default::default()
+            }))
+        );
+    }
+
     #[test]
     fn given_rows_int_should_config_return_in_rows() {
         let yaml = "
@@ -619,6 +1254,108 @@ mod tests {
         assert_eq!(info.rows, None);
     }
 
+    #[test]
+    fn given_partition_by_list_should_config_return_in_partition_by() {
+        let yaml = "
+        info:
+            partition_by: [country, day]
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(
+            info.partition_by,
+            Some(vec!["country".to_string(), "day".to_string()])
+        );
+    }
+
+    #[test]
+    fn given_partition_by_str_should_config_return_in_partition_by() {
+        let yaml = "
+        info:
+            partition_by: country
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.partition_by, Some(vec!["country".to_string()]));
+    }
+
+    #[test]
+    fn given_invalid_partition_by_should_config_return_none_partition_by() {
+        let yaml = "
+        info:
+            partition_by: 12
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.partition_by, None);
+    }
+
+    #[test]
+    fn given_max_rows_per_file_should_config_return_in_max_rows_per_file() {
+        let yaml = "
+        info:
+            max_rows_per_file: 1_000
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.max_rows_per_file, Some(1000));
+        assert_eq!(info.max_bytes_per_file, None);
+    }
+
+    #[test]
+    fn given_max_bytes_per_file_should_config_return_in_max_bytes_per_file() {
+        let yaml = "
+        info:
+            max_bytes_per_file: 2048
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        assert_eq!(info.unwrap().max_bytes_per_file, Some(2048));
+    }
+
+    #[test]
+    fn given_max_bytes_per_file_with_unit_should_config_return_in_bytes() {
+        for (size, expected) in [
+            ("12KB", 12 * 1024),
+            ("128 MB", 128 * 1024 * 1024),
+            ("1gb", 1024 * 1024 * 1024),
+        ] {
+            let yaml = format!("info:\n    max_bytes_per_file: {}", size);
+            let info = generate_info_from_yaml(&yaml);
+            expecting_ok(&info);
+            assert_eq!(info.unwrap().max_bytes_per_file, Some(expected));
+        }
+    }
+
+    #[test]
+    fn given_invalid_file_limits_should_config_return_none() {
+        let yaml = "
+        info:
+            max_rows_per_file: 0
+            max_bytes_per_file: 12TB
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        let info = &info.unwrap();
+        assert_eq!(info.max_rows_per_file, None);
+        assert_eq!(info.max_bytes_per_file, None);
+    }
+
+    #[test]
+    fn given_unit_in_max_rows_per_file_should_config_return_none() {
+        let yaml = "
+        info:
+            max_rows_per_file: 10KB
+        ";
+        let info = generate_info_from_yaml(yaml);
+        expecting_ok(&info);
+        assert_eq!(info.unwrap().max_rows_per_file, None);
+    }
+
     // get_config_from_string
     #[test]
     fn given_not_yaml_should_return_err() {
@@ -758,6 +1495,62 @@ mod tests {
         );
     }
 
+    // get_partition_by
+    #[test]
+    fn given_no_info_should_return_no_partition() {
+        let file_content = "
+        columns:
+            - name: id
+              provider: Increment.integer
+        "
+        .to_string();
+        let config = get_config_from_string(file_content).unwrap();
+        assert!(config.get_partition_by().is_empty());
+    }
+
+    #[test]
+    fn given_partition_by_should_return_partition_columns() {
+        let file_content = "
+        columns:
+            - name: id
+              provider: Increment.integer
+        info:
+            partition_by: [id]
+        "
+        .to_string();
+        let config = get_config_from_string(file_content).unwrap();
+        assert_eq!(config.get_partition_by(), vec!["id".to_string()]);
+    }
+
+    #[test]
+    fn given_dash_name_should_return_stdout() {
+        let file_content = "
+        columns:
+            - name: id
+              provider: Increment.integer
+        info:
+            output_name: '-'
+        "
+        .to_string();
+        let config = get_config_from_string(file_content).unwrap();
+        assert!(config.is_stdout());
+        assert_eq!(config.get_output_file_name(".parquet"), "-");
+    }
+
+    #[test]
+    fn given_name_should_not_return_stdout() {
+        let file_content = "
+        columns:
+            - name: id
+              provider: Increment.integer
+        info:
+            output_name: expected_name
+        "
+        .to_string();
+        let config = get_config_from_string(file_content).unwrap();
+        assert!(!config.is_stdout());
+    }
+
     // get_output_rows
     #[test]
     fn given_no_info_should_return_default_rows() {
@@ -7,6 +7,12 @@ pub enum FakeLakeError {
     IOError(io::Error),
     CSVError(csv::Error),
     JSONError(serde_json::Error),
+    AvroError(apache_avro::Error),
+    OrcError(orc_rust::error::OrcError),
+    ArrowError(arrow_schema::ArrowError),
+    ParquetError(parquet::errors::ParquetError),
+    XlsxError(rust_xlsxwriter::XlsxError),
+    SqliteError(rusqlite::Error),
 }
 
 #[cfg(not(tarpaulin_include))]
@@ -23,3 +29,10 @@ impl From<io::Error> for FakeLakeError {
         FakeLakeError::IOError(error)
     }
 }
+
+#[cfg(not(tarpaulin_include))]
+impl From<rusqlite::Error> for FakeLakeError {
+    fn from(error: rusqlite::Error) -> Self {
+        FakeLakeError::SqliteError(error)
+    }
+}
@@ -0,0 +1,288 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::generate::parquet::{generate_batches_from_config, get_schema_from_config, BATCH_SIZE};
+
+use arrow_ipc::writer::{FileWriter, StreamWriter};
+use log::debug;
+
+const ARROW_FILE_EXTENSION: &str = ".arrow";
+const ARROW_STREAM_EXTENSION: &str = ".arrows";
+
+#[derive(Debug, PartialEq)]
+pub struct OutputArrow {
+    stream: bool,
+}
+
+impl OutputArrow {
+    pub fn new(stream: bool) -> OutputArrow {
+        OutputArrow { stream }
+    }
+}
+
+impl OutputFormat for OutputArrow {
+    fn get_extension(&self) -> &str {
+        match self.stream {
+            true => ARROW_STREAM_EXTENSION,
+            false => ARROW_FILE_EXTENSION,
+        }
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let schema = get_schema_from_config(config);
+        debug!("Writing schema: {:?}", schema);
+
+        let file = OutputStream::create(file_name)?;
+
+        let res = match self.stream {
+            true => {
+                let mut writer = match StreamWriter::try_new_buffered(file, &schema) {
+                    Ok(value) => value,
+                    Err(e) => return Err(FakeLakeError::ArrowError(e)),
+                };
+                generate_batches_from_config(config, BATCH_SIZE, |batch| {
+                    match writer.write(&batch) {
+                        Ok(_) => Ok(()),
+                        Err(e) => Err(FakeLakeError::ArrowError(e)),
+                    }
+                })?;
+                // writer must be finished to write the end of stream marker
+                writer.finish()
+            }
+            false => {
+                let mut writer = match FileWriter::try_new_buffered(file, &schema) {
+                    Ok(value) => value,
+                    Err(e) => return Err(FakeLakeError::ArrowError(e)),
+                };
+                generate_batches_from_config(config, BATCH_SIZE, |batch| {
+                    match writer.write(&batch) {
+                        Ok(_) => Ok(()),
+                        Err(e) => Err(FakeLakeError::ArrowError(e)),
+                    }
+                })?;
+                // writer must be finished to write footer
+                writer.finish()
+            }
+        };
+
+        match res {
+            Ok(_) => Ok(()),
+            Err(e) => Err(FakeLakeError::ArrowError(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use arrow_ipc::reader::{FileReader, StreamReader};
+    use std::fs::File;
+    use yaml_rust::YamlLoader;
+
+    fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
+        let mut columns = vec![];
+
+        for _ in 0..nb_columns {
+            columns.push(Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            });
+        }
+
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Arrow(false)),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputArrow { stream: false };
+        assert_eq!(output.get_extension(), ".arrow");
+    }
+
+    #[test]
+    fn given_stream_get_extension() {
+        let output = OutputArrow { stream: true };
+        assert_eq!(output.get_extension(), ".arrows");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(0, None, None);
+        for stream in [false, true] {
+            let output = OutputArrow { stream };
+            match output.generate_from_config(&config) {
+                Err(_) => (),
+                Ok(_) => panic!("Should fail"),
+            }
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = get_config(1, None, None);
+        let output = OutputArrow { stream: false };
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_write_file() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_arrow".to_string()),
+            Some(100_000),
+        );
+        let output = OutputArrow { stream: false };
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let file = File::open("target/test_generated/output_arrow.arrow").unwrap();
+        let reader = FileReader::try_new(file, None).unwrap();
+        let rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(rows, 100_000);
+    }
+
+    #[test]
+    fn given_config_should_write_stream() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_arrow".to_string()),
+            Some(100_000),
+        );
+        let output = OutputArrow { stream: true };
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let file = File::open("target/test_generated/output_arrow.arrows").unwrap();
+        let reader = StreamReader::try_new(file, None).unwrap();
+        let rows: usize = reader.map(|batch| batch.unwrap().num_rows()).sum();
+        assert_eq!(rows, 100_000);
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_file() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "bool".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "float".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "timestamp".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_arrow_all".to_string()),
+                output_format: Some(OutputType::Arrow(true)),
+                rows: Some(1000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        for stream in [false, true] {
+            let output = OutputArrow { stream };
+            match output.generate_from_config(&config) {
+                Ok(_) => (),
+                Err(_) => panic!("Error"),
+            }
+        }
+    }
+}
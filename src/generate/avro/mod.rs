@@ -0,0 +1,494 @@
+use crate::config::{Column, Config};
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::unscaled_bytes;
+
+use apache_avro::types::Value as av;
+use apache_avro::{Decimal, Schema, Writer};
+use chrono::{Datelike, NaiveDate};
+use log::debug;
+use serde_json::json;
+use serde_json::Value as sv;
+use std::io::BufWriter;
+
+const AVRO_EXTENSION: &str = ".avro";
+const AVRO_RECORD_NAME: &str = "fakelake";
+
+#[derive(Debug, PartialEq)]
+pub struct OutputAvro;
+
+impl OutputFormat for OutputAvro {
+    fn get_extension(&self) -> &str {
+        AVRO_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let schema = get_schema_from_config(config)?;
+        debug!("Writing schema: {:?}", schema);
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let buffer = BufWriter::new(OutputStream::create(file_name)?);
+        let mut writer = Writer::new(&schema, buffer);
+        let rows = config.get_number_of_rows();
+
+        for i in 0..rows {
+            let mut record: Vec<(String, av)> = Vec::with_capacity(config.columns.len());
+            for column in &config.columns {
+                let value = match column.is_next_present() {
+                    true => Some(get_avro_value(column.provider.value(i))),
+                    false => None,
+                };
+
+                let value = match (column.can_be_null(), value) {
+                    (true, Some(value)) => av::Union(1, Box::new(value)),
+                    (true, None) => av::Union(0, Box::new(av::Null)),
+                    (false, Some(value)) => value,
+                    (false, None) => av::Null,
+                };
+                record.push((column.name.to_string(), value));
+            }
+
+            if let Err(e) = writer.append(av::Record(record)) {
+                return Err(FakeLakeError::AvroError(e));
+            }
+        }
+
+        // writer must be flushed to write the last block
+        if let Err(e) = writer.flush() {
+            return Err(FakeLakeError::AvroError(e));
+        }
+
+        Ok(())
+    }
+}
+
+fn get_avro_value(value: Value) -> av {
+    match value {
+        Value::Bool(value) => av::Boolean(value),
+        Value::Int32(value) => av::Int(value),
+        Value::Int64(value) => av::Long(value),
+        Value::Decimal(value, _, _) => av::Decimal(Decimal::from(unscaled_bytes(value))),
+        Value::Float64(value) => av::Double(value),
+        Value::String(value) => av::String(value),
+        Value::Date(value, _) => {
+            let epoch_days = NaiveDate::from_ymd_opt(1970, 1, 1)
+                .unwrap()
+                .num_days_from_ce();
+            av::Date(value.num_days_from_ce() - epoch_days)
+        }
+        Value::Timestamp(value, _) => av::TimestampMillis(value.timestamp_millis()),
+    }
+}
+
+pub fn get_avro_type_from_column(column: Column) -> sv {
+    match column.provider.value(0) {
+        Value::Bool(_) => json!("boolean"),
+        Value::Int32(_) => json!("int"),
+        Value::Int64(_) => json!("long"),
+        Value::Decimal(_, precision, scale) => {
+            json!({"type": "bytes", "logicalType": "decimal", "precision": precision, "scale": scale})
+        }
+        Value::Float64(_) => json!("double"),
+        Value::String(_) => json!("string"),
+        Value::Date(_, _) => json!({"type": "int", "logicalType": "date"}),
+        Value::Timestamp(_, _) => json!({"type": "long", "logicalType": "timestamp-millis"}),
+    }
+}
+
+fn get_schema_from_config(config: &Config) -> Result<Schema, FakeLakeError> {
+    let mut fields = Vec::new();
+
+    for column in &config.columns {
+        let avro_type = get_avro_type_from_column(column.clone());
+        let field = match column.can_be_null() {
+            true => json!({"name": column.name, "type": ["null", avro_type], "default": null}),
+            false => json!({"name": column.name, "type": avro_type}),
+        };
+        fields.push(field);
+    }
+
+    let schema = json!({
+        "type": "record",
+        "name": AVRO_RECORD_NAME,
+        "fields": fields,
+    });
+
+    match Schema::parse(&schema) {
+        Ok(schema) => Ok(schema),
+        Err(e) => Err(FakeLakeError::AvroError(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::increment::long::IncrementLongProvider;
+    use crate::providers::provider::Provider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::decimal::DecimalProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use apache_avro::Reader;
+    use std::fs::File;
+    use yaml_rust::YamlLoader;
+
+    fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
+        let mut columns = vec![];
+
+        for i in 0..nb_columns {
+            columns.push(Column {
+                name: format!("id_{}", i),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            });
+        }
+
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Avro()),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputAvro;
+        assert_eq!(output.get_extension(), ".avro");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(0, None, None);
+        let output = OutputAvro;
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = get_config(1, None, None);
+        let output = OutputAvro;
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_write_file() {
+        let config = get_config(
+            2,
+            Some("target/test_generated/output_avro".to_string()),
+            Some(1000),
+        );
+        let output = OutputAvro;
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let file = File::open("target/test_generated/output_avro.avro").unwrap();
+        let reader = Reader::new(file).unwrap();
+        assert_eq!(reader.count(), 1000);
+    }
+
+    #[test]
+    fn given_invalid_column_name_should_error() {
+        let mut config = get_config(1, None, None);
+        config.columns[0].name = "not a valid avro name".to_string();
+        let output = OutputAvro;
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_nullable_column_should_be_union_with_null() {
+        let columns = vec![Column {
+            name: "id".to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+            ),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: None,
+        };
+
+        let schema = get_schema_from_config(&config).unwrap();
+        let canonical = schema.canonical_form();
+        assert!(canonical.contains("[\"null\",\"int\"]"));
+    }
+
+    #[test]
+    fn given_decimal_should_return_decimal_bytes() {
+        assert_eq!(
+            get_avro_value(Value::Decimal(-129, 10, 2)),
+            av::Decimal(Decimal::from(vec![0xff, 0x7f]))
+        );
+    }
+
+    #[test]
+    fn given_not_nullable_column_should_not_be_union() {
+        let config = get_config(1, None, None);
+
+        let schema = get_schema_from_config(&config).unwrap();
+        let canonical = schema.canonical_form();
+        assert!(!canonical.contains("null"));
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_file() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "long".to_string(),
+                provider: Box::new(IncrementLongProvider {
+                    start: 10_000_000_000,
+                    step: 1,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "decimal".to_string(),
+                provider: Box::new(DecimalProvider {
+                    precision: 38,
+                    scale: 4,
+                    min: -99_999_999,
+                    max: 99_999_999,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "bool".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "float".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "timestamp".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_avro_all".to_string()),
+                output_format: Some(OutputType::Avro()),
+                rows: Some(1000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputAvro;
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let file = File::open("target/test_generated/output_avro_all.avro").unwrap();
+        let reader = Reader::new(file).unwrap();
+        assert_eq!(reader.count(), 1000);
+    }
+
+    #[test]
+    fn given_known_values_should_read_them_back() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 2)
+            .unwrap()
+            .num_days_from_ce();
+        let column = |name: &str, provider: Box<dyn Provider>, presence: &str| Column {
+            name: name.to_string(),
+            provider,
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: id\npresence: {}", presence)).unwrap()
+                    [0],
+            ),
+            path: None,
+        };
+        let columns = vec![
+            column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                "1",
+            ),
+            column(
+                "long",
+                Box::new(IncrementLongProvider {
+                    start: 10_000_000_000,
+                    step: 1,
+                }),
+                "1",
+            ),
+            column(
+                "decimal",
+                Box::new(DecimalProvider {
+                    precision: 10,
+                    scale: 2,
+                    min: -129,
+                    max: -129,
+                }),
+                "1",
+            ),
+            column(
+                "string",
+                Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                "0",
+            ),
+            column(
+                "date",
+                Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: date,
+                    before: date + 1,
+                }),
+                "1",
+            ),
+            column(
+                "timestamp",
+                Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 1_700_000_000,
+                    before: 1_700_000_001,
+                }),
+                "1",
+            ),
+        ];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_avro_values".to_string()),
+                output_format: Some(OutputType::Avro()),
+                rows: Some(2),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputAvro;
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let file = File::open("target/test_generated/output_avro_values.avro").unwrap();
+        let records: Vec<av> = Reader::new(file)
+            .unwrap()
+            .map(|record| record.unwrap())
+            .collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(
+            records[1],
+            av::Record(vec![
+                ("id".to_string(), av::Int(1)),
+                ("long".to_string(), av::Long(10_000_000_001)),
+                (
+                    "decimal".to_string(),
+                    av::Decimal(Decimal::from(vec![0xff, 0x7f]))
+                ),
+                ("string".to_string(), av::Union(0, Box::new(av::Null))),
+                ("date".to_string(), av::Date(19724)),
+                (
+                    "timestamp".to_string(),
+                    av::TimestampMillis(1_700_000_000_000)
+                ),
+            ])
+        );
+    }
+}
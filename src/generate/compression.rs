@@ -0,0 +1,213 @@
+use bzip2::write::BzEncoder;
+use flate2::write::GzEncoder;
+use liblzma::write::XzEncoder;
+use std::io::{self, Write};
+
+const XZ_DEFAULT_LEVEL: u32 = 6;
+
+/// Compression of the streams of text output formats
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputCompression {
+    Gzip,
+    Zstd,
+    Bzip2,
+    Xz,
+}
+
+impl OutputCompression {
+    pub fn from_name(name: &str) -> Option<OutputCompression> {
+        match name.to_lowercase().as_str() {
+            "gzip" | "gz" => Some(OutputCompression::Gzip),
+            "zstd" | "zst" => Some(OutputCompression::Zstd),
+            "bzip2" | "bz2" => Some(OutputCompression::Bzip2),
+            "xz" => Some(OutputCompression::Xz),
+            _ => None,
+        }
+    }
+
+    /// Extension appended to the extension of the output format
+    pub fn get_extension(&self) -> &str {
+        match self {
+            OutputCompression::Gzip => ".gz",
+            OutputCompression::Zstd => ".zst",
+            OutputCompression::Bzip2 => ".bz2",
+            OutputCompression::Xz => ".xz",
+        }
+    }
+}
+
+/// Extension of the output format followed by the one of the compression
+pub fn get_compressed_extension(extension: &str, compression: Option<OutputCompression>) -> String {
+    match compression {
+        Some(compression) => format!("{}{}", extension, compression.get_extension()),
+        None => extension.to_string(),
+    }
+}
+
+/// Writer compressing what is written before giving it to the inner writer
+pub enum CompressedWriter<W: Write> {
+    Uncompressed(W),
+    Gzip(GzEncoder<W>),
+    Zstd(zstd::Encoder<'static, W>),
+    Bzip2(BzEncoder<W>),
+    Xz(XzEncoder<W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(
+        inner: W,
+        compression: Option<OutputCompression>,
+    ) -> Result<CompressedWriter<W>, io::Error> {
+        let writer = match compression {
+            None => CompressedWriter::Uncompressed(inner),
+            Some(OutputCompression::Gzip) => {
+                CompressedWriter::Gzip(GzEncoder::new(inner, flate2::Compression::default()))
+            }
+            Some(OutputCompression::Zstd) => {
+                CompressedWriter::Zstd(zstd::Encoder::new(inner, zstd::DEFAULT_COMPRESSION_LEVEL)?)
+            }
+            Some(OutputCompression::Bzip2) => {
+                CompressedWriter::Bzip2(BzEncoder::new(inner, bzip2::Compression::default()))
+            }
+            Some(OutputCompression::Xz) => {
+                CompressedWriter::Xz(XzEncoder::new(inner, XZ_DEFAULT_LEVEL))
+            }
+        };
+        Ok(writer)
+    }
+
+    pub fn get_ref(&self) -> &W {
+        match self {
+            CompressedWriter::Uncompressed(writer) => writer,
+            CompressedWriter::Gzip(writer) => writer.get_ref(),
+            CompressedWriter::Zstd(writer) => writer.get_ref(),
+            CompressedWriter::Bzip2(writer) => writer.get_ref(),
+            CompressedWriter::Xz(writer) => writer.get_ref(),
+        }
+    }
+
+    /// Writes the end of the compressed stream and returns the inner writer
+    pub fn finish(self) -> Result<W, io::Error> {
+        let mut writer = match self {
+            CompressedWriter::Uncompressed(writer) => writer,
+            CompressedWriter::Gzip(writer) => writer.finish()?,
+            CompressedWriter::Zstd(writer) => writer.finish()?,
+            CompressedWriter::Bzip2(writer) => writer.finish()?,
+            CompressedWriter::Xz(writer) => writer.finish()?,
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            CompressedWriter::Uncompressed(writer) => writer.write(buf),
+            CompressedWriter::Gzip(writer) => writer.write(buf),
+            CompressedWriter::Zstd(writer) => writer.write(buf),
+            CompressedWriter::Bzip2(writer) => writer.write(buf),
+            CompressedWriter::Xz(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            CompressedWriter::Uncompressed(writer) => writer.flush(),
+            CompressedWriter::Gzip(writer) => writer.flush(),
+            CompressedWriter::Zstd(writer) => writer.flush(),
+            CompressedWriter::Bzip2(writer) => writer.flush(),
+            CompressedWriter::Xz(writer) => writer.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bzip2::read::BzDecoder;
+    use flate2::read::GzDecoder;
+    use liblzma::read::XzDecoder;
+    use std::io::Read;
+
+    fn compress(compression: Option<OutputCompression>, content: &[u8]) -> Vec<u8> {
+        let mut writer = CompressedWriter::new(Vec::new(), compression).unwrap();
+        writer.write_all(content).unwrap();
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn given_names_should_return_compression() {
+        assert_eq!(
+            OutputCompression::from_name("gzip"),
+            Some(OutputCompression::Gzip)
+        );
+        assert_eq!(
+            OutputCompression::from_name("ZSTD"),
+            Some(OutputCompression::Zstd)
+        );
+        assert_eq!(
+            OutputCompression::from_name("bzip2"),
+            Some(OutputCompression::Bzip2)
+        );
+        assert_eq!(
+            OutputCompression::from_name("xz"),
+            Some(OutputCompression::Xz)
+        );
+        assert_eq!(OutputCompression::from_name("rar"), None);
+    }
+
+    #[test]
+    fn given_compression_should_append_extension() {
+        assert_eq!(get_compressed_extension(".csv", None), ".csv");
+        assert_eq!(
+            get_compressed_extension(".csv", Some(OutputCompression::Gzip)),
+            ".csv.gz"
+        );
+        assert_eq!(
+            get_compressed_extension(".json", Some(OutputCompression::Zstd)),
+            ".json.zst"
+        );
+        assert_eq!(
+            get_compressed_extension(".csv", Some(OutputCompression::Bzip2)),
+            ".csv.bz2"
+        );
+        assert_eq!(
+            get_compressed_extension(".json", Some(OutputCompression::Xz)),
+            ".json.xz"
+        );
+    }
+
+    #[test]
+    fn given_no_compression_should_write_content() {
+        assert_eq!(compress(None, b"id\n1\n"), b"id\n1\n");
+    }
+
+    #[test]
+    fn given_compression_should_be_decompressed() {
+        let content = b"id,name\n1,fakelake\n".repeat(100);
+
+        let mut decompressed = Vec::new();
+        GzDecoder::new(&compress(Some(OutputCompression::Gzip), &content)[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, content);
+
+        let decompressed =
+            zstd::decode_all(&compress(Some(OutputCompression::Zstd), &content)[..]).unwrap();
+        assert_eq!(decompressed, content);
+
+        let mut decompressed = Vec::new();
+        BzDecoder::new(&compress(Some(OutputCompression::Bzip2), &content)[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, content);
+
+        let mut decompressed = Vec::new();
+        XzDecoder::new(&compress(Some(OutputCompression::Xz), &content)[..])
+            .read_to_end(&mut decompressed)
+            .unwrap();
+        assert_eq!(decompressed, content);
+    }
+}
@@ -1,26 +1,41 @@
+pub mod options;
+
 use crate::config::Config;
 use crate::errors::FakeLakeError;
+use crate::generate::compression::{get_compressed_extension, CompressedWriter};
+use crate::generate::output_files::{
+    get_partition_value, CountingWriter, OutputFile, OutputFiles, OutputStream,
+};
 use crate::generate::output_format::OutputFormat;
 use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+
+use options::{CsvOptions, UTF8_BOM};
 
-use csv::WriterBuilder;
+use csv::{QuoteStyle, Writer};
+use std::io::Write;
+use std::path::Path;
 
 const CSV_EXTENSION: &str = ".csv";
 
 #[derive(Debug, PartialEq)]
 pub struct OutputCsv {
-    delimiter: u8,
+    options: CsvOptions,
+    extension: String,
 }
 
 impl OutputCsv {
-    pub fn new(delimiter: u8) -> OutputCsv {
-        OutputCsv { delimiter }
+    pub fn new(options: CsvOptions) -> OutputCsv {
+        OutputCsv {
+            extension: get_compressed_extension(CSV_EXTENSION, options.compression),
+            options,
+        }
     }
 }
 
 impl OutputFormat for OutputCsv {
     fn get_extension(&self) -> &str {
-        CSV_EXTENSION
+        &self.extension
     }
 
     fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
@@ -30,66 +45,127 @@ impl OutputFormat for OutputCsv {
             ));
         }
 
-        let file_name = config.get_output_file_name(self.get_extension());
+        let mut files = OutputFiles::new(config, self.get_extension())?;
         let rows = config.get_number_of_rows();
 
-        let mut wtr = match WriterBuilder::new()
-            .delimiter(self.delimiter)
-            .from_path(file_name)
-        {
-            Ok(value) => value,
-            Err(e) => {
-                return Err(FakeLakeError::CSVError(e));
+        let mut quoter = self.options.get_field_quoter();
+        let mut column_names: Vec<Vec<u8>> = vec![];
+        for (index, column) in config.columns.iter().enumerate() {
+            if !files.is_partition_column(index) {
+                column_names.push(quoter.quote(&column.name));
+            }
+        }
+
+        // fields are already quoted, so that null values are written as is
+        let mut builder = self.options.get_writer_builder();
+        builder.quote_style(QuoteStyle::Never);
+        let create_writer = |path: &Path| {
+            let mut stream = CompressedWriter::new(
+                CountingWriter::new(OutputStream::create(path)?),
+                self.options.compression,
+            )?;
+            if self.options.bom {
+                stream.write_all(UTF8_BOM)?;
+            }
+            let mut wtr = builder.from_writer(stream);
+            if self.options.header {
+                if let Err(e) = wtr.write_record(&column_names) {
+                    return Err(FakeLakeError::CSVError(e));
+                }
             }
+            Ok(wtr)
         };
 
-        let mut column_names: Vec<&str> = vec![];
-        for column in &config.columns {
-            column_names.push(&column.name);
-        }
-        if let Err(e) = wtr.write_record(column_names) {
-            return Err(FakeLakeError::CSVError(e));
+        // without partitions, the file is written even without rows
+        if !files.is_partitioned() {
+            files.get_writer(vec![], 0, create_writer)?;
         }
 
         for i in 0..rows {
-            let mut row: Vec<String> = vec![];
+            let mut values: Vec<Option<String>> = vec![];
             for column in &config.columns {
-                let mut str_value = "".to_string();
+                let mut str_value = None;
                 if column.is_next_present() {
-                    str_value = match column.provider.value(i) {
+                    str_value = Some(match column.provider.value(i) {
                         Value::Bool(value) => value.to_string(),
                         Value::Int32(value) => value.to_string(),
+                        Value::Int64(value) => value.to_string(),
+                        Value::Decimal(value, _, scale) => format_decimal(value, scale),
                         Value::Float64(value) => value.to_string(),
                         Value::String(value) => value,
                         Value::Date(value, date_format) => value.format(&date_format).to_string(),
                         Value::Timestamp(value, date_format) => {
                             value.format(&date_format).to_string()
                         }
-                    };
+                    });
                 }
-                row.push(str_value);
+                values.push(str_value);
             }
+
+            let partition_values: Vec<String> = files
+                .partition_indexes()
+                .iter()
+                .map(|index| get_partition_value(values[*index].as_deref()))
+                .collect();
+            let row: Vec<Vec<u8>> = values
+                .into_iter()
+                .enumerate()
+                .filter(|(index, _)| !files.is_partition_column(*index))
+                .map(|(_, value)| match value {
+                    Some(value) => quoter.quote(&value),
+                    None => self.options.null_value.as_bytes().to_vec(),
+                })
+                .collect();
+
+            let (wtr, _) = files.get_writer(partition_values, 1, create_writer)?;
             if let Err(e) = wtr.write_record(row) {
                 return Err(FakeLakeError::CSVError(e));
             }
         }
 
+        files.close()
+    }
+}
+
+impl OutputFile for Writer<CompressedWriter<CountingWriter<OutputStream>>> {
+    fn bytes_written(&self) -> u64 {
+        self.get_ref().get_ref().count()
+    }
+
+    fn flush_buffers(&mut self) -> Result<(), FakeLakeError> {
+        // also flushes the pending output of the compression
+        self.flush()?;
         Ok(())
     }
+
+    fn close(self) -> Result<(), FakeLakeError> {
+        match self.into_inner() {
+            Ok(writer) => {
+                writer.finish()?;
+                Ok(())
+            }
+            Err(e) => Err(FakeLakeError::IOError(e.into_error())),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::{Column, Config, Info, OutputType};
+    use crate::generate::compression::OutputCompression;
     use crate::options::presence;
     use crate::providers::increment::integer::IncrementIntegerProvider;
     use crate::providers::random::bool::BoolProvider;
     use crate::providers::random::date::date::DateProvider;
     use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::decimal::DecimalProvider;
     use crate::providers::random::number::f64::F64Provider;
     use crate::providers::random::string::alphanumeric::AlphanumericProvider;
 
+    use flate2::read::GzDecoder;
+    use std::fs::File;
+    use std::io::Read;
     use yaml_rust::YamlLoader;
 
     fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
@@ -102,6 +178,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             });
         }
 
@@ -109,23 +186,35 @@ mod tests {
             columns,
             info: Some(Info {
                 output_name: name,
-                output_format: Some(OutputType::Csv(5)),
+                output_format: Some(OutputType::Csv(CsvOptions {
+                    delimiter: 5,
+                    ..Default::default()
+                })),
                 rows,
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         }
     }
 
     #[test]
     fn given_get_extension() {
-        let output = OutputCsv { delimiter: 5 };
+        let output = OutputCsv::new(CsvOptions {
+            delimiter: 5,
+            ..Default::default()
+        });
         assert_eq!(output.get_extension(), ".csv");
     }
 
     #[test]
     fn given_config_without_columns_should_error() {
         let config = get_config(0, None, None);
-        let output = OutputCsv { delimiter: 5 };
+        let output = OutputCsv::new(CsvOptions {
+            delimiter: 5,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Err(_) => (),
             Ok(_) => panic!("Should fail"),
@@ -135,7 +224,10 @@ mod tests {
     #[test]
     fn given_config_without_info_should_write_file() {
         let config = get_config(1, None, None);
-        let output = OutputCsv { delimiter: 5 };
+        let output = OutputCsv::new(CsvOptions {
+            delimiter: 5,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
@@ -149,7 +241,10 @@ mod tests {
             Some("target/test_generated/output_name".to_string()),
             Some(1000),
         );
-        let output = OutputCsv { delimiter: 5 };
+        let output = OutputCsv::new(CsvOptions {
+            delimiter: 5,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
@@ -165,6 +260,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "bool".to_string(),
@@ -172,13 +268,19 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
-                provider: Box::new(F64Provider { min: 0.0, max: 1.1 }),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
@@ -189,6 +291,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
@@ -200,6 +303,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
@@ -211,6 +315,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
         ];
 
@@ -218,16 +323,242 @@ mod tests {
             columns,
             info: Some(Info {
                 output_name: Some("target/test_generated/output_name".to_string()),
-                output_format: Some(OutputType::Csv(5)),
+                output_format: Some(OutputType::Csv(CsvOptions {
+                    delimiter: 5,
+                    ..Default::default()
+                })),
                 rows: Some(1000),
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputCsv::new(CsvOptions {
+            delimiter: 5,
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_partition_by_should_write_hive_directories() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "flag".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: flag\npresence: 0.8").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let directory = "target/test_generated/output_csv_partitioned";
+        std::fs::remove_dir_all(directory).ok();
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some(directory.to_string()),
+                output_format: Some(OutputType::Csv(CsvOptions::default())),
+                rows: Some(1000),
+                seed: None,
+                partition_by: Some(vec!["flag".to_string()]),
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputCsv::new(CsvOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let mut total_rows = 0;
+        for partition in ["true", "false", "__HIVE_DEFAULT_PARTITION__"] {
+            let file_name = format!("{}/flag={}/part-00000.csv", directory, partition);
+            let content = std::fs::read_to_string(file_name).unwrap();
+            let mut lines = content.lines();
+            assert_eq!(lines.next(), Some("id"));
+            total_rows += lines.count();
+        }
+        assert_eq!(total_rows, 1000);
+    }
+
+    #[test]
+    fn given_max_bytes_per_file_should_split_files() {
+        for compression in [None, Some(OutputCompression::Gzip)] {
+            let directory = format!("target/test_generated/output_csv_split_{:?}", compression);
+            std::fs::remove_dir_all(&directory).ok();
+            std::fs::create_dir_all(&directory).unwrap();
+            let mut config = get_config(1, Some(format!("{}/output", directory)), Some(100_000));
+            config.info.as_mut().unwrap().max_bytes_per_file = Some(64 * 1024);
+
+            let output = OutputCsv::new(CsvOptions {
+                compression,
+                ..Default::default()
+            });
+            match output.generate_from_config(&config) {
+                Ok(_) => (),
+                Err(_) => panic!("Error"),
+            }
+
+            let mut total_rows = 0;
+            let mut files = 0;
+            for entry in std::fs::read_dir(&directory).unwrap() {
+                let path = entry.unwrap().path();
+                // buffers are flushed before measuring, files only go over by about one row
+                assert!(std::fs::metadata(&path).unwrap().len() <= 64 * 1024 + 64);
+                let mut content = String::new();
+                match compression {
+                    Some(_) => GzDecoder::new(File::open(&path).unwrap())
+                        .read_to_string(&mut content)
+                        .unwrap(),
+                    None => File::open(&path)
+                        .unwrap()
+                        .read_to_string(&mut content)
+                        .unwrap(),
+                };
+                let mut lines = content.lines();
+                assert_eq!(lines.next(), Some("id"));
+                total_rows += lines.count();
+                files += 1;
+            }
+            assert!(files > 1);
+            assert_eq!(total_rows, 100_000);
+        }
+    }
+
+    #[test]
+    fn given_compression_should_write_compressed_file() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_csv_gzip".to_string()),
+            Some(1000),
+        );
+        let output = OutputCsv::new(CsvOptions {
+            compression: Some(OutputCompression::Gzip),
+            ..Default::default()
+        });
+        assert_eq!(output.get_extension(), ".csv.gz");
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let file = File::open("target/test_generated/output_csv_gzip.csv.gz").unwrap();
+        let mut content = String::new();
+        GzDecoder::new(file).read_to_string(&mut content).unwrap();
+        let mut lines = content.lines();
+        assert_eq!(lines.next(), Some("id"));
+        assert_eq!(lines.next(), Some("0"));
+        assert_eq!(lines.count(), 999);
+    }
+
+    #[test]
+    fn given_decimal_should_write_exact_text() {
+        let columns = vec![Column {
+            name: "amount".to_string(),
+            provider: Box::new(DecimalProvider {
+                precision: 20,
+                scale: 3,
+                min: 12_345_678_901_234_567,
+                max: 12_345_678_901_234_567,
+            }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: amount\npresence: 1").unwrap()[0],
+            ),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_csv_decimal".to_string()),
+                output_format: None,
+                rows: Some(1),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         };
 
-        let output = OutputCsv { delimiter: 5 };
+        let output = OutputCsv::new(CsvOptions::default());
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
         }
+
+        assert_eq!(
+            std::fs::read_to_string("target/test_generated/output_csv_decimal.csv").unwrap(),
+            "amount\n12345678901234.567\n"
+        );
+    }
+
+    #[test]
+    fn given_dialect_should_write_file_in_dialect() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "name".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 0,
+                    max_length: 1,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: name\npresence: 0").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_csv_dialect".to_string()),
+                output_format: None,
+                rows: Some(2),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputCsv::new(CsvOptions {
+            delimiter: b';',
+            header: false,
+            quote_style: options::CsvQuoteStyle::Always,
+            line_terminator: options::CsvLineTerminator::Crlf,
+            null_value: "\\N".to_string(),
+            bom: true,
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let content = std::fs::read("target/test_generated/output_csv_dialect.csv").unwrap();
+        assert_eq!(content, b"\xEF\xBB\xBF\"0\";\\N\r\n\"1\";\\N\r\n");
     }
 }
@@ -0,0 +1,322 @@
+use crate::config::parse_compression;
+use crate::generate::compression::OutputCompression;
+
+use csv::{QuoteStyle, Terminator, WriterBuilder};
+use log::warn;
+use yaml_rust::Yaml;
+
+pub const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Quoting of the fields, see csv::QuoteStyle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CsvQuoteStyle {
+    Always,
+    Necessary,
+    NonNumeric,
+    Never,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CsvLineTerminator {
+    Lf,
+    Crlf,
+}
+
+/// CSV dialect settings of the info section
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: u8,
+    pub compression: Option<OutputCompression>,
+    pub header: bool,
+    pub quote: u8,
+    pub quote_style: CsvQuoteStyle,
+    /// Quotes inside fields are escaped with this char instead of being doubled
+    pub escape: Option<u8>,
+    pub line_terminator: CsvLineTerminator,
+    /// Written instead of null values
+    pub null_value: String,
+    /// Files start with the UTF-8 byte order mark
+    pub bom: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: b',',
+            compression: None,
+            header: true,
+            quote: b'"',
+            quote_style: CsvQuoteStyle::Necessary,
+            escape: None,
+            line_terminator: CsvLineTerminator::Lf,
+            null_value: "".to_string(),
+            bom: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    pub fn new_from_yaml(section_info: &Yaml) -> CsvOptions {
+        let mut options = CsvOptions::default();
+
+        if let Some(delimiter) = parse_char(section_info, "delimiter") {
+            options.delimiter = delimiter;
+        }
+        options.compression = parse_compression(&section_info["compression"]);
+
+        match section_info["header"] {
+            Yaml::Boolean(value) => options.header = value,
+            Yaml::BadValue => (),
+            _ => warn!("CSV header should be a bool. Default value 'true' is taken."),
+        }
+
+        if let Some(quote) = parse_char(section_info, "quote") {
+            options.quote = quote;
+        }
+
+        match section_info["quote_style"].as_str() {
+            Some("always") => options.quote_style = CsvQuoteStyle::Always,
+            Some("necessary") => options.quote_style = CsvQuoteStyle::Necessary,
+            Some("non_numeric") => options.quote_style = CsvQuoteStyle::NonNumeric,
+            Some("never") => options.quote_style = CsvQuoteStyle::Never,
+            None if section_info["quote_style"].is_badvalue() => (),
+            _ => warn!("CSV quote_style should be always, necessary, non_numeric or never. Default 'necessary' is taken."),
+        }
+
+        // escape is either 'double' to double the quotes or the escaping char
+        match section_info["escape"].as_str() {
+            Some("double") => (),
+            _ => options.escape = parse_char(section_info, "escape"),
+        }
+
+        match section_info["line_terminator"]
+            .as_str()
+            .map(|value| value.to_lowercase())
+        {
+            Some(value) if value == "lf" || value == "\n" => {
+                options.line_terminator = CsvLineTerminator::Lf
+            }
+            Some(value) if value == "crlf" || value == "\r\n" => {
+                options.line_terminator = CsvLineTerminator::Crlf
+            }
+            None if section_info["line_terminator"].is_badvalue() => (),
+            _ => warn!("CSV line_terminator should be lf or crlf. Default 'lf' is taken."),
+        }
+
+        match &section_info["null_value"] {
+            Yaml::String(value) => options.null_value = value.to_string(),
+            Yaml::BadValue => (),
+            _ => warn!("CSV null_value should be a string. Default empty string is taken."),
+        }
+
+        match section_info["bom"] {
+            Yaml::Boolean(value) => options.bom = value,
+            Yaml::BadValue => (),
+            _ => warn!("CSV bom should be a bool. Default value 'false' is taken."),
+        }
+
+        options
+    }
+
+    pub fn get_writer_builder(&self) -> WriterBuilder {
+        let mut builder = WriterBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quote_style(match self.quote_style {
+                CsvQuoteStyle::Always => QuoteStyle::Always,
+                CsvQuoteStyle::Necessary => QuoteStyle::Necessary,
+                CsvQuoteStyle::NonNumeric => QuoteStyle::NonNumeric,
+                CsvQuoteStyle::Never => QuoteStyle::Never,
+            })
+            .terminator(match self.line_terminator {
+                CsvLineTerminator::Lf => Terminator::Any(b'\n'),
+                CsvLineTerminator::Crlf => Terminator::CRLF,
+            });
+        if let Some(escape) = self.escape {
+            builder.escape(escape).double_quote(false);
+        }
+        builder
+    }
+
+    pub fn get_field_quoter(&self) -> CsvFieldQuoter {
+        let mut builder = csv_core::WriterBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .quote_style(match self.quote_style {
+                CsvQuoteStyle::Always => csv_core::QuoteStyle::Always,
+                CsvQuoteStyle::Necessary => csv_core::QuoteStyle::Necessary,
+                CsvQuoteStyle::NonNumeric => csv_core::QuoteStyle::NonNumeric,
+                CsvQuoteStyle::Never => csv_core::QuoteStyle::Never,
+            });
+        if let Some(escape) = self.escape {
+            builder.escape(escape).double_quote(false);
+        }
+        CsvFieldQuoter {
+            writer: builder.build(),
+        }
+    }
+}
+
+/// Quotes fields one by one, so that rows can mix quoted fields
+/// with null values written as is
+pub struct CsvFieldQuoter {
+    writer: csv_core::Writer,
+}
+
+impl CsvFieldQuoter {
+    pub fn quote(&mut self, field: &str) -> Vec<u8> {
+        let input = field.as_bytes();
+        if !self.writer.should_quote(input) {
+            return input.to_vec();
+        }
+
+        // quotes around the field, each char being escaped at worst
+        let mut output = vec![0; 2 * input.len() + 2];
+        let (_, _, written) = self.writer.field(input, &mut output);
+        let (_, closed) = self.writer.finish(&mut output[written..]);
+        output.truncate(written + closed);
+        output
+    }
+}
+
+/// Single byte setting, the default is kept when it is missing or invalid
+fn parse_char(section_info: &Yaml, name: &str) -> Option<u8> {
+    let value = &section_info[name];
+    if value.is_badvalue() {
+        return None;
+    }
+
+    match value.as_str() {
+        Some(value) if value.len() == 1 => value.as_bytes().first().copied(),
+        _ => {
+            warn!("CSV {} should be one char. Default value is taken.", name);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_options(yaml: &str) -> CsvOptions {
+        let parsed_yaml = &YamlLoader::load_from_str(yaml).unwrap()[0];
+        CsvOptions::new_from_yaml(parsed_yaml)
+    }
+
+    fn write(options: &CsvOptions, records: &[Vec<&str>]) -> String {
+        let mut wtr = options.get_writer_builder().from_writer(vec![]);
+        for record in records {
+            wtr.write_record(record).unwrap();
+        }
+        String::from_utf8(wtr.into_inner().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn given_no_settings_should_return_default() {
+        assert_eq!(get_options("output_format: csv"), CsvOptions::default());
+    }
+
+    #[test]
+    fn given_settings_should_return_them() {
+        let options = get_options(
+            "
+            delimiter: ';'
+            compression: gzip
+            header: false
+            quote: \"'\"
+            quote_style: always
+            escape: '\\'
+            line_terminator: crlf
+            null_value: NULL
+            bom: true
+            ",
+        );
+        assert_eq!(
+            options,
+            CsvOptions {
+                delimiter: b';',
+                compression: Some(OutputCompression::Gzip),
+                header: false,
+                quote: b'\'',
+                quote_style: CsvQuoteStyle::Always,
+                escape: Some(b'\\'),
+                line_terminator: CsvLineTerminator::Crlf,
+                null_value: "NULL".to_string(),
+                bom: true,
+            }
+        );
+    }
+
+    #[test]
+    fn given_invalid_settings_should_return_default() {
+        let options = get_options(
+            "
+            delimiter: ab
+            header: 1
+            quote: ''
+            quote_style: sometimes
+            escape: ab
+            line_terminator: cr
+            null_value: [a]
+            bom: yes
+            ",
+        );
+        assert_eq!(options, CsvOptions::default());
+    }
+
+    #[test]
+    fn given_double_escape_should_double_quotes() {
+        let options = get_options("escape: double");
+        assert_eq!(options.escape, None);
+        assert_eq!(write(&options, &[vec!["a\"b"]]), "\"a\"\"b\"\n");
+    }
+
+    #[test]
+    fn given_quote_styles_should_quote_fields() {
+        let records = [vec!["id", "name"], vec!["1", "a,b"]];
+
+        let options = get_options("quote_style: always");
+        assert_eq!(
+            write(&options, &records),
+            "\"id\",\"name\"\n\"1\",\"a,b\"\n"
+        );
+
+        let options = get_options("quote_style: necessary");
+        assert_eq!(write(&options, &records), "id,name\n1,\"a,b\"\n");
+
+        let options = get_options("quote_style: non_numeric");
+        assert_eq!(write(&options, &records), "\"id\",\"name\"\n1,\"a,b\"\n");
+
+        let options = get_options("quote_style: never");
+        assert_eq!(write(&options, &records), "id,name\n1,a,b\n");
+    }
+
+    #[test]
+    fn given_quote_styles_should_quote_single_fields() {
+        let quote = |yaml: &str, field: &str| {
+            String::from_utf8(get_options(yaml).get_field_quoter().quote(field)).unwrap()
+        };
+        assert_eq!(quote("quote_style: always", "1"), "\"1\"");
+        assert_eq!(quote("quote_style: always", ""), "\"\"");
+        assert_eq!(quote("quote_style: necessary", "a,b"), "\"a,b\"");
+        assert_eq!(quote("quote_style: necessary", ""), "");
+        assert_eq!(quote("quote_style: non_numeric", "1.5"), "1.5");
+        assert_eq!(quote("quote_style: never", "a,b"), "a,b");
+        assert_eq!(quote("escape: '\\'", "a\"b"), "\"a\\\"b\"");
+        assert_eq!(quote("escape: double", "a\"b"), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn given_quote_escape_and_terminator_should_write_dialect() {
+        let options = get_options("quote: \"'\"\nescape: '\\'\nline_terminator: crlf");
+        assert_eq!(
+            write(&options, &[vec!["it's", "a"], vec!["b", "c"]]),
+            "'it\\'s',a\r\nb,c\r\n"
+        );
+    }
+}
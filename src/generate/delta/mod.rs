@@ -0,0 +1,604 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::{parse_partition_value, OutputFiles, WrittenFile};
+use crate::generate::output_format::OutputFormat;
+use crate::generate::parquet::get_schema_from_config;
+use crate::generate::parquet::options::ParquetOptions;
+use crate::generate::parquet::statistics::{read_file_statistics, Bound};
+use crate::generate::parquet::{DataFileSettings, OutputParquet};
+use crate::providers::utils::decimal::format_decimal;
+
+use arrow_schema::{DataType, Schema, TimeUnit};
+use chrono::{DateTime, NaiveDate};
+use serde_json::{json, Map, Value as JsonValue};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const DELTA_EXTENSION: &str = ".parquet";
+const DELTA_LOG_DIRECTORY: &str = "_delta_log";
+/// First commit of the table, creating it
+const DELTA_FIRST_COMMIT: &str = "00000000000000000000.json";
+
+const DELTA_DATE_FORMAT: &str = "%Y-%m-%d";
+const DELTA_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.3f";
+/// Digits a double keeps exactly
+const DELTA_MAX_DECIMAL_STATS_PRECISION: u8 = 15;
+
+#[derive(Debug, PartialEq)]
+pub struct OutputDelta {
+    parquet: OutputParquet,
+}
+
+impl OutputDelta {
+    pub fn new(options: ParquetOptions) -> OutputDelta {
+        OutputDelta {
+            parquet: OutputParquet::new_with_settings(
+                options,
+                DataFileSettings {
+                    timestamp_unit: TimeUnit::Microsecond,
+                    uuid_columns: false,
+                    ..Default::default()
+                },
+            ),
+        }
+    }
+}
+
+impl OutputFormat for OutputDelta {
+    fn get_extension(&self) -> &str {
+        DELTA_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+        if config.is_stdout() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "Delta tables can't be written to standard output".to_string(),
+            ));
+        }
+
+        // the table is the output name directory
+        let table_path = PathBuf::from(config.get_output_file_name(""));
+        let log_path = table_path.join(DELTA_LOG_DIRECTORY);
+        if log_path.exists() {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Delta table {} already exists",
+                table_path.display()
+            )));
+        }
+
+        let files = OutputFiles::new_in_directory(config, self.get_extension(), &table_path)?;
+        let partition_indexes = files.partition_indexes().to_vec();
+        let written_files = self.parquet.write_files(config, files)?;
+
+        let log = get_first_commit(config, &partition_indexes, &table_path, &written_files)?;
+        std::fs::create_dir_all(&log_path)?;
+        let mut file = File::create(log_path.join(DELTA_FIRST_COMMIT))?;
+        file.write_all(log.as_bytes())?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+/// Transaction log creating the table with all the written files,
+/// one action per line
+fn get_first_commit(
+    config: &Config,
+    partition_indexes: &[usize],
+    table_path: &Path,
+    written_files: &[WrittenFile],
+) -> Result<String, FakeLakeError> {
+    let schema = get_schema_from_config(config);
+    let now = chrono::Utc::now().timestamp_millis();
+    let partition_columns: Vec<&str> = partition_indexes
+        .iter()
+        .map(|index| schema.field(*index).name().as_str())
+        .collect();
+
+    let mut actions = vec![
+        json!({"commitInfo": {
+            "timestamp": now,
+            "operation": "CREATE TABLE",
+            "operationParameters": {
+                "mode": "ErrorIfExists",
+                "partitionBy": serde_json::to_string(&partition_columns).unwrap(),
+            },
+            "isBlindAppend": true,
+            "engineInfo": format!("fakelake/{}", env!("CARGO_PKG_VERSION")),
+        }}),
+        json!({ "protocol": get_protocol(&schema) }),
+        json!({"metaData": {
+            "id": uuid::Uuid::new_v4().to_string(),
+            "format": {"provider": "parquet", "options": {}},
+            "schemaString": get_schema_string(&schema).to_string(),
+            "partitionColumns": partition_columns,
+            "configuration": {},
+            "createdTime": now,
+        }}),
+    ];
+
+    // partition columns are not in the data files
+    let data_indexes: Vec<usize> = (0..schema.fields().len())
+        .filter(|index| !partition_indexes.contains(index))
+        .collect();
+    let data_schema = match schema.project(&data_indexes) {
+        Ok(value) => value,
+        Err(e) => return Err(FakeLakeError::ArrowError(e)),
+    };
+
+    for written_file in written_files {
+        let mut partition_values = Map::new();
+        for (index, value) in partition_indexes.iter().zip(&written_file.partition_values) {
+            let field = schema.field(*index);
+            let value = parse_partition_value(value).map(|value| match field.data_type() {
+                DataType::Timestamp(_, _) => value.replace('T', " "),
+                _ => value,
+            });
+            partition_values.insert(field.name().to_string(), json!(value));
+        }
+
+        let relative_path = written_file
+            .path
+            .strip_prefix(table_path)
+            .unwrap_or(&written_file.path);
+        actions.push(json!({"add": {
+            "path": get_uri_path(relative_path),
+            "partitionValues": partition_values,
+            "size": std::fs::metadata(&written_file.path)?.len(),
+            "modificationTime": now,
+            "dataChange": true,
+            "stats": get_file_stats(&written_file.path, &data_schema)?.to_string(),
+        }}));
+    }
+
+    let mut log = String::new();
+    for action in actions {
+        log.push_str(&action.to_string());
+        log.push('\n');
+    }
+    Ok(log)
+}
+
+/// Timestamps without time zone need the timestampNtz table feature
+fn get_protocol(schema: &Schema) -> JsonValue {
+    let has_timestamp = schema
+        .fields()
+        .iter()
+        .any(|field| matches!(field.data_type(), DataType::Timestamp(_, _)));
+    match has_timestamp {
+        true => json!({
+            "minReaderVersion": 3,
+            "minWriterVersion": 7,
+            "readerFeatures": ["timestampNtz"],
+            "writerFeatures": ["timestampNtz"],
+        }),
+        false => json!({"minReaderVersion": 1, "minWriterVersion": 2}),
+    }
+}
+
+fn get_delta_type(data_type: &DataType) -> String {
+    let delta_type = match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int32 => "integer",
+        DataType::Int64 => "long",
+        DataType::Decimal128(precision, scale) => {
+            return format!("decimal({},{})", precision, scale)
+        }
+        DataType::Float64 => "double",
+        DataType::Date32 => "date",
+        DataType::Timestamp(_, _) => "timestamp_ntz",
+        _ => "string",
+    };
+    delta_type.to_string()
+}
+
+fn get_schema_string(schema: &Schema) -> JsonValue {
+    let fields: Vec<JsonValue> = schema
+        .fields()
+        .iter()
+        .map(|field| {
+            json!({
+                "name": field.name(),
+                "type": get_delta_type(field.data_type()),
+                "nullable": field.is_nullable(),
+                "metadata": {},
+            })
+        })
+        .collect();
+    json!({"type": "struct", "fields": fields})
+}
+
+/// Relative path of a data file as an URI, directory names being
+/// already escaped, their '%' are escaped a second time
+fn get_uri_path(path: &Path) -> String {
+    let parts: Vec<String> = path
+        .iter()
+        .map(|part| part.to_string_lossy().to_string())
+        .collect();
+
+    let mut uri = String::new();
+    for byte in parts.join("/").bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b'=' => {
+                uri.push(byte as char)
+            }
+            byte => uri.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    uri
+}
+
+fn get_stats_value(bound: Bound, data_type: &DataType) -> Option<JsonValue> {
+    match (bound, data_type) {
+        (Bound::Integer(days), DataType::Date32) => {
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+            let date = epoch.checked_add_signed(chrono::Duration::days(days))?;
+            Some(json!(date.format(DELTA_DATE_FORMAT).to_string()))
+        }
+        // timestamps are written in microseconds
+        (Bound::Integer(micros), DataType::Timestamp(_, _)) => {
+            let timestamp = DateTime::from_timestamp_micros(micros)?;
+            Some(json!(timestamp.format(DELTA_TIMESTAMP_FORMAT).to_string()))
+        }
+        // statistics of decimals are unscaled, their doubles are only exact up to 15 digits
+        (Bound::Integer(value), DataType::Decimal128(precision, scale)) => {
+            match *precision <= DELTA_MAX_DECIMAL_STATS_PRECISION {
+                true => format_decimal(value as i128, *scale as u8)
+                    .parse::<f64>()
+                    .ok()
+                    .map(|value| json!(value)),
+                false => None,
+            }
+        }
+        (Bound::Boolean(value), _) => Some(json!(value)),
+        (Bound::Integer(value), _) => Some(json!(value)),
+        (Bound::Double(value), _) => Some(json!(value)),
+        (Bound::String(value), _) => Some(json!(value)),
+    }
+}
+
+/// Statistics of a data file, read from its footer.
+/// Booleans have no minimum and maximum in Delta statistics.
+fn get_file_stats(path: &Path, schema: &Schema) -> Result<JsonValue, FakeLakeError> {
+    let statistics = read_file_statistics(path)?;
+
+    let mut min_values = Map::new();
+    let mut max_values = Map::new();
+    let mut null_count = Map::new();
+    for (field, column) in schema.fields().iter().zip(statistics.columns) {
+        if let (false, Some((min, max))) = (field.data_type() == &DataType::Boolean, column.bounds)
+        {
+            if let Some(min) = get_stats_value(min, field.data_type()) {
+                min_values.insert(field.name().to_string(), min);
+            }
+            if let Some(max) = get_stats_value(max, field.data_type()) {
+                max_values.insert(field.name().to_string(), max);
+            }
+        }
+        if let Some(nulls) = column.null_count {
+            null_count.insert(field.name().to_string(), json!(nulls));
+        }
+    }
+
+    Ok(json!({
+        "numRecords": statistics.rows,
+        "minValues": min_values,
+        "maxValues": max_values,
+        "nullCount": null_count,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use parquet::file::properties::EnabledStatistics;
+    use yaml_rust::YamlLoader;
+
+    fn get_column(
+        name: &str,
+        provider: Box<dyn crate::providers::provider::Provider>,
+        presence: f64,
+    ) -> Column {
+        Column {
+            name: name.to_string(),
+            provider,
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: {}\npresence: {}", name, presence))
+                    .unwrap()[0],
+            ),
+            path: None,
+        }
+    }
+
+    fn get_config(
+        columns: Vec<Column>,
+        name: &str,
+        rows: Option<u32>,
+        partition_by: Option<Vec<String>>,
+    ) -> Config {
+        // tables are not overwritten
+        std::fs::remove_dir_all(name).ok();
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: Some(name.to_string()),
+                output_format: Some(OutputType::Delta(ParquetOptions::default())),
+                rows,
+                seed: None,
+                partition_by,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    fn read_log(name: &str) -> Vec<JsonValue> {
+        std::fs::read_to_string(format!("{}/_delta_log/{}", name, DELTA_FIRST_COMMIT))
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputDelta::new(ParquetOptions::default());
+        assert_eq!(output.get_extension(), ".parquet");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(vec![], "target/test_generated/delta_empty", None, None);
+        let output = OutputDelta::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_stdout_should_error() {
+        let mut config = get_config(
+            vec![get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                1.0,
+            )],
+            "target/test_generated/delta_stdout",
+            Some(1),
+            None,
+        );
+        config.info.as_mut().unwrap().output_name = Some("-".to_string());
+        let output = OutputDelta::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_existing_table_should_error() {
+        let config = get_config(
+            vec![get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                1.0,
+            )],
+            "target/test_generated/delta_existing",
+            Some(1),
+            None,
+        );
+        let output = OutputDelta::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_write_log_with_schema_and_stats() {
+        let name = "target/test_generated/delta_stats";
+        let columns = vec![
+            get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 5, step: 1 }),
+                1.0,
+            ),
+            get_column("bool", Box::new(BoolProvider {}), 1.0),
+            get_column(
+                "string",
+                Box::new(AlphanumericProvider {
+                    min_length: 1,
+                    max_length: 5,
+                }),
+                0.5,
+            ),
+            get_column(
+                "date",
+                Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 738163,
+                    before: 738173,
+                }),
+                1.0,
+            ),
+        ];
+        let mut config = get_config(columns, name, Some(100), None);
+        config.info.as_mut().unwrap().max_rows_per_file = Some(60);
+        let output = OutputDelta::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let log = read_log(name);
+        assert_eq!(log.len(), 5);
+        assert_eq!(log[0]["commitInfo"]["operation"], "CREATE TABLE");
+        assert_eq!(
+            log[1]["protocol"],
+            json!({"minReaderVersion": 1, "minWriterVersion": 2})
+        );
+        assert_eq!(log[2]["metaData"]["partitionColumns"], json!([]));
+        let schema: JsonValue =
+            serde_json::from_str(log[2]["metaData"]["schemaString"].as_str().unwrap()).unwrap();
+        assert_eq!(
+            schema["fields"][0],
+            json!({"name": "id", "type": "integer", "nullable": false, "metadata": {}})
+        );
+        assert_eq!(schema["fields"][2]["nullable"], true);
+        assert_eq!(schema["fields"][3]["type"], "date");
+
+        assert_eq!(log[3]["add"]["path"], "part-00000.parquet");
+        assert_eq!(log[4]["add"]["path"], "part-00001.parquet");
+        let stats: JsonValue =
+            serde_json::from_str(log[4]["add"]["stats"].as_str().unwrap()).unwrap();
+        assert_eq!(stats["numRecords"], 40);
+        assert_eq!(stats["minValues"]["id"], 65);
+        assert_eq!(stats["maxValues"]["id"], 104);
+        assert_eq!(stats["minValues"].get("bool"), None);
+        assert_eq!(stats["nullCount"]["bool"], 0);
+        assert!(stats["nullCount"]["string"].as_u64().unwrap() > 0);
+        assert!(stats["minValues"]["date"]
+            .as_str()
+            .unwrap()
+            .starts_with("2022-01-"));
+        assert_eq!(
+            log[4]["add"]["size"],
+            std::fs::metadata(format!("{}/part-00001.parquet", name))
+                .unwrap()
+                .len()
+        );
+    }
+
+    #[test]
+    fn given_partitions_should_write_partition_values() {
+        let name = "target/test_generated/delta_partitioned";
+        let columns = vec![
+            get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                1.0,
+            ),
+            get_column(
+                "created",
+                Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 1_000_000,
+                    before: 1_000_001,
+                }),
+                0.0,
+            ),
+        ];
+        let config = get_config(columns, name, Some(10), Some(vec!["created".to_string()]));
+        let output = OutputDelta::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let log = read_log(name);
+        assert_eq!(log.len(), 4);
+        assert_eq!(log[1]["protocol"]["minReaderVersion"], 3);
+        assert_eq!(log[2]["metaData"]["partitionColumns"], json!(["created"]));
+        assert_eq!(
+            log[3]["add"]["path"],
+            "created=__HIVE_DEFAULT_PARTITION__/part-00000.parquet"
+        );
+        assert_eq!(log[3]["add"]["partitionValues"], json!({ "created": null }));
+    }
+
+    #[test]
+    fn given_disabled_statistics_should_only_count_records() {
+        let name = "target/test_generated/delta_no_statistics";
+        let config = get_config(
+            vec![get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                1.0,
+            )],
+            name,
+            Some(10),
+            None,
+        );
+        let output = OutputDelta::new(ParquetOptions {
+            statistics: EnabledStatistics::None,
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let log = read_log(name);
+        let stats: JsonValue =
+            serde_json::from_str(log[3]["add"]["stats"].as_str().unwrap()).unwrap();
+        assert_eq!(stats["numRecords"], 10);
+        assert_eq!(stats["minValues"], json!({}));
+    }
+
+    #[test]
+    fn given_values_should_return_stats_values() {
+        assert_eq!(
+            get_stats_value(Bound::Integer(19000), &DataType::Date32),
+            Some(json!("2022-01-08"))
+        );
+        assert_eq!(
+            get_stats_value(
+                Bound::Integer(1_000_000_000_000_000),
+                &DataType::Timestamp(arrow_schema::TimeUnit::Second, None)
+            ),
+            Some(json!("2001-09-09T01:46:40.000"))
+        );
+        assert_eq!(
+            get_stats_value(Bound::Integer(3), &DataType::Int32),
+            Some(json!(3))
+        );
+        assert_eq!(
+            get_stats_value(Bound::String("a".to_string()), &DataType::Utf8),
+            Some(json!("a"))
+        );
+        assert_eq!(
+            get_stats_value(Bound::Integer(-12345), &DataType::Decimal128(10, 2)),
+            Some(json!(-123.45))
+        );
+        assert_eq!(
+            get_stats_value(Bound::Integer(1), &DataType::Decimal128(18, 2)),
+            None
+        );
+    }
+
+    #[test]
+    fn given_decimal_should_return_delta_type() {
+        assert_eq!(
+            get_delta_type(&DataType::Decimal128(10, 2)),
+            "decimal(10,2)"
+        );
+        assert_eq!(get_delta_type(&DataType::Int64), "long");
+    }
+
+    #[test]
+    fn given_escaped_path_should_return_uri() {
+        assert_eq!(
+            get_uri_path(Path::new("a=b%3Dc/part-00000.parquet")),
+            "a=b%253Dc/part-00000.parquet"
+        );
+        assert_eq!(get_uri_path(Path::new("a=b c/p")), "a=b%20c/p");
+    }
+}
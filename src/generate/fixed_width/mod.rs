@@ -0,0 +1,307 @@
+pub mod options;
+
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+use options::FixedWidthOptions;
+
+use log::warn;
+use std::io::{BufWriter, Write};
+
+const FIXED_WIDTH_EXTENSION: &str = ".txt";
+
+#[derive(Debug, PartialEq)]
+pub struct OutputFixedWidth {
+    options: FixedWidthOptions,
+}
+
+impl OutputFixedWidth {
+    pub fn new(options: FixedWidthOptions) -> OutputFixedWidth {
+        OutputFixedWidth { options }
+    }
+}
+
+impl OutputFormat for OutputFixedWidth {
+    fn get_extension(&self) -> &str {
+        FIXED_WIDTH_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let mut widths = Vec::with_capacity(config.columns.len());
+        for column in &config.columns {
+            match self.options.get_column_width(&column.name) {
+                Some(width) => widths.push(width),
+                None => {
+                    return Err(FakeLakeError::BadYAMLFormat(format!(
+                        "Column {} should have a width to be written in a fixed width file",
+                        column.name
+                    )))
+                }
+            }
+        }
+        for width in &self.options.columns {
+            if !config
+                .columns
+                .iter()
+                .any(|column| column.name == width.name)
+            {
+                warn!(
+                    "fixed width settings of column {} are ignored as it is not generated.",
+                    width.name
+                );
+            }
+        }
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let mut buffer = BufWriter::new(OutputStream::create(file_name)?);
+        let rows = config.get_number_of_rows();
+
+        let mut line = String::new();
+        for i in 0..rows {
+            line.clear();
+            for (column, width) in config.columns.iter().zip(&widths) {
+                let mut str_value = String::new();
+                if column.is_next_present() {
+                    str_value = match column.provider.value(i) {
+                        Value::Bool(value) => value.to_string(),
+                        Value::Int32(value) => value.to_string(),
+                        Value::Int64(value) => value.to_string(),
+                        Value::Decimal(value, _, scale) => format_decimal(value, scale),
+                        Value::Float64(value) => value.to_string(),
+                        Value::String(value) => value,
+                        Value::Date(value, date_format) => value.format(&date_format).to_string(),
+                        Value::Timestamp(value, date_format) => {
+                            value.format(&date_format).to_string()
+                        }
+                    };
+                }
+
+                match width.format(&str_value, self.options.truncate) {
+                    Some(field) => line.push_str(&field),
+                    None => {
+                        return Err(FakeLakeError::BadYAMLFormat(format!(
+                            "Value {} of column {} is longer than its width {}",
+                            str_value, column.name, width.width
+                        )))
+                    }
+                }
+            }
+            line.push('\n');
+            buffer.write_all(line.as_bytes())?;
+        }
+
+        buffer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_options(yaml: &str) -> FixedWidthOptions {
+        FixedWidthOptions::new_from_yaml(&YamlLoader::load_from_str(yaml).unwrap()[0])
+    }
+
+    fn get_column(name: &str, start: i32, presence: f64) -> Column {
+        Column {
+            name: name.to_string(),
+            provider: Box::new(IncrementIntegerProvider { start, step: 1 }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: id\npresence: {}", presence)).unwrap()
+                    [0],
+            ),
+            path: None,
+        }
+    }
+
+    fn get_config(columns: Vec<Column>, name: Option<String>, rows: Option<u32>) -> Config {
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::FixedWidth(FixedWidthOptions::default())),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputFixedWidth::new(FixedWidthOptions::default());
+        assert_eq!(output.get_extension(), ".txt");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(vec![], None, None);
+        let output = OutputFixedWidth::new(FixedWidthOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_column_without_width_should_error() {
+        let config = get_config(vec![get_column("id", 0, 1.0)], None, Some(1));
+        let output = OutputFixedWidth::new(get_options("columns:\n  other:\n    width: 2"));
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = Config {
+            columns: vec![get_column("id", 0, 1.0)],
+            info: None,
+        };
+        let output = OutputFixedWidth::new(get_options("columns:\n  id:\n    width: 10"));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_write_expected_file() {
+        let config = get_config(
+            vec![
+                get_column("id", 8, 1.0),
+                get_column("missing", 0, 0.0),
+                get_column("index", 0, 1.0),
+            ],
+            Some("target/test_generated/output_fixed_width".to_string()),
+            Some(3),
+        );
+        let output = OutputFixedWidth::new(get_options(
+            "
+            columns:
+              id:
+                width: 3
+                align: right
+                pad: 0
+              missing:
+                width: 2
+                pad: '.'
+              index:
+                width: 4
+            ",
+        ));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "008..0   \n009..1   \n010..2   \n",
+            std::fs::read_to_string("target/test_generated/output_fixed_width.txt").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_overflow_should_error_unless_truncated() {
+        let config = get_config(
+            vec![get_column("id", 98, 1.0)],
+            Some("target/test_generated/output_fixed_width_overflow".to_string()),
+            Some(3),
+        );
+        let output = OutputFixedWidth::new(get_options("columns:\n  id:\n    width: 2"));
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+
+        let output = OutputFixedWidth::new(get_options(
+            "overflow: truncate\ncolumns:\n  id:\n    width: 2",
+        ));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+        assert_eq!(
+            "98\n99\n10\n",
+            std::fs::read_to_string("target/test_generated/output_fixed_width_overflow.txt")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn given_dates_and_strings_should_write_fields() {
+        let columns = vec![
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%d/%m/%Y".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: date\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 1,
+                    max_length: 10,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: string\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+        let config = get_config(
+            columns,
+            Some("target/test_generated/output_fixed_width_all".to_string()),
+            Some(1000),
+        );
+        let output = OutputFixedWidth::new(get_options(
+            "
+            columns:
+              date:
+                width: 10
+              string:
+                width: 12
+                align: right
+            ",
+        ));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let content =
+            std::fs::read_to_string("target/test_generated/output_fixed_width_all.txt").unwrap();
+        assert_eq!(content.lines().count(), 1000);
+        for line in content.lines() {
+            assert_eq!(line.len(), 22);
+            assert_eq!(&line[2..3], "/");
+            assert_eq!(&line[10..12], "  ");
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::config::parse_positive_integer;
+
+use log::warn;
+use yaml_rust::Yaml;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Fixed width settings of the info section
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FixedWidthOptions {
+    /// Values longer than their field are truncated instead of failing the generation
+    pub truncate: bool,
+    pub columns: Vec<ColumnWidth>,
+}
+
+/// Field of one column in fixed width files
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnWidth {
+    pub name: String,
+    /// Number of chars of the field
+    pub width: usize,
+    pub align: Align,
+    pub pad: char,
+}
+
+impl FixedWidthOptions {
+    pub fn new_from_yaml(section_info: &Yaml) -> FixedWidthOptions {
+        let mut options = FixedWidthOptions::default();
+
+        match section_info["overflow"].as_str() {
+            Some("truncate") => options.truncate = true,
+            Some("error") => (),
+            None if section_info["overflow"].is_badvalue() => (),
+            _ => warn!("Overflow should be truncate or error. Default 'error' is taken."),
+        }
+
+        match &section_info["columns"] {
+            Yaml::Hash(columns) => {
+                for (name, column) in columns {
+                    match name.as_str() {
+                        Some(name) => {
+                            if let Some(width) = ColumnWidth::new_from_yaml(name, column) {
+                                options.columns.push(width);
+                            }
+                        }
+                        None => warn!("fixed width columns should be a map of column names."),
+                    }
+                }
+            }
+            Yaml::BadValue => (),
+            _ => warn!("fixed width columns should be a map of column names."),
+        }
+
+        options
+    }
+
+    pub fn get_column_width(&self, name: &str) -> Option<&ColumnWidth> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
+
+impl ColumnWidth {
+    fn new_from_yaml(name: &str, column: &Yaml) -> Option<ColumnWidth> {
+        let width = match parse_positive_integer(&column["width"], false) {
+            Some(width) => width as usize,
+            None => {
+                warn!(
+                    "width of column {} should be a positive integer. It is ignored.",
+                    name
+                );
+                return None;
+            }
+        };
+
+        let align = match column["align"].as_str() {
+            Some("left") => Align::Left,
+            Some("right") => Align::Right,
+            None if column["align"].is_badvalue() => Align::Left,
+            _ => {
+                warn!(
+                    "align of column {} should be left or right. Default 'left' is taken.",
+                    name
+                );
+                Align::Left
+            }
+        };
+
+        // pad could be a char or a digit like 0
+        let pad = match &column["pad"] {
+            Yaml::String(pad) if pad.chars().count() == 1 => pad.chars().next().unwrap(),
+            Yaml::Integer(pad) if (0..=9).contains(pad) => {
+                char::from_digit(*pad as u32, 10).unwrap()
+            }
+            Yaml::BadValue => ' ',
+            _ => {
+                warn!(
+                    "pad of column {} should be one char. Default ' ' is taken.",
+                    name
+                );
+                ' '
+            }
+        };
+
+        Some(ColumnWidth {
+            name: name.to_string(),
+            width,
+            align,
+            pad,
+        })
+    }
+
+    /// Pads the value to the width, None when it is too long
+    pub fn format(&self, value: &str, truncate: bool) -> Option<String> {
+        let length = value.chars().count();
+        if length > self.width {
+            return match truncate {
+                true => Some(value.chars().take(self.width).collect()),
+                false => None,
+            };
+        }
+
+        let padding: String = std::iter::repeat_n(self.pad, self.width - length).collect();
+        match self.align {
+            Align::Left => Some(format!("{}{}", value, padding)),
+            // zeros are written after the sign of negative numbers
+            Align::Right if self.pad == '0' && value.starts_with('-') => {
+                Some(format!("-{}{}", padding, &value[1..]))
+            }
+            Align::Right => Some(format!("{}{}", padding, value)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_options(yaml: &str) -> FixedWidthOptions {
+        let parsed_yaml = &YamlLoader::load_from_str(yaml).unwrap()[0];
+        FixedWidthOptions::new_from_yaml(parsed_yaml)
+    }
+
+    fn get_width(width: usize, align: Align, pad: char) -> ColumnWidth {
+        ColumnWidth {
+            name: "id".to_string(),
+            width,
+            align,
+            pad,
+        }
+    }
+
+    #[test]
+    fn given_no_settings_should_return_default() {
+        assert_eq!(
+            get_options("output_format: fixed_width"),
+            FixedWidthOptions::default()
+        );
+    }
+
+    #[test]
+    fn given_overflow_should_return_truncate() {
+        assert!(get_options("overflow: truncate").truncate);
+        assert!(!get_options("overflow: error").truncate);
+        assert!(!get_options("overflow: ignore").truncate);
+    }
+
+    #[test]
+    fn given_columns_should_return_widths() {
+        let options = get_options(
+            "
+            columns:
+              id:
+                width: 8
+                align: right
+                pad: 0
+              name:
+                width: 5
+              code:
+                width: 3
+                align: center
+                pad: ab
+              other:
+                width: -2
+            ",
+        );
+        assert_eq!(
+            options.columns,
+            vec![
+                ColumnWidth {
+                    name: "id".to_string(),
+                    width: 8,
+                    align: Align::Right,
+                    pad: '0',
+                },
+                ColumnWidth {
+                    name: "name".to_string(),
+                    width: 5,
+                    align: Align::Left,
+                    pad: ' ',
+                },
+                ColumnWidth {
+                    name: "code".to_string(),
+                    width: 3,
+                    align: Align::Left,
+                    pad: ' ',
+                },
+            ]
+        );
+        assert_eq!(options.get_column_width("name"), Some(&options.columns[1]));
+        assert_eq!(options.get_column_width("other"), None);
+    }
+
+    #[test]
+    fn given_invalid_columns_should_return_no_widths() {
+        assert_eq!(get_options("columns: [id]").columns, vec![]);
+    }
+
+    #[test]
+    fn given_value_should_be_padded() {
+        let width = get_width(5, Align::Left, '*');
+        assert_eq!(width.format("ab", false), Some("ab***".to_string()));
+        assert_eq!(width.format("", false), Some("*****".to_string()));
+        assert_eq!(width.format("éèà", false), Some("éèà**".to_string()));
+
+        let width = get_width(5, Align::Right, '0');
+        assert_eq!(width.format("42", false), Some("00042".to_string()));
+        assert_eq!(width.format("-42", false), Some("-0042".to_string()));
+    }
+
+    #[test]
+    fn given_too_long_value_should_truncate_or_fail() {
+        let width = get_width(3, Align::Right, ' ');
+        assert_eq!(width.format("abcde", true), Some("abc".to_string()));
+        assert_eq!(width.format("abcde", false), None);
+        assert_eq!(width.format("abc", false), Some("abc".to_string()));
+    }
+}
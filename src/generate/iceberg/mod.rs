@@ -0,0 +1,1152 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::{parse_partition_value, OutputFiles, WrittenFile};
+use crate::generate::output_format::OutputFormat;
+use crate::generate::parquet::get_schema_from_config;
+use crate::generate::parquet::options::ParquetOptions;
+use crate::generate::parquet::statistics::{read_file_statistics, Bound};
+use crate::generate::parquet::{DataFileSettings, OutputParquet};
+use crate::providers::utils::decimal::unscaled_bytes;
+
+use apache_avro::types::Value as av;
+use apache_avro::{Schema as AvroSchema, Writer};
+use arrow_schema::{DataType, Schema, TimeUnit};
+use chrono::{NaiveDate, NaiveDateTime};
+use serde_json::{json, Value as JsonValue};
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const ICEBERG_EXTENSION: &str = ".parquet";
+const ICEBERG_DATA_DIRECTORY: &str = "data";
+const ICEBERG_METADATA_DIRECTORY: &str = "metadata";
+/// Hadoop catalogs find the current metadata file from the version hint
+const ICEBERG_VERSION_HINT: &str = "version-hint.text";
+const ICEBERG_FORMAT_VERSION: u8 = 2;
+/// Partition field ids start after the ids of the columns
+const ICEBERG_FIRST_PARTITION_ID: usize = 1000;
+
+const ICEBERG_DATE_FORMAT: &str = "%Y-%m-%d";
+/// Format of timestamp partition values, given by arrow
+const ICEBERG_PARTITION_TIMESTAMP_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// Status of manifest entries of added files
+const MANIFEST_ENTRY_ADDED: i32 = 1;
+
+#[derive(Debug, PartialEq)]
+pub struct OutputIceberg {
+    parquet: OutputParquet,
+}
+
+impl OutputIceberg {
+    pub fn new(options: ParquetOptions) -> OutputIceberg {
+        OutputIceberg {
+            parquet: OutputParquet::new_with_settings(
+                options,
+                DataFileSettings {
+                    timestamp_unit: TimeUnit::Microsecond,
+                    field_ids: true,
+                    partition_columns: true,
+                    uuid_columns: false,
+                },
+            ),
+        }
+    }
+}
+
+/// Ids and paths of the snapshot creating the table
+struct Snapshot {
+    table_uuid: String,
+    snapshot_id: i64,
+    timestamp_ms: i64,
+    location: String,
+    manifest_list_path: String,
+    manifest_path: String,
+}
+
+impl Snapshot {
+    /// With a seed, the same ids are given to the table at each generation
+    fn new(config: &Config, table_path: &Path) -> Result<Snapshot, FakeLakeError> {
+        let mut rng = match config.info.as_ref().and_then(|info| info.seed) {
+            Some(seed) => fastrand::Rng::with_seed(seed),
+            None => fastrand::Rng::new(),
+        };
+        let mut new_uuid = || {
+            uuid::Builder::from_random_bytes(rng.u128(..).to_le_bytes())
+                .into_uuid()
+                .to_string()
+        };
+        let table_uuid = new_uuid();
+        let commit_uuid = new_uuid();
+        let snapshot_id = rng.i64(1..i64::MAX);
+
+        let location = format!("file:{}", std::path::absolute(table_path)?.display());
+        let metadata_location = format!("{}/{}", location, ICEBERG_METADATA_DIRECTORY);
+        Ok(Snapshot {
+            table_uuid,
+            snapshot_id,
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+            manifest_list_path: format!(
+                "{}/snap-{}-1-{}.avro",
+                metadata_location, snapshot_id, commit_uuid
+            ),
+            manifest_path: format!("{}/{}-m0.avro", metadata_location, commit_uuid),
+            location,
+        })
+    }
+}
+
+/// Data file added to the table, with its partition values
+struct DataFile {
+    size: u64,
+    partition: Vec<Option<Bound>>,
+    manifest_entry: av,
+    rows: u64,
+}
+
+impl OutputFormat for OutputIceberg {
+    fn get_extension(&self) -> &str {
+        ICEBERG_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+        if config.is_stdout() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "Iceberg tables can't be written to standard output".to_string(),
+            ));
+        }
+
+        // the table is the output name directory
+        let table_path = PathBuf::from(config.get_output_file_name(""));
+        let metadata_path = table_path.join(ICEBERG_METADATA_DIRECTORY);
+        if metadata_path.exists() {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Iceberg table {} already exists",
+                table_path.display()
+            )));
+        }
+
+        let files = OutputFiles::new_in_directory(
+            config,
+            self.get_extension(),
+            &table_path.join(ICEBERG_DATA_DIRECTORY),
+        )?;
+        let partition_indexes = files.partition_indexes().to_vec();
+        let schema = get_schema_from_config(config);
+        for index in &partition_indexes {
+            if let DataType::Decimal128(_, _) = schema.field(*index).data_type() {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Iceberg tables can't be partitioned by the decimal column {}",
+                    schema.field(*index).name()
+                )));
+            }
+        }
+        let written_files = self.parquet.write_files(config, files)?;
+
+        let snapshot = Snapshot::new(config, &table_path)?;
+        let mut data_files = Vec::with_capacity(written_files.len());
+        for written_file in &written_files {
+            data_files.push(get_data_file(
+                written_file,
+                &schema,
+                &partition_indexes,
+                &snapshot,
+                &table_path,
+            )?);
+        }
+
+        std::fs::create_dir_all(&metadata_path)?;
+        let manifest = write_manifest(&schema, &partition_indexes, &data_files)?;
+        let manifest_length = manifest.len() as u64;
+        write_file(&metadata_path, &snapshot.manifest_path, &manifest)?;
+        let manifest_list = write_manifest_list(
+            &schema,
+            &partition_indexes,
+            &data_files,
+            &snapshot,
+            manifest_length,
+        )?;
+        write_file(&metadata_path, &snapshot.manifest_list_path, &manifest_list)?;
+
+        let metadata = get_table_metadata(&schema, &partition_indexes, &data_files, &snapshot);
+        write_file(
+            &metadata_path,
+            "v1.metadata.json",
+            metadata.to_string().as_bytes(),
+        )?;
+        write_file(&metadata_path, ICEBERG_VERSION_HINT, b"1")
+    }
+}
+
+/// Writes the file in the metadata directory, the name being the end of location
+fn write_file(metadata_path: &Path, location: &str, content: &[u8]) -> Result<(), FakeLakeError> {
+    let file_name = location.rsplit('/').next().unwrap_or(location);
+    let mut file = File::create(metadata_path.join(file_name))?;
+    file.write_all(content)?;
+    file.flush()?;
+    Ok(())
+}
+
+fn get_iceberg_type(data_type: &DataType) -> String {
+    let iceberg_type = match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int32 => "int",
+        DataType::Int64 => "long",
+        DataType::Decimal128(precision, scale) => {
+            return format!("decimal({}, {})", precision, scale)
+        }
+        DataType::Float64 => "double",
+        DataType::Date32 => "date",
+        DataType::Timestamp(_, _) => "timestamp",
+        _ => "string",
+    };
+    iceberg_type.to_string()
+}
+
+/// Columns get their index in the config, starting at 1, as field id
+fn get_schema_json(schema: &Schema) -> JsonValue {
+    let fields: Vec<JsonValue> = schema
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            json!({
+                "id": index + 1,
+                "name": field.name(),
+                "required": !field.is_nullable(),
+                "type": get_iceberg_type(field.data_type()),
+            })
+        })
+        .collect();
+    json!({"type": "struct", "schema-id": 0, "fields": fields})
+}
+
+/// Identity partition fields of the partition columns
+fn get_partition_spec_fields(schema: &Schema, partition_indexes: &[usize]) -> JsonValue {
+    let fields: Vec<JsonValue> = partition_indexes
+        .iter()
+        .enumerate()
+        .map(|(position, index)| {
+            json!({
+                "name": schema.field(*index).name(),
+                "transform": "identity",
+                "source-id": index + 1,
+                "field-id": ICEBERG_FIRST_PARTITION_ID + position,
+            })
+        })
+        .collect();
+    JsonValue::Array(fields)
+}
+
+/// Avro names can only have letters, digits and '_', as Iceberg
+/// other chars are replaced by their hexadecimal code
+fn get_avro_name(name: &str) -> String {
+    let mut avro_name = String::with_capacity(name.len());
+    for (index, c) in name.chars().enumerate() {
+        match c {
+            'a'..='z' | 'A'..='Z' | '_' => avro_name.push(c),
+            '0'..='9' if index > 0 => avro_name.push(c),
+            '0'..='9' => {
+                avro_name.push('_');
+                avro_name.push(c);
+            }
+            c => avro_name.push_str(&format!("_x{:X}", c as u32)),
+        }
+    }
+    avro_name
+}
+
+/// Value of a partition from its directory value
+fn get_partition_bound(value: &str, data_type: &DataType) -> Option<Bound> {
+    let value = parse_partition_value(value)?;
+    match data_type {
+        DataType::Boolean => value.parse().ok().map(Bound::Boolean),
+        DataType::Int32 | DataType::Int64 => value.parse().ok().map(Bound::Integer),
+        DataType::Float64 => value.parse().ok().map(Bound::Double),
+        DataType::Date32 => {
+            let date = NaiveDate::parse_from_str(&value, ICEBERG_DATE_FORMAT).ok()?;
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1)?;
+            Some(Bound::Integer((date - epoch).num_days()))
+        }
+        DataType::Timestamp(_, _) => {
+            let timestamp =
+                NaiveDateTime::parse_from_str(&value, ICEBERG_PARTITION_TIMESTAMP_FORMAT).ok()?;
+            Some(Bound::Integer(timestamp.and_utc().timestamp_micros()))
+        }
+        _ => Some(Bound::String(value)),
+    }
+}
+
+/// Single-value serialization of Iceberg, used by bounds
+fn get_bound_bytes(bound: &Bound, data_type: &DataType) -> Option<Vec<u8>> {
+    match (bound, data_type) {
+        (Bound::Boolean(value), _) => Some(vec![*value as u8]),
+        (Bound::Integer(value), DataType::Int32 | DataType::Date32) => {
+            Some((*value as i32).to_le_bytes().to_vec())
+        }
+        // timestamps are written in microseconds
+        (Bound::Integer(value), DataType::Int64 | DataType::Timestamp(_, _)) => {
+            Some(value.to_le_bytes().to_vec())
+        }
+        // decimals are unscaled, in big-endian
+        (Bound::Integer(value), DataType::Decimal128(_, _)) => Some(unscaled_bytes(*value as i128)),
+        (Bound::Double(value), _) => Some(value.to_le_bytes().to_vec()),
+        (Bound::String(value), _) => Some(value.as_bytes().to_vec()),
+        _ => None,
+    }
+}
+
+fn get_partition_avro_value(bound: &Option<Bound>, data_type: &DataType) -> av {
+    let value = match (bound, data_type) {
+        (None, _) => return av::Union(0, Box::new(av::Null)),
+        (Some(Bound::Boolean(value)), _) => av::Boolean(*value),
+        (Some(Bound::Integer(value)), DataType::Int64 | DataType::Timestamp(_, _)) => {
+            av::Long(*value)
+        }
+        (Some(Bound::Integer(value)), _) => av::Int(*value as i32),
+        (Some(Bound::Double(value)), _) => av::Double(*value),
+        (Some(Bound::String(value)), _) => av::String(value.clone()),
+    };
+    av::Union(1, Box::new(value))
+}
+
+fn get_avro_partition_type(data_type: &DataType) -> &str {
+    match data_type {
+        DataType::Boolean => "boolean",
+        DataType::Int32 | DataType::Date32 => "int",
+        DataType::Float64 => "double",
+        DataType::Int64 | DataType::Timestamp(_, _) => "long",
+        _ => "string",
+    }
+}
+
+/// Maps with int keys are written as arrays of key/value records
+fn get_avro_map_schema(field_id: u32, key_id: u32, value_type: &str, name: &str) -> JsonValue {
+    json!({
+        "name": name,
+        "type": ["null", {
+            "type": "array",
+            "items": {
+                "type": "record",
+                "name": format!("k{}_v{}", key_id, key_id + 1),
+                "fields": [
+                    {"name": "key", "type": "int", "field-id": key_id},
+                    {"name": "value", "type": value_type, "field-id": key_id + 1},
+                ],
+            },
+            "logicalType": "map",
+        }],
+        "default": null,
+        "field-id": field_id,
+    })
+}
+
+fn get_avro_map(values: Vec<(usize, av)>) -> av {
+    let entries = values
+        .into_iter()
+        .map(|(key, value)| {
+            av::Record(vec![
+                ("key".to_string(), av::Int(key as i32)),
+                ("value".to_string(), value),
+            ])
+        })
+        .collect();
+    av::Union(1, Box::new(av::Array(entries)))
+}
+
+fn get_manifest_schema(schema: &Schema, partition_indexes: &[usize]) -> JsonValue {
+    let partition_fields: Vec<JsonValue> = partition_indexes
+        .iter()
+        .enumerate()
+        .map(|(position, index)| {
+            let field = schema.field(*index);
+            json!({
+                "name": get_avro_name(field.name()),
+                "type": ["null", get_avro_partition_type(field.data_type())],
+                "default": null,
+                "field-id": ICEBERG_FIRST_PARTITION_ID + position,
+            })
+        })
+        .collect();
+
+    json!({
+        "type": "record",
+        "name": "manifest_entry",
+        "fields": [
+            {"name": "status", "type": "int", "field-id": 0},
+            {"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1},
+            {"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3},
+            {"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4},
+            {"name": "data_file", "type": {
+                "type": "record",
+                "name": "r2",
+                "fields": [
+                    {"name": "content", "type": "int", "field-id": 134},
+                    {"name": "file_path", "type": "string", "field-id": 100},
+                    {"name": "file_format", "type": "string", "field-id": 101},
+                    {"name": "partition", "type": {
+                        "type": "record",
+                        "name": "r102",
+                        "fields": partition_fields,
+                    }, "field-id": 102},
+                    {"name": "record_count", "type": "long", "field-id": 103},
+                    {"name": "file_size_in_bytes", "type": "long", "field-id": 104},
+                    get_avro_map_schema(108, 117, "long", "column_sizes"),
+                    get_avro_map_schema(109, 119, "long", "value_counts"),
+                    get_avro_map_schema(110, 121, "long", "null_value_counts"),
+                    get_avro_map_schema(125, 126, "bytes", "lower_bounds"),
+                    get_avro_map_schema(128, 129, "bytes", "upper_bounds"),
+                ],
+            }, "field-id": 2},
+        ],
+    })
+}
+
+fn get_manifest_list_schema() -> JsonValue {
+    json!({
+        "type": "record",
+        "name": "manifest_file",
+        "fields": [
+            {"name": "manifest_path", "type": "string", "field-id": 500},
+            {"name": "manifest_length", "type": "long", "field-id": 501},
+            {"name": "partition_spec_id", "type": "int", "field-id": 502},
+            {"name": "content", "type": "int", "field-id": 517},
+            {"name": "sequence_number", "type": "long", "field-id": 515},
+            {"name": "min_sequence_number", "type": "long", "field-id": 516},
+            {"name": "added_snapshot_id", "type": "long", "field-id": 503},
+            {"name": "added_files_count", "type": "int", "field-id": 504},
+            {"name": "existing_files_count", "type": "int", "field-id": 505},
+            {"name": "deleted_files_count", "type": "int", "field-id": 506},
+            {"name": "added_rows_count", "type": "long", "field-id": 512},
+            {"name": "existing_rows_count", "type": "long", "field-id": 513},
+            {"name": "deleted_rows_count", "type": "long", "field-id": 514},
+            {"name": "partitions", "type": ["null", {
+                "type": "array",
+                "items": {
+                    "type": "record",
+                    "name": "r508",
+                    "fields": [
+                        {"name": "contains_null", "type": "boolean", "field-id": 509},
+                        {"name": "contains_nan", "type": ["null", "boolean"], "default": null, "field-id": 518},
+                        {"name": "lower_bound", "type": ["null", "bytes"], "default": null, "field-id": 510},
+                        {"name": "upper_bound", "type": ["null", "bytes"], "default": null, "field-id": 511},
+                    ],
+                },
+                "element-id": 508,
+            }], "default": null, "field-id": 507},
+        ],
+    })
+}
+
+fn parse_avro_schema(schema: &JsonValue) -> Result<AvroSchema, FakeLakeError> {
+    match AvroSchema::parse(schema) {
+        Ok(value) => Ok(value),
+        Err(e) => Err(FakeLakeError::AvroError(e)),
+    }
+}
+
+/// Manifest entry of a written file, with the statistics of its columns
+fn get_data_file(
+    written_file: &WrittenFile,
+    schema: &Schema,
+    partition_indexes: &[usize],
+    snapshot: &Snapshot,
+    table_path: &Path,
+) -> Result<DataFile, FakeLakeError> {
+    let statistics = read_file_statistics(&written_file.path)?;
+    let size = std::fs::metadata(&written_file.path)?.len();
+    let relative_path = written_file
+        .path
+        .strip_prefix(table_path)
+        .unwrap_or(&written_file.path);
+    let path = format!("{}/{}", snapshot.location, relative_path.display());
+
+    let partition: Vec<Option<Bound>> = partition_indexes
+        .iter()
+        .zip(&written_file.partition_values)
+        .map(|(index, value)| get_partition_bound(value, schema.field(*index).data_type()))
+        .collect();
+    let partition_record: Vec<(String, av)> = partition_indexes
+        .iter()
+        .zip(&partition)
+        .map(|(index, bound)| {
+            let field = schema.field(*index);
+            (
+                get_avro_name(field.name()),
+                get_partition_avro_value(bound, field.data_type()),
+            )
+        })
+        .collect();
+
+    let mut column_sizes = Vec::new();
+    let mut value_counts = Vec::new();
+    let mut null_value_counts = Vec::new();
+    let mut lower_bounds = Vec::new();
+    let mut upper_bounds = Vec::new();
+    for (index, (field, column)) in schema.fields().iter().zip(statistics.columns).enumerate() {
+        let id = index + 1;
+        column_sizes.push((id, av::Long(column.size as i64)));
+        value_counts.push((id, av::Long(statistics.rows as i64)));
+        if let Some(nulls) = column.null_count {
+            null_value_counts.push((id, av::Long(nulls as i64)));
+        }
+        if let Some((min, max)) = column.bounds {
+            if let (Some(min), Some(max)) = (
+                get_bound_bytes(&min, field.data_type()),
+                get_bound_bytes(&max, field.data_type()),
+            ) {
+                lower_bounds.push((id, av::Bytes(min)));
+                upper_bounds.push((id, av::Bytes(max)));
+            }
+        }
+    }
+
+    let data_file = av::Record(vec![
+        ("content".to_string(), av::Int(0)),
+        ("file_path".to_string(), av::String(path)),
+        ("file_format".to_string(), av::String("PARQUET".to_string())),
+        ("partition".to_string(), av::Record(partition_record)),
+        ("record_count".to_string(), av::Long(statistics.rows as i64)),
+        ("file_size_in_bytes".to_string(), av::Long(size as i64)),
+        ("column_sizes".to_string(), get_avro_map(column_sizes)),
+        ("value_counts".to_string(), get_avro_map(value_counts)),
+        (
+            "null_value_counts".to_string(),
+            get_avro_map(null_value_counts),
+        ),
+        ("lower_bounds".to_string(), get_avro_map(lower_bounds)),
+        ("upper_bounds".to_string(), get_avro_map(upper_bounds)),
+    ]);
+    let manifest_entry = av::Record(vec![
+        ("status".to_string(), av::Int(MANIFEST_ENTRY_ADDED)),
+        (
+            "snapshot_id".to_string(),
+            av::Union(1, Box::new(av::Long(snapshot.snapshot_id))),
+        ),
+        (
+            "sequence_number".to_string(),
+            av::Union(1, Box::new(av::Long(1))),
+        ),
+        (
+            "file_sequence_number".to_string(),
+            av::Union(1, Box::new(av::Long(1))),
+        ),
+        ("data_file".to_string(), data_file),
+    ]);
+
+    Ok(DataFile {
+        size,
+        partition,
+        manifest_entry,
+        rows: statistics.rows,
+    })
+}
+
+fn write_avro(
+    schema: &AvroSchema,
+    metadata: Vec<(&str, String)>,
+    records: Vec<av>,
+) -> Result<Vec<u8>, FakeLakeError> {
+    let mut writer = Writer::new(schema, Vec::new());
+    for (key, value) in metadata {
+        if let Err(e) = writer.add_user_metadata(key.to_string(), value) {
+            return Err(FakeLakeError::AvroError(e));
+        }
+    }
+    for record in records {
+        if let Err(e) = writer.append(record) {
+            return Err(FakeLakeError::AvroError(e));
+        }
+    }
+    match writer.into_inner() {
+        Ok(value) => Ok(value),
+        Err(e) => Err(FakeLakeError::AvroError(e)),
+    }
+}
+
+fn write_manifest(
+    schema: &Schema,
+    partition_indexes: &[usize],
+    data_files: &[DataFile],
+) -> Result<Vec<u8>, FakeLakeError> {
+    let avro_schema = parse_avro_schema(&get_manifest_schema(schema, partition_indexes))?;
+    let metadata = vec![
+        ("schema", get_schema_json(schema).to_string()),
+        ("schema-id", "0".to_string()),
+        (
+            "partition-spec",
+            get_partition_spec_fields(schema, partition_indexes).to_string(),
+        ),
+        ("partition-spec-id", "0".to_string()),
+        ("format-version", ICEBERG_FORMAT_VERSION.to_string()),
+        ("content", "data".to_string()),
+    ];
+    let records = data_files
+        .iter()
+        .map(|data_file| data_file.manifest_entry.clone())
+        .collect();
+    write_avro(&avro_schema, metadata, records)
+}
+
+/// Summary of the values of each partition field in the manifest
+fn get_partition_summaries(
+    schema: &Schema,
+    partition_indexes: &[usize],
+    data_files: &[DataFile],
+) -> av {
+    let mut summaries = Vec::with_capacity(partition_indexes.len());
+    for (position, index) in partition_indexes.iter().enumerate() {
+        let data_type = schema.field(*index).data_type();
+        let values: Vec<&Option<Bound>> = data_files
+            .iter()
+            .map(|data_file| &data_file.partition[position])
+            .collect();
+        let contains_null = values.iter().any(|value| value.is_none());
+        let contains_nan = values
+            .iter()
+            .any(|value| matches!(value, Some(Bound::Double(value)) if value.is_nan()));
+
+        let mut bounds: Option<(&Bound, &Bound)> = None;
+        for value in values.iter().filter_map(|value| value.as_ref()) {
+            if matches!(value, Bound::Double(value) if value.is_nan()) {
+                continue;
+            }
+            bounds = match bounds {
+                Some((min, max)) => Some((
+                    if value < min { value } else { min },
+                    if value > max { value } else { max },
+                )),
+                None => Some((value, value)),
+            };
+        }
+        let get_bound = |bound: Option<&Bound>| match bound
+            .and_then(|bound| get_bound_bytes(bound, data_type))
+        {
+            Some(bytes) => av::Union(1, Box::new(av::Bytes(bytes))),
+            None => av::Union(0, Box::new(av::Null)),
+        };
+
+        summaries.push(av::Record(vec![
+            ("contains_null".to_string(), av::Boolean(contains_null)),
+            (
+                "contains_nan".to_string(),
+                match data_type {
+                    DataType::Float64 => av::Union(1, Box::new(av::Boolean(contains_nan))),
+                    _ => av::Union(0, Box::new(av::Null)),
+                },
+            ),
+            ("lower_bound".to_string(), get_bound(bounds.map(|b| b.0))),
+            ("upper_bound".to_string(), get_bound(bounds.map(|b| b.1))),
+        ]));
+    }
+    av::Union(1, Box::new(av::Array(summaries)))
+}
+
+fn write_manifest_list(
+    schema: &Schema,
+    partition_indexes: &[usize],
+    data_files: &[DataFile],
+    snapshot: &Snapshot,
+    manifest_length: u64,
+) -> Result<Vec<u8>, FakeLakeError> {
+    let avro_schema = parse_avro_schema(&get_manifest_list_schema())?;
+    let metadata = vec![
+        ("snapshot-id", snapshot.snapshot_id.to_string()),
+        ("parent-snapshot-id", "null".to_string()),
+        ("sequence-number", "1".to_string()),
+        ("format-version", ICEBERG_FORMAT_VERSION.to_string()),
+    ];
+    let rows: u64 = data_files.iter().map(|data_file| data_file.rows).sum();
+    let manifest_file = av::Record(vec![
+        (
+            "manifest_path".to_string(),
+            av::String(snapshot.manifest_path.clone()),
+        ),
+        (
+            "manifest_length".to_string(),
+            av::Long(manifest_length as i64),
+        ),
+        ("partition_spec_id".to_string(), av::Int(0)),
+        ("content".to_string(), av::Int(0)),
+        ("sequence_number".to_string(), av::Long(1)),
+        ("min_sequence_number".to_string(), av::Long(1)),
+        (
+            "added_snapshot_id".to_string(),
+            av::Long(snapshot.snapshot_id),
+        ),
+        (
+            "added_files_count".to_string(),
+            av::Int(data_files.len() as i32),
+        ),
+        ("existing_files_count".to_string(), av::Int(0)),
+        ("deleted_files_count".to_string(), av::Int(0)),
+        ("added_rows_count".to_string(), av::Long(rows as i64)),
+        ("existing_rows_count".to_string(), av::Long(0)),
+        ("deleted_rows_count".to_string(), av::Long(0)),
+        (
+            "partitions".to_string(),
+            get_partition_summaries(schema, partition_indexes, data_files),
+        ),
+    ]);
+    write_avro(&avro_schema, metadata, vec![manifest_file])
+}
+
+fn get_table_metadata(
+    schema: &Schema,
+    partition_indexes: &[usize],
+    data_files: &[DataFile],
+    snapshot: &Snapshot,
+) -> JsonValue {
+    let rows: u64 = data_files.iter().map(|data_file| data_file.rows).sum();
+    let size: u64 = data_files.iter().map(|data_file| data_file.size).sum();
+    let mut partitions: Vec<&Vec<Option<Bound>>> = Vec::new();
+    for data_file in data_files {
+        if !partitions.contains(&&data_file.partition) {
+            partitions.push(&data_file.partition);
+        }
+    }
+    let summary = json!({
+        "operation": "append",
+        "added-data-files": data_files.len().to_string(),
+        "added-records": rows.to_string(),
+        "added-files-size": size.to_string(),
+        "changed-partition-count": partitions.len().to_string(),
+        "total-data-files": data_files.len().to_string(),
+        "total-records": rows.to_string(),
+        "total-files-size": size.to_string(),
+        "total-delete-files": "0",
+        "total-position-deletes": "0",
+        "total-equality-deletes": "0",
+    });
+
+    json!({
+        "format-version": ICEBERG_FORMAT_VERSION,
+        "table-uuid": snapshot.table_uuid,
+        "location": snapshot.location,
+        "last-sequence-number": 1,
+        "last-updated-ms": snapshot.timestamp_ms,
+        "last-column-id": schema.fields().len(),
+        "current-schema-id": 0,
+        "schemas": [get_schema_json(schema)],
+        "default-spec-id": 0,
+        "partition-specs": [{
+            "spec-id": 0,
+            "fields": get_partition_spec_fields(schema, partition_indexes),
+        }],
+        "last-partition-id": ICEBERG_FIRST_PARTITION_ID + partition_indexes.len() - 1,
+        "default-sort-order-id": 0,
+        "sort-orders": [{"order-id": 0, "fields": []}],
+        "properties": {"write.format.default": "parquet"},
+        "current-snapshot-id": snapshot.snapshot_id,
+        "refs": {"main": {"snapshot-id": snapshot.snapshot_id, "type": "branch"}},
+        "snapshots": [{
+            "sequence-number": 1,
+            "snapshot-id": snapshot.snapshot_id,
+            "timestamp-ms": snapshot.timestamp_ms,
+            "summary": summary,
+            "manifest-list": snapshot.manifest_list_path,
+            "schema-id": 0,
+        }],
+        "snapshot-log": [{
+            "snapshot-id": snapshot.snapshot_id,
+            "timestamp-ms": snapshot.timestamp_ms,
+        }],
+        "metadata-log": [],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::decimal::DecimalProvider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use apache_avro::Reader;
+    use yaml_rust::YamlLoader;
+
+    fn get_column(
+        name: &str,
+        provider: Box<dyn crate::providers::provider::Provider>,
+        presence: f64,
+    ) -> Column {
+        Column {
+            name: name.to_string(),
+            provider,
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: {}\npresence: {}", name, presence))
+                    .unwrap()[0],
+            ),
+            path: None,
+        }
+    }
+
+    fn get_config(
+        columns: Vec<Column>,
+        name: &str,
+        rows: Option<u32>,
+        partition_by: Option<Vec<String>>,
+    ) -> Config {
+        // tables are not overwritten
+        std::fs::remove_dir_all(name).ok();
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: Some(name.to_string()),
+                output_format: Some(OutputType::Iceberg(ParquetOptions::default())),
+                rows,
+                seed: Some(42),
+                partition_by,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    fn get_id_column() -> Column {
+        get_column(
+            "id",
+            Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            1.0,
+        )
+    }
+
+    fn read_metadata(name: &str) -> JsonValue {
+        let metadata = std::fs::read_to_string(format!("{}/metadata/v1.metadata.json", name));
+        serde_json::from_str(&metadata.unwrap()).unwrap()
+    }
+
+    /// Reads the records of an avro file given by its location
+    fn read_avro(location: &str) -> Vec<av> {
+        let path = location.strip_prefix("file:").unwrap();
+        let reader = Reader::new(File::open(path).unwrap()).unwrap();
+        reader.map(|record| record.unwrap()).collect()
+    }
+
+    fn get_field<'a>(record: &'a av, name: &str) -> &'a av {
+        match record {
+            av::Record(fields) => &fields.iter().find(|(key, _)| key == name).unwrap().1,
+            _ => panic!("Should be a record"),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputIceberg::new(ParquetOptions::default());
+        assert_eq!(output.get_extension(), ".parquet");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(vec![], "target/test_generated/iceberg_empty", None, None);
+        let output = OutputIceberg::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_stdout_should_error() {
+        let mut config = get_config(
+            vec![get_id_column()],
+            "target/test_generated/iceberg_stdout",
+            Some(1),
+            None,
+        );
+        config.info.as_mut().unwrap().output_name = Some("-".to_string());
+        let output = OutputIceberg::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_existing_table_should_error() {
+        let config = get_config(
+            vec![get_id_column()],
+            "target/test_generated/iceberg_existing",
+            Some(1),
+            None,
+        );
+        let output = OutputIceberg::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_seed_should_write_same_ids() {
+        let mut ids = Vec::new();
+        for name in [
+            "target/test_generated/iceberg_seed_1",
+            "target/test_generated/iceberg_seed_2",
+        ] {
+            let config = get_config(vec![get_id_column()], name, Some(1), None);
+            let output = OutputIceberg::new(ParquetOptions::default());
+            match output.generate_from_config(&config) {
+                Ok(_) => (),
+                Err(_) => panic!("Error"),
+            }
+            let metadata = read_metadata(name);
+            ids.push((
+                metadata["table-uuid"].clone(),
+                metadata["current-snapshot-id"].clone(),
+            ));
+        }
+        assert_eq!(ids[0], ids[1]);
+    }
+
+    #[test]
+    fn given_config_should_write_metadata_and_manifests() {
+        let name = "target/test_generated/iceberg_stats";
+        let columns = vec![
+            get_id_column(),
+            get_column(
+                "string",
+                Box::new(AlphanumericProvider {
+                    min_length: 1,
+                    max_length: 5,
+                }),
+                0.5,
+            ),
+            get_column(
+                "created",
+                Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 1_000_000,
+                    before: 1_000_001,
+                }),
+                1.0,
+            ),
+        ];
+        let mut config = get_config(columns, name, Some(100), None);
+        config.info.as_mut().unwrap().max_rows_per_file = Some(60);
+        let output = OutputIceberg::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            std::fs::read_to_string(format!("{}/metadata/version-hint.text", name)).unwrap(),
+            "1"
+        );
+        let metadata = read_metadata(name);
+        assert_eq!(metadata["format-version"], 2);
+        assert_eq!(metadata["last-column-id"], 3);
+        assert_eq!(
+            metadata["schemas"][0]["fields"][0],
+            json!({"id": 1, "name": "id", "required": true, "type": "int"})
+        );
+        assert_eq!(metadata["schemas"][0]["fields"][1]["required"], false);
+        assert_eq!(metadata["schemas"][0]["fields"][2]["type"], "timestamp");
+        assert_eq!(metadata["partition-specs"][0]["fields"], json!([]));
+        let snapshot = &metadata["snapshots"][0];
+        assert_eq!(snapshot["snapshot-id"], metadata["current-snapshot-id"]);
+        assert_eq!(snapshot["summary"]["added-records"], "100");
+        assert_eq!(snapshot["summary"]["added-data-files"], "2");
+
+        let manifest_files = read_avro(snapshot["manifest-list"].as_str().unwrap());
+        assert_eq!(manifest_files.len(), 1);
+        assert_eq!(
+            get_field(&manifest_files[0], "added_rows_count"),
+            &av::Long(100)
+        );
+        let manifest_path = match get_field(&manifest_files[0], "manifest_path") {
+            av::String(value) => value.clone(),
+            _ => panic!("Should be a string"),
+        };
+
+        let entries = read_avro(&manifest_path);
+        assert_eq!(entries.len(), 2);
+        let data_file = get_field(&entries[1], "data_file");
+        match get_field(data_file, "file_path") {
+            av::String(value) => assert!(value.ends_with("/data/part-00001.parquet")),
+            _ => panic!("Should be a string"),
+        }
+        assert_eq!(get_field(data_file, "record_count"), &av::Long(40));
+        let get_bound = |name: &str| match get_field(data_file, name) {
+            av::Union(_, value) => match value.as_ref() {
+                av::Array(values) => get_field(&values[0], "value").clone(),
+                _ => panic!("Should be an array"),
+            },
+            _ => panic!("Should be a union"),
+        };
+        assert_eq!(
+            get_bound("lower_bounds"),
+            av::Bytes(60_i32.to_le_bytes().to_vec())
+        );
+        assert_eq!(
+            get_bound("upper_bounds"),
+            av::Bytes(99_i32.to_le_bytes().to_vec())
+        );
+    }
+
+    #[test]
+    fn given_decimal_column_should_write_decimal_type_and_bounds() {
+        let name = "target/test_generated/iceberg_decimal";
+        let columns = vec![get_column(
+            "amount",
+            Box::new(DecimalProvider {
+                precision: 10,
+                scale: 2,
+                min: -129,
+                max: -129,
+            }),
+            1.0,
+        )];
+        let config = get_config(columns, name, Some(10), None);
+        let output = OutputIceberg::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let metadata = read_metadata(name);
+        assert_eq!(
+            metadata["schemas"][0]["fields"][0]["type"],
+            json!("decimal(10, 2)")
+        );
+        let manifest_list = metadata["snapshots"][0]["manifest-list"].as_str().unwrap();
+        let manifest_path = match get_field(&read_avro(manifest_list)[0], "manifest_path") {
+            av::String(value) => value.clone(),
+            _ => panic!("Should be a string"),
+        };
+        let entries = read_avro(&manifest_path);
+        match get_field(get_field(&entries[0], "data_file"), "lower_bounds") {
+            av::Union(_, value) => match value.as_ref() {
+                av::Array(bounds) => {
+                    assert_eq!(get_field(&bounds[0], "value"), &av::Bytes(vec![0xff, 0x7f]))
+                }
+                _ => panic!("Should be an array"),
+            },
+            _ => panic!("Should be a union"),
+        }
+    }
+
+    #[test]
+    fn given_decimal_partition_should_error() {
+        let name = "target/test_generated/iceberg_decimal_partitioned";
+        let columns = vec![get_column(
+            "amount",
+            Box::new(DecimalProvider {
+                precision: 10,
+                scale: 2,
+                min: 0,
+                max: 1,
+            }),
+            1.0,
+        )];
+        let config = get_config(columns, name, Some(10), Some(vec!["amount".to_string()]));
+        let output = OutputIceberg::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_partitions_should_write_partition_spec_and_values() {
+        let name = "target/test_generated/iceberg_partitioned";
+        let columns = vec![
+            get_id_column(),
+            get_column("is active", Box::new(BoolProvider {}), 0.0),
+        ];
+        let config = get_config(columns, name, Some(10), Some(vec!["is active".to_string()]));
+        let output = OutputIceberg::new(ParquetOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let metadata = read_metadata(name);
+        assert_eq!(
+            metadata["partition-specs"][0]["fields"],
+            json!([{"name": "is active", "transform": "identity", "source-id": 2, "field-id": 1000}])
+        );
+        assert_eq!(metadata["last-partition-id"], 1000);
+
+        let manifest_list = metadata["snapshots"][0]["manifest-list"].as_str().unwrap();
+        let manifest_files = read_avro(manifest_list);
+        match get_field(&manifest_files[0], "partitions") {
+            av::Union(_, value) => match value.as_ref() {
+                av::Array(summaries) => assert_eq!(
+                    get_field(&summaries[0], "contains_null"),
+                    &av::Boolean(true)
+                ),
+                _ => panic!("Should be an array"),
+            },
+            _ => panic!("Should be a union"),
+        }
+    }
+
+    #[test]
+    fn given_values_should_return_partition_bounds() {
+        assert_eq!(
+            get_partition_bound("__HIVE_DEFAULT_PARTITION__", &DataType::Int32),
+            None
+        );
+        assert_eq!(
+            get_partition_bound("12", &DataType::Int32),
+            Some(Bound::Integer(12))
+        );
+        assert_eq!(
+            get_partition_bound("10000000000", &DataType::Int64),
+            Some(Bound::Integer(10_000_000_000))
+        );
+        assert_eq!(
+            get_partition_bound("1970-01-11", &DataType::Date32),
+            Some(Bound::Integer(10))
+        );
+        assert_eq!(
+            get_partition_bound(
+                "1970-01-01T00%3A00%3A01",
+                &DataType::Timestamp(TimeUnit::Second, None)
+            ),
+            Some(Bound::Integer(1_000_000))
+        );
+        assert_eq!(
+            get_partition_bound("a%2Fb", &DataType::Utf8),
+            Some(Bound::String("a/b".to_string()))
+        );
+    }
+
+    #[test]
+    fn given_bounds_should_return_their_single_value_serialization() {
+        assert_eq!(
+            get_bound_bytes(&Bound::Integer(1), &DataType::Int32),
+            Some(vec![1, 0, 0, 0])
+        );
+        assert_eq!(
+            get_bound_bytes(&Bound::Integer(1), &DataType::Int64),
+            Some(vec![1, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(
+            get_bound_bytes(&Bound::Integer(-129), &DataType::Decimal128(10, 2)),
+            Some(vec![0xff, 0x7f])
+        );
+    }
+
+    #[test]
+    fn given_names_should_return_avro_names() {
+        assert_eq!(get_avro_name("id_1"), "id_1");
+        assert_eq!(get_avro_name("1st"), "_1st");
+        assert_eq!(get_avro_name("is active"), "is_x20active");
+    }
+}
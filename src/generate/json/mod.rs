@@ -1,28 +1,157 @@
+pub mod options;
+
 use crate::config::Config;
 use crate::errors::FakeLakeError;
+use crate::generate::compression::{get_compressed_extension, CompressedWriter};
+use crate::generate::output_files::{
+    get_partition_value, CountingWriter, OutputFile, OutputFiles, OutputStream,
+};
 use crate::generate::output_format::OutputFormat;
 use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+use options::JsonOptions;
+
+use serde::Serialize;
+use serde_json::ser::PrettyFormatter;
 use serde_json::Value as sv;
-use serde_json::{Map, Number};
-use std::fs::File;
+use serde_json::{Map, Number, Serializer};
 use std::io::{BufWriter, Write};
+use std::path::Path;
 
 const JSON_EXTENSION: &str = ".json";
 
+struct JsonFile {
+    buffer: BufWriter<CompressedWriter<CountingWriter<OutputStream>>>,
+    wrap_up: bool,
+    /// Indentation of the objects when they are pretty printed
+    indent: Option<String>,
+    rows: u64,
+}
+
+impl JsonFile {
+    /// Rows wrapped up are streamed in the array opened when the file is created
+    fn write_row(&mut self, row: &Map<String, sv>) -> Result<(), FakeLakeError> {
+        let json = match &self.indent {
+            Some(indent) => {
+                let mut json = Vec::new();
+                let formatter = PrettyFormatter::with_indent(indent.as_bytes());
+                let mut serializer = Serializer::with_formatter(&mut json, formatter);
+                if let Err(e) = row.serialize(&mut serializer) {
+                    return Err(FakeLakeError::JSONError(e));
+                }
+                json
+            }
+            None => match serde_json::to_vec(row) {
+                Ok(json) => json,
+                Err(e) => return Err(FakeLakeError::JSONError(e)),
+            },
+        };
+
+        if !self.wrap_up {
+            self.buffer.write_all(&json)?;
+            self.buffer.write_all(b"\n")?;
+        } else if let Some(indent) = &self.indent {
+            // rows are one level deeper than the array
+            self.buffer
+                .write_all(if self.rows == 0 { b"\n" } else { b",\n" })?;
+            for (index, line) in json.split(|c| *c == b'\n').enumerate() {
+                if index > 0 {
+                    self.buffer.write_all(b"\n")?;
+                }
+                self.buffer.write_all(indent.as_bytes())?;
+                self.buffer.write_all(line)?;
+            }
+        } else {
+            if self.rows > 0 {
+                self.buffer.write_all(b",")?;
+            }
+            self.buffer.write_all(&json)?;
+        }
+        self.rows += 1;
+        Ok(())
+    }
+}
+
+impl OutputFile for JsonFile {
+    fn bytes_written(&self) -> u64 {
+        self.buffer.get_ref().get_ref().count() + self.buffer.buffer().len() as u64
+    }
+
+    fn flush_buffers(&mut self) -> Result<(), FakeLakeError> {
+        // also flushes the pending output of the compression
+        self.buffer.flush()?;
+        Ok(())
+    }
+
+    fn close(mut self) -> Result<(), FakeLakeError> {
+        if self.wrap_up {
+            match self.indent {
+                Some(_) if self.rows > 0 => self.buffer.write_all(b"\n]")?,
+                _ => self.buffer.write_all(b"]")?,
+            }
+        }
+        match self.buffer.into_inner() {
+            Ok(writer) => {
+                writer.finish()?;
+                Ok(())
+            }
+            Err(e) => Err(FakeLakeError::IOError(e.into_error())),
+        }
+    }
+}
+
+/// Inserts the value in the nested objects of its path, creating them when needed
+fn insert_value(row: &mut Map<String, sv>, path: &[&str], value: sv) {
+    match path {
+        [] => (),
+        [key] => {
+            row.insert(key.to_string(), value);
+        }
+        [key, keys @ ..] => {
+            let object = row
+                .entry(key.to_string())
+                .or_insert_with(|| sv::Object(Map::new()));
+            if let sv::Object(object) = object {
+                insert_value(object, keys, value);
+            }
+        }
+    }
+}
+
+/// A key can't hold both a value and a nested object
+fn check_paths(paths: &[Vec<&str>]) -> Result<(), FakeLakeError> {
+    for path in paths {
+        for other in paths {
+            if other.len() > path.len() && other.starts_with(path) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "JSON key {} can't be both a value and an object of {}",
+                    path.join("."),
+                    other.join(".")
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
 #[derive(Debug, PartialEq)]
 pub struct OutputJson {
-    wrap_up: bool,
+    options: JsonOptions,
+    extension: String,
 }
 
 impl OutputJson {
-    pub fn new(wrap_up: bool) -> OutputJson {
-        OutputJson { wrap_up }
+    pub fn new(options: JsonOptions) -> OutputJson {
+        OutputJson {
+            extension: get_compressed_extension(JSON_EXTENSION, options.compression),
+            options,
+        }
     }
 }
 
 impl OutputFormat for OutputJson {
     fn get_extension(&self) -> &str {
-        JSON_EXTENSION
+        &self.extension
     }
 
     fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
@@ -32,19 +161,63 @@ impl OutputFormat for OutputJson {
             ));
         }
 
-        let file_name = config.get_output_file_name(self.get_extension());
-        let mut buffer = BufWriter::new(File::create(file_name)?);
+        let mut files = OutputFiles::new(config, self.get_extension())?;
         let rows = config.get_number_of_rows();
-        let mut json = Vec::<sv>::new();
+
+        let paths: Vec<Vec<&str>> = config
+            .columns
+            .iter()
+            .map(|column| column.get_path())
+            .collect();
+        let written_paths: Vec<Vec<&str>> = paths
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !files.is_partition_column(*index))
+            .map(|(_, path)| path.clone())
+            .collect();
+        check_paths(&written_paths)?;
+
+        let create_writer = |path: &Path| {
+            let mut buffer = BufWriter::new(CompressedWriter::new(
+                CountingWriter::new(OutputStream::create(path)?),
+                self.options.compression,
+            )?);
+            if self.options.wrap_up {
+                buffer.write_all(b"[")?;
+            }
+            Ok(JsonFile {
+                buffer,
+                wrap_up: self.options.wrap_up,
+                indent: match self.options.pretty {
+                    true => Some(self.options.indent.clone()),
+                    false => None,
+                },
+                rows: 0,
+            })
+        };
+
+        // without partitions, the file is written even without rows
+        if !files.is_partitioned() {
+            files.get_writer(vec![], 0, create_writer)?;
+        }
 
         for i in 0..rows {
             let mut row = Map::new();
-            for column in &config.columns {
+            let mut partition_values = vec![String::new(); files.partition_indexes().len()];
+            for (index, column) in config.columns.iter().enumerate() {
+                let mut value = None;
                 if column.is_next_present() {
-                    let str_value = match column.provider.value(i) {
+                    value = Some(match column.provider.value(i) {
                         Value::Bool(value) => sv::Bool(value),
                         Value::Int32(value) => sv::Number(Number::from(value)),
-                        Value::Float64(value) => sv::Number(Number::from_f64(value).unwrap()),
+                        Value::Int64(value) => sv::Number(Number::from(value)),
+                        // strings keep every digit where numbers would be read as floats
+                        Value::Decimal(value, _, scale) => sv::String(format_decimal(value, scale)),
+                        // JSON has no infinity nor NaN, they are written as null
+                        Value::Float64(value) => match Number::from_f64(value) {
+                            Some(number) => sv::Number(number),
+                            None => sv::Null,
+                        },
                         Value::String(value) => sv::String(value),
                         Value::Date(value, date_format) => {
                             sv::String(value.format(&date_format).to_string())
@@ -52,30 +225,30 @@ impl OutputFormat for OutputJson {
                         Value::Timestamp(value, date_format) => {
                             sv::String(value.format(&date_format).to_string())
                         }
-                    };
-                    row.insert(column.name.to_string(), str_value);
+                    });
                 }
-            }
 
-            if self.wrap_up {
-                json.insert(i.try_into().unwrap(), sv::Object(row));
-            } else {
-                if let Err(e) = serde_json::to_writer(&mut buffer, &row) {
-                    return Err(FakeLakeError::JSONError(e));
-                }
-                if let Err(e) = buffer.write(b"\n") {
-                    return Err(FakeLakeError::IOError(e));
+                match files.partition_indexes().iter().position(|i| *i == index) {
+                    Some(position) => {
+                        partition_values[position] = match value {
+                            Some(sv::String(value)) => get_partition_value(Some(&value)),
+                            Some(sv::Null) | None => get_partition_value(None),
+                            Some(value) => get_partition_value(Some(&value.to_string())),
+                        }
+                    }
+                    None => {
+                        if let Some(value) = value {
+                            insert_value(&mut row, &paths[index], value);
+                        }
+                    }
                 }
             }
-        }
 
-        if self.wrap_up {
-            if let Err(e) = serde_json::to_writer(&mut buffer, &json) {
-                return Err(FakeLakeError::JSONError(e));
-            }
+            let (file, _) = files.get_writer(partition_values, 1, create_writer)?;
+            file.write_row(&row)?;
         }
 
-        Ok(())
+        files.close()
     }
 }
 
@@ -84,14 +257,18 @@ mod tests {
 
     use super::*;
     use crate::config::{Column, Config, Info, OutputType};
+    use crate::generate::compression::OutputCompression;
     use crate::options::presence;
     use crate::providers::increment::integer::IncrementIntegerProvider;
     use crate::providers::random::bool::BoolProvider;
     use crate::providers::random::date::date::DateProvider;
     use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::decimal::DecimalProvider;
     use crate::providers::random::number::f64::F64Provider;
     use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+    use crate::providers::utils::distribution::{Distribution, Law};
 
+    use std::fs::File;
     use yaml_rust::YamlLoader;
 
     fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
@@ -104,6 +281,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             });
         }
 
@@ -111,23 +289,35 @@ mod tests {
             columns,
             info: Some(Info {
                 output_name: name,
-                output_format: Some(OutputType::Json(true)),
+                output_format: Some(OutputType::Json(JsonOptions {
+                    wrap_up: true,
+                    ..Default::default()
+                })),
                 rows,
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         }
     }
 
     #[test]
     fn given_get_extension() {
-        let output = OutputJson { wrap_up: true };
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
         assert_eq!(output.get_extension(), ".json");
     }
 
     #[test]
     fn given_config_without_columns_should_error() {
         let config = get_config(0, None, None);
-        let output = OutputJson { wrap_up: true };
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Err(_) => (),
             Ok(_) => panic!("Should fail"),
@@ -137,7 +327,10 @@ mod tests {
     #[test]
     fn given_config_without_info_should_write_file() {
         let config = get_config(1, None, None);
-        let output = OutputJson { wrap_up: true };
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
@@ -151,7 +344,10 @@ mod tests {
             Some("target/test_generated/output_name".to_string()),
             Some(1000),
         );
-        let output = OutputJson { wrap_up: true };
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
@@ -167,6 +363,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "bool".to_string(),
@@ -174,13 +371,19 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
-                provider: Box::new(F64Provider { min: 0.0, max: 1.1 }),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
@@ -191,6 +394,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
@@ -202,6 +406,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
             Column {
                 name: "id".to_string(),
@@ -213,6 +418,7 @@ mod tests {
                 presence: presence::new_from_yaml(
                     &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
                 ),
+                path: None,
             },
         ];
 
@@ -220,13 +426,22 @@ mod tests {
             columns,
             info: Some(Info {
                 output_name: Some("target/test_generated/output_name".to_string()),
-                output_format: Some(OutputType::Json(true)),
+                output_format: Some(OutputType::Json(JsonOptions {
+                    wrap_up: true,
+                    ..Default::default()
+                })),
                 rows: Some(1000),
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         };
 
-        let output = OutputJson { wrap_up: true };
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
@@ -241,19 +456,29 @@ mod tests {
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
+            path: None,
         }];
 
         let config = Config {
             columns,
             info: Some(Info {
                 output_name: Some("target/test_generated/output_wrap_up".to_string()),
-                output_format: Some(OutputType::Json(true)),
+                output_format: Some(OutputType::Json(JsonOptions {
+                    wrap_up: true,
+                    ..Default::default()
+                })),
                 rows: Some(5),
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         };
 
-        let output = OutputJson { wrap_up: true };
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
@@ -265,6 +490,164 @@ mod tests {
         );
     }
 
+    #[test]
+    fn given_decimal_should_write_exact_string() {
+        let columns = vec![Column {
+            name: "amount".to_string(),
+            provider: Box::new(DecimalProvider {
+                precision: 20,
+                scale: 3,
+                min: -12_345_678_901_234_567,
+                max: -12_345_678_901_234_567,
+            }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: amount\npresence: 1").unwrap()[0],
+            ),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_json_decimal".to_string()),
+                output_format: None,
+                rows: Some(1),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputJson::new(JsonOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            std::fs::read_to_string("target/test_generated/output_json_decimal.json").unwrap(),
+            "{\"amount\":\"-12345678901234.567\"}\n"
+        );
+    }
+
+    #[test]
+    fn given_infinite_float_should_write_null() {
+        let columns = vec![Column {
+            name: "score".to_string(),
+            // a rate of 0 gives infinite values
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.0,
+                distribution: Some(Distribution {
+                    law: Law::Exponential { rate: 0.0 },
+                    clamp: false,
+                }),
+            }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: score\npresence: 1").unwrap()[0],
+            ),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_json_infinite".to_string()),
+                output_format: None,
+                rows: Some(2),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputJson::new(JsonOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            std::fs::read_to_string("target/test_generated/output_json_infinite.json").unwrap(),
+            "{\"score\":null}\n{\"score\":null}\n"
+        );
+    }
+
+    #[test]
+    fn given_pretty_should_indent_rows_in_array() {
+        let mut config = get_config(
+            1,
+            Some("target/test_generated/output_json_pretty".to_string()),
+            Some(2),
+        );
+        config.columns[0].name = "a.id".to_string();
+
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            pretty: true,
+            indent: "\t".to_string(),
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "[\n\t{\n\t\t\"a\": {\n\t\t\t\"id\": 0\n\t\t}\n\t},\n\t{\n\t\t\"a\": {\n\t\t\t\"id\": 1\n\t\t}\n\t}\n]",
+            std::fs::read_to_string("target/test_generated/output_json_pretty.json").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_pretty_without_wrap_up_should_write_indented_objects() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_json_pretty_lines".to_string()),
+            Some(2),
+        );
+
+        let output = OutputJson::new(JsonOptions {
+            pretty: true,
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "{\n  \"id\": 0\n}\n{\n  \"id\": 1\n}\n",
+            std::fs::read_to_string("target/test_generated/output_json_pretty_lines.json").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_no_rows_should_write_empty_array() {
+        for pretty in [false, true] {
+            let config = get_config(
+                1,
+                Some("target/test_generated/output_json_empty".to_string()),
+                Some(0),
+            );
+
+            let output = OutputJson::new(JsonOptions {
+                wrap_up: true,
+                pretty,
+                ..Default::default()
+            });
+            match output.generate_from_config(&config) {
+                Ok(_) => (),
+                Err(_) => panic!("Error"),
+            }
+
+            assert_eq!(
+                "[]",
+                std::fs::read_to_string("target/test_generated/output_json_empty.json").unwrap()
+            );
+        }
+    }
+
     #[test]
     fn given_should_not_wrap_up() {
         let columns = vec![Column {
@@ -273,19 +656,23 @@ mod tests {
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
+            path: None,
         }];
 
         let config = Config {
             columns,
             info: Some(Info {
                 output_name: Some("target/test_generated/output_not_wrap_up".to_string()),
-                output_format: Some(OutputType::Json(false)),
+                output_format: Some(OutputType::Json(JsonOptions::default())),
                 rows: Some(5),
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         };
 
-        let output = OutputJson { wrap_up: false };
+        let output = OutputJson::new(JsonOptions::default());
         match output.generate_from_config(&config) {
             Ok(_) => (),
             Err(_) => panic!("Error"),
@@ -296,4 +683,170 @@ mod tests {
             std::fs::read_to_string("target/test_generated/output_not_wrap_up.json").unwrap()
         );
     }
+
+    #[test]
+    fn given_partition_by_should_write_hive_directories() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "group".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 0 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: group\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let directory = "target/test_generated/output_json_partitioned";
+        std::fs::remove_dir_all(directory).ok();
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some(directory.to_string()),
+                output_format: Some(OutputType::Json(JsonOptions {
+                    wrap_up: true,
+                    ..Default::default()
+                })),
+                rows: Some(3),
+                seed: None,
+                partition_by: Some(vec!["group".to_string()]),
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "[{\"id\":0},{\"id\":1},{\"id\":2}]",
+            std::fs::read_to_string(format!("{}/group=0/part-00000.json", directory)).unwrap()
+        );
+    }
+
+    #[test]
+    fn given_max_rows_per_file_should_wrap_up_each_file() {
+        let mut config = get_config(
+            1,
+            Some("target/test_generated/output_json_split".to_string()),
+            Some(5),
+        );
+        config.info.as_mut().unwrap().max_rows_per_file = Some(2);
+
+        let output = OutputJson::new(JsonOptions {
+            wrap_up: true,
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        for (index, expected) in [
+            "[{\"id\":0},{\"id\":1}]",
+            "[{\"id\":2},{\"id\":3}]",
+            "[{\"id\":4}]",
+        ]
+        .iter()
+        .enumerate()
+        {
+            let file_name = format!("target/test_generated/output_json_split-{:05}.json", index);
+            assert_eq!(*expected, std::fs::read_to_string(file_name).unwrap());
+        }
+    }
+
+    #[test]
+    fn given_dotted_names_and_paths_should_write_nested_objects() {
+        let mut columns = vec![];
+        for (name, path) in [
+            ("id", None),
+            ("address.city", None),
+            ("zip", Some(vec!["address".to_string(), "zip".to_string()])),
+            ("version.number", Some(vec!["version.number".to_string()])),
+            ("a.b.c", None),
+        ] {
+            columns.push(Column {
+                name: name.to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path,
+            });
+        }
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_json_nested".to_string()),
+                output_format: Some(OutputType::Json(JsonOptions::default())),
+                rows: Some(1),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputJson::new(JsonOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "{\"a\":{\"b\":{\"c\":0}},\"address\":{\"city\":0,\"zip\":0},\"id\":0,\"version.number\":0}\n",
+            std::fs::read_to_string("target/test_generated/output_json_nested.json").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_value_and_object_on_same_key_should_error() {
+        let mut config = get_config(2, None, Some(1));
+        config.columns[1].name = "id.value".to_string();
+
+        let output = OutputJson::new(JsonOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_compression_should_write_compressed_file() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_json_zstd".to_string()),
+            Some(3),
+        );
+        let output = OutputJson::new(JsonOptions {
+            compression: Some(OutputCompression::Zstd),
+            ..Default::default()
+        });
+        assert_eq!(output.get_extension(), ".json.zst");
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let file = File::open("target/test_generated/output_json_zstd.json.zst").unwrap();
+        assert_eq!(
+            "{\"id\":0}\n{\"id\":1}\n{\"id\":2}\n",
+            String::from_utf8(zstd::decode_all(file).unwrap()).unwrap()
+        );
+    }
 }
@@ -0,0 +1,108 @@
+use crate::config::parse_compression;
+use crate::generate::compression::OutputCompression;
+
+use log::warn;
+use yaml_rust::Yaml;
+
+const DEFAULT_INDENT: &str = "  ";
+
+/// JSON settings of the info section
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonOptions {
+    /// Rows are written in a JSON array instead of one object per line
+    pub wrap_up: bool,
+    pub compression: Option<OutputCompression>,
+    /// Objects are written on multiple indented lines
+    pub pretty: bool,
+    pub indent: String,
+}
+
+impl Default for JsonOptions {
+    fn default() -> Self {
+        JsonOptions {
+            wrap_up: false,
+            compression: None,
+            pretty: false,
+            indent: DEFAULT_INDENT.to_string(),
+        }
+    }
+}
+
+impl JsonOptions {
+    pub fn new_from_yaml(section_info: &Yaml) -> JsonOptions {
+        let mut options = JsonOptions::default();
+
+        match section_info["wrap_up"] {
+            Yaml::Boolean(value) => options.wrap_up = value,
+            Yaml::BadValue => (),
+            _ => warn!("Wrap up should be a bool. Default value 'false' is taken."),
+        }
+
+        options.compression = parse_compression(&section_info["compression"]);
+
+        match section_info["pretty"] {
+            Yaml::Boolean(value) => options.pretty = value,
+            Yaml::BadValue => (),
+            _ => warn!("JSON pretty should be a bool. Default value 'false' is taken."),
+        }
+
+        // indent could be a number of spaces or the whitespaces to use, like a tab
+        match &section_info["indent"] {
+            Yaml::Integer(spaces) if (0..=16).contains(spaces) => {
+                options.indent = " ".repeat(*spaces as usize)
+            }
+            Yaml::String(indent) if indent.chars().all(|c| c == ' ' || c == '\t') => {
+                options.indent = indent.to_string()
+            }
+            Yaml::BadValue => (),
+            _ => warn!(
+                "JSON indent should be a number of spaces up to 16 or a tab. Default 2 spaces are taken."
+            ),
+        }
+
+        options
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_options(yaml: &str) -> JsonOptions {
+        let parsed_yaml = &YamlLoader::load_from_str(yaml).unwrap()[0];
+        JsonOptions::new_from_yaml(parsed_yaml)
+    }
+
+    #[test]
+    fn given_no_settings_should_return_default() {
+        assert_eq!(get_options("output_format: json"), JsonOptions::default());
+    }
+
+    #[test]
+    fn given_settings_should_return_them() {
+        let options = get_options("wrap_up: true\ncompression: xz\npretty: true\nindent: 4");
+        assert_eq!(
+            options,
+            JsonOptions {
+                wrap_up: true,
+                compression: Some(OutputCompression::Xz),
+                pretty: true,
+                indent: "    ".to_string(),
+            }
+        );
+
+        let options = get_options("indent: \"\\t\"");
+        assert_eq!(options.indent, "\t");
+    }
+
+    #[test]
+    fn given_invalid_settings_should_return_default() {
+        let options = get_options("wrap_up: invalid\npretty: 1\nindent: -1");
+        assert_eq!(options, JsonOptions::default());
+
+        let options = get_options("indent: abc");
+        assert_eq!(options, JsonOptions::default());
+    }
+}
@@ -1,19 +1,52 @@
+pub mod arrow;
+pub mod avro;
+pub mod compression;
 pub mod csv;
+pub mod delta;
+pub mod fixed_width;
+pub mod iceberg;
 pub mod json;
+pub mod msgpack;
+pub mod orc;
+pub mod output_files;
 pub mod output_format;
 pub mod parquet;
+pub mod pgcopy;
+pub mod protobuf;
+pub mod sql;
+pub mod sqlite;
+pub mod xlsx;
+pub mod xml;
 
 use crate::config;
 use crate::errors::FakeLakeError;
+use arrow::OutputArrow;
+use avro::OutputAvro;
 use csv::OutputCsv;
+use delta::OutputDelta;
+use fixed_width::OutputFixedWidth;
+use iceberg::OutputIceberg;
 use json::OutputJson;
+use msgpack::OutputMessagePack;
+use orc::OutputOrc;
 use output_format::OutputFormat;
+use parquet::options::ParquetOptions;
 use parquet::OutputParquet;
+use pgcopy::OutputPgCopy;
+use protobuf::OutputProtobuf;
+use sql::OutputSql;
+use sqlite::OutputSqlite;
+use xlsx::OutputXlsx;
+use xml::OutputXml;
 
 use log::{debug, info, warn};
 use std::path::PathBuf;
 
-pub fn generate_from_paths(paths_to_config: Vec<PathBuf>) -> Result<(), FakeLakeError> {
+/// With stdout, the generated data is written to standard output whatever the output_name
+pub fn generate_from_paths(
+    paths_to_config: Vec<PathBuf>,
+    stdout: bool,
+) -> Result<(), FakeLakeError> {
     let mut res: Result<(), FakeLakeError> = Ok(());
 
     for path in paths_to_config {
@@ -27,7 +60,7 @@ pub fn generate_from_paths(paths_to_config: Vec<PathBuf>) -> Result<(), FakeLake
             }
         };
 
-        match generate_from_string(&path, file_content) {
+        match generate_from_string(&path, file_content, stdout) {
             Ok(_) => info!("File from path {:?} generated.", &path),
             Err(e) => {
                 res = Err(FakeLakeError::BadYAMLFormat(format!(
@@ -41,14 +74,33 @@ pub fn generate_from_paths(paths_to_config: Vec<PathBuf>) -> Result<(), FakeLake
     res
 }
 
-fn generate_from_string(_: &PathBuf, file_content: String) -> Result<(), FakeLakeError> {
-    let config = config::get_config_from_string(file_content)?;
+fn generate_from_string(
+    _: &PathBuf,
+    file_content: String,
+    stdout: bool,
+) -> Result<(), FakeLakeError> {
+    let mut config = config::get_config_from_string(file_content)?;
+    if stdout {
+        if let Some(info) = config.info.as_mut() {
+            info.output_name = Some(config::STDOUT_OUTPUT_NAME.to_string());
+        }
+    }
     debug!("Parsed YAML config: {:?}", config);
     generate_from_config(config)
 }
 
 pub fn generate_from_config(config: config::Config) -> Result<(), FakeLakeError> {
     let output = get_corresponding_output(&config);
+    if !supports_output_files(&config) {
+        if !config.get_partition_by().is_empty() {
+            warn!(
+                "partition_by is only supported by parquet, csv, json, delta and iceberg outputs. It is ignored."
+            );
+        }
+        if config.get_max_rows_per_file().is_some() || config.get_max_bytes_per_file().is_some() {
+            warn!("max_rows_per_file and max_bytes_per_file are only supported by parquet, csv, json, delta and iceberg outputs. They are ignored.");
+        }
+    }
     output.generate_from_config(&config)
 }
 
@@ -56,9 +108,36 @@ fn get_corresponding_output(config: &config::Config) -> Box<dyn OutputFormat> {
     match &config.info {
         Some(info) => match &info.output_format {
             Some(output_format) => match output_format {
-                config::OutputType::Parquet() => Box::new(OutputParquet),
-                config::OutputType::Csv(value) => Box::new(OutputCsv::new(*value)),
-                config::OutputType::Json(value) => Box::new(OutputJson::new(*value)),
+                config::OutputType::Parquet(options) => {
+                    Box::new(OutputParquet::new(options.clone()))
+                }
+                config::OutputType::Csv(options) => Box::new(OutputCsv::new(options.clone())),
+                config::OutputType::Json(options) => Box::new(OutputJson::new(options.clone())),
+                config::OutputType::Avro() => Box::new(OutputAvro),
+                config::OutputType::Orc(value) => Box::new(OutputOrc::new(*value)),
+                config::OutputType::Arrow(value) => Box::new(OutputArrow::new(*value)),
+                config::OutputType::Sql(dialect, table_name) => {
+                    Box::new(OutputSql::new(*dialect, table_name.clone()))
+                }
+                config::OutputType::PgCopy(value) => Box::new(OutputPgCopy::new(*value)),
+                config::OutputType::Xml(options) => Box::new(OutputXml::new(options.clone())),
+                config::OutputType::Delta(options) => Box::new(OutputDelta::new(options.clone())),
+                config::OutputType::Iceberg(options) => {
+                    Box::new(OutputIceberg::new(options.clone()))
+                }
+                config::OutputType::Sqlite(table_name) => {
+                    Box::new(OutputSqlite::new(table_name.clone()))
+                }
+                config::OutputType::Xlsx(sheet_name) => {
+                    Box::new(OutputXlsx::new(sheet_name.clone()))
+                }
+                config::OutputType::Protobuf(message_name) => {
+                    Box::new(OutputProtobuf::new(message_name.clone()))
+                }
+                config::OutputType::MessagePack() => Box::new(OutputMessagePack),
+                config::OutputType::FixedWidth(options) => {
+                    Box::new(OutputFixedWidth::new(options.clone()))
+                }
             },
             None => wrong_format(),
         },
@@ -66,14 +145,34 @@ fn get_corresponding_output(config: &config::Config) -> Box<dyn OutputFormat> {
     }
 }
 
+/// Output formats writing their files with OutputFiles,
+/// handling partition_by, max_rows_per_file and max_bytes_per_file
+fn supports_output_files(config: &config::Config) -> bool {
+    match config
+        .info
+        .as_ref()
+        .and_then(|info| info.output_format.as_ref())
+    {
+        Some(config::OutputType::Parquet(_))
+        | Some(config::OutputType::Csv(_))
+        | Some(config::OutputType::Json(_))
+        | Some(config::OutputType::Delta(_))
+        | Some(config::OutputType::Iceberg(_))
+        | None => true,
+        Some(_) => false,
+    }
+}
+
 fn wrong_format() -> Box<dyn OutputFormat> {
     warn!("No output format specified, the file will be in parquet.");
-    Box::new(OutputParquet)
+    Box::new(OutputParquet::new(ParquetOptions::default()))
 }
 
 #[cfg(test)]
 mod tests {
     use crate::config::{Config, Info, OutputType};
+    use crate::generate::csv::options::CsvOptions;
+    use crate::generate::json::options::JsonOptions;
 
     use super::*;
 
@@ -100,7 +199,10 @@ mod tests {
         };
 
         let output = get_corresponding_output(&config);
-        assert_eq!(output.get_extension(), OutputParquet.get_extension());
+        assert_eq!(
+            output.get_extension(),
+            OutputParquet::new(ParquetOptions::default()).get_extension()
+        );
     }
 
     #[test]
@@ -110,6 +212,9 @@ mod tests {
             output_format: None,
             rows: None,
             seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
         });
         let config = Config {
             columns: Vec::new(),
@@ -117,16 +222,295 @@ mod tests {
         };
 
         let output = get_corresponding_output(&config);
-        assert_eq!(output.get_extension(), OutputParquet.get_extension());
+        assert_eq!(
+            output.get_extension(),
+            OutputParquet::new(ParquetOptions::default()).get_extension()
+        );
     }
 
     #[test]
     fn given_parquet_format_should_call_parquet_generation() {
         let info = Some(Info {
             output_name: None,
-            output_format: Some(OutputType::Parquet()),
+            output_format: Some(OutputType::Parquet(ParquetOptions::default())),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputParquet::new(ParquetOptions::default()).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_avro_format_should_call_avro_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Avro()),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(output.get_extension(), OutputAvro.get_extension());
+    }
+
+    #[test]
+    fn given_orc_format_should_call_orc_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Orc(false)),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputOrc::new(false).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_arrow_format_should_call_arrow_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Arrow(true)),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputArrow::new(true).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_sql_format_should_call_sql_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Sql(sql::SqlDialect::Sqlite, None)),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputSql::new(sql::SqlDialect::Sqlite, None).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_pg_copy_format_should_call_pg_copy_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::PgCopy(true)),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputPgCopy::new(true).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_fixed_width_format_should_call_fixed_width_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::FixedWidth(
+                fixed_width::options::FixedWidthOptions::default(),
+            )),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputFixedWidth::new(fixed_width::options::FixedWidthOptions::default())
+                .get_extension()
+        );
+    }
+
+    #[test]
+    fn given_delta_format_should_call_delta_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Delta(ParquetOptions::default())),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputDelta::new(ParquetOptions::default()).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_iceberg_format_should_call_iceberg_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Iceberg(ParquetOptions::default())),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputIceberg::new(ParquetOptions::default()).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_protobuf_format_should_call_protobuf_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Protobuf(None)),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputProtobuf::new(None).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_msgpack_format_should_call_msgpack_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::MessagePack()),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(output.get_extension(), OutputMessagePack.get_extension());
+    }
+
+    #[test]
+    fn given_sqlite_format_should_call_sqlite_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Sqlite(None)),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputSqlite::new(None).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_xlsx_format_should_call_xlsx_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Xlsx(None)),
             rows: None,
             seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
         });
         let config = Config {
             columns: Vec::new(),
@@ -134,7 +518,33 @@ mod tests {
         };
 
         let output = get_corresponding_output(&config);
-        assert_eq!(output.get_extension(), OutputParquet.get_extension());
+        assert_eq!(
+            output.get_extension(),
+            OutputXlsx::new(None).get_extension()
+        );
+    }
+
+    #[test]
+    fn given_xml_format_should_call_xml_generation() {
+        let info = Some(Info {
+            output_name: None,
+            output_format: Some(OutputType::Xml(xml::options::XmlOptions::default())),
+            rows: None,
+            seed: None,
+            partition_by: None,
+            max_rows_per_file: None,
+            max_bytes_per_file: None,
+        });
+        let config = Config {
+            columns: Vec::new(),
+            info,
+        };
+
+        let output = get_corresponding_output(&config);
+        assert_eq!(
+            output.get_extension(),
+            OutputXml::new(xml::options::XmlOptions::default()).get_extension()
+        );
     }
 
     // Wrong Format
@@ -146,7 +556,54 @@ mod tests {
         };
 
         let output = wrong_format();
-        assert_eq!(output.get_extension(), OutputParquet.get_extension());
+        assert_eq!(
+            output.get_extension(),
+            OutputParquet::new(ParquetOptions::default()).get_extension()
+        );
+    }
+
+    // supports_output_files
+    #[test]
+    fn given_parquet_csv_json_delta_iceberg_formats_should_support_output_files() {
+        for output_format in [
+            None,
+            Some(OutputType::Parquet(ParquetOptions::default())),
+            Some(OutputType::Csv(CsvOptions::default())),
+            Some(OutputType::Json(JsonOptions::default())),
+            Some(OutputType::Delta(ParquetOptions::default())),
+            Some(OutputType::Iceberg(ParquetOptions::default())),
+        ] {
+            let config = Config {
+                columns: Vec::new(),
+                info: Some(Info {
+                    output_name: None,
+                    output_format,
+                    rows: None,
+                    seed: None,
+                    partition_by: None,
+                    max_rows_per_file: None,
+                    max_bytes_per_file: None,
+                }),
+            };
+            assert!(supports_output_files(&config));
+        }
+    }
+
+    #[test]
+    fn given_other_formats_should_not_support_output_files() {
+        let config = Config {
+            columns: Vec::new(),
+            info: Some(Info {
+                output_name: None,
+                output_format: Some(OutputType::Avro()),
+                rows: None,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+        assert!(!supports_output_files(&config));
     }
 
     fn paths_to_vec_pathbuf(path: &str) -> Vec<PathBuf> {
@@ -157,28 +614,28 @@ mod tests {
     // generate_from_paths
     #[test]
     fn given_no_files_should_return_ok() {
-        let output = generate_from_paths(Vec::new());
+        let output = generate_from_paths(Vec::new(), false);
         expecting_ok(&output);
     }
 
     #[test]
     fn given_not_existing_file_should_skip_and_return_err() {
         let paths = paths_to_vec_pathbuf("this/is/not/an/existing/file");
-        let output = generate_from_paths(paths);
+        let output = generate_from_paths(paths, false);
         expecting_err(&output);
     }
 
     #[test]
     fn given_existing_file_but_not_yaml_should_err() {
         let paths = paths_to_vec_pathbuf("src/generate/generate.rs");
-        let output = generate_from_paths(paths);
+        let output = generate_from_paths(paths, false);
         expecting_err(&output);
     }
 
     #[test]
     fn given_existing_file_should_return_ok() {
         let paths = paths_to_vec_pathbuf("tests/one_row_parquet.yaml");
-        let output = generate_from_paths(paths);
+        let output = generate_from_paths(paths, false);
         expecting_ok(&output);
     }
 }
@@ -0,0 +1,283 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+
+use chrono::{DateTime, Utc};
+use rmp::encode;
+use std::io::{BufWriter, Write};
+
+const MSGPACK_EXTENSION: &str = ".msgpack";
+/// Extension type of the timestamps in the MessagePack specification
+const MSGPACK_TIMESTAMP_TYPE: i8 = -1;
+
+#[derive(Debug, PartialEq)]
+pub struct OutputMessagePack;
+
+impl OutputFormat for OutputMessagePack {
+    fn get_extension(&self) -> &str {
+        MSGPACK_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let mut buffer = BufWriter::new(OutputStream::create(file_name)?);
+        let rows = config.get_number_of_rows();
+
+        // each row is a map of the column names to their values, written one after another
+        for i in 0..rows {
+            encode::write_map_len(&mut buffer, config.columns.len() as u32)
+                .map_err(std::io::Error::from)?;
+            for column in &config.columns {
+                let value = match column.is_next_present() {
+                    true => Some(column.provider.value(i)),
+                    false => None,
+                };
+                encode::write_str(&mut buffer, &column.name).map_err(std::io::Error::from)?;
+                write_value(&mut buffer, value)?;
+            }
+        }
+
+        buffer.flush()?;
+        Ok(())
+    }
+}
+
+/// Dates are written as strings in their format, decimals as their exact text
+/// and timestamps with the timestamp extension type
+fn write_value<W: Write>(buffer: &mut W, value: Option<Value>) -> std::io::Result<()> {
+    match value {
+        None => encode::write_nil(buffer),
+        Some(Value::Bool(value)) => encode::write_bool(buffer, value),
+        Some(Value::Int32(value)) => encode::write_sint(buffer, value as i64)
+            .map(|_| ())
+            .map_err(std::io::Error::from),
+        Some(Value::Int64(value)) => encode::write_sint(buffer, value)
+            .map(|_| ())
+            .map_err(std::io::Error::from),
+        Some(Value::Decimal(value, _, scale)) => {
+            encode::write_str(buffer, &format_decimal(value, scale)).map_err(std::io::Error::from)
+        }
+        Some(Value::Float64(value)) => {
+            encode::write_f64(buffer, value).map_err(std::io::Error::from)
+        }
+        Some(Value::String(value)) => {
+            encode::write_str(buffer, &value).map_err(std::io::Error::from)
+        }
+        Some(Value::Date(value, date_format)) => {
+            encode::write_str(buffer, &value.format(&date_format).to_string())
+                .map_err(std::io::Error::from)
+        }
+        Some(Value::Timestamp(value, _)) => write_timestamp(buffer, value),
+    }
+}
+
+/// Smallest of the timestamp 32, 64 and 96 formats holding the timestamp
+fn write_timestamp<W: Write>(buffer: &mut W, value: DateTime<Utc>) -> std::io::Result<()> {
+    let seconds = value.timestamp();
+    let nanos = value.timestamp_subsec_nanos();
+    if seconds >> 34 == 0 {
+        let data = ((nanos as u64) << 34) | seconds as u64;
+        if data >> 32 == 0 {
+            encode::write_ext_meta(buffer, 4, MSGPACK_TIMESTAMP_TYPE)
+                .map_err(std::io::Error::from)?;
+            return buffer.write_all(&(data as u32).to_be_bytes());
+        }
+        encode::write_ext_meta(buffer, 8, MSGPACK_TIMESTAMP_TYPE).map_err(std::io::Error::from)?;
+        return buffer.write_all(&data.to_be_bytes());
+    }
+    encode::write_ext_meta(buffer, 12, MSGPACK_TIMESTAMP_TYPE).map_err(std::io::Error::from)?;
+    buffer.write_all(&nanos.to_be_bytes())?;
+    buffer.write_all(&seconds.to_be_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use chrono::{NaiveDate, TimeZone};
+    use rmp::decode;
+    use yaml_rust::YamlLoader;
+
+    fn get_column(
+        name: &str,
+        provider: Box<dyn crate::providers::provider::Provider>,
+        presence: f64,
+    ) -> Column {
+        Column {
+            name: name.to_string(),
+            provider,
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: {}\npresence: {}", name, presence))
+                    .unwrap()[0],
+            ),
+            path: None,
+        }
+    }
+
+    fn get_config(columns: Vec<Column>, name: Option<String>, rows: Option<u32>) -> Config {
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::MessagePack()),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    fn encode_value(value: Option<Value>) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        write_value(&mut buffer, value).unwrap();
+        buffer
+    }
+
+    fn read_str(buffer: &mut &[u8]) -> String {
+        let length = decode::read_str_len(buffer).unwrap() as usize;
+        let value = String::from_utf8(buffer[..length].to_vec()).unwrap();
+        *buffer = &buffer[length..];
+        value
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputMessagePack;
+        assert_eq!(output.get_extension(), ".msgpack");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(vec![], None, None);
+        let output = OutputMessagePack;
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_values_should_encode_them() {
+        assert_eq!(encode_value(None), vec![0xc0]);
+        assert_eq!(encode_value(Some(Value::Bool(true))), vec![0xc3]);
+        assert_eq!(encode_value(Some(Value::Int32(5))), vec![0x05]);
+        assert_eq!(
+            encode_value(Some(Value::Int32(-300))),
+            vec![0xd1, 0xfe, 0xd4]
+        );
+        assert_eq!(
+            encode_value(Some(Value::Int64(1 << 32))),
+            vec![0xcf, 0, 0, 0, 1, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            encode_value(Some(Value::Decimal(-5, 4, 2))),
+            vec![0xa5, b'-', b'0', b'.', b'0', b'5']
+        );
+        assert_eq!(
+            encode_value(Some(Value::Float64(1.5))),
+            [vec![0xcb], 1.5_f64.to_be_bytes().to_vec()].concat()
+        );
+        assert_eq!(
+            encode_value(Some(Value::String("ab".to_string()))),
+            vec![0xa2, b'a', b'b']
+        );
+        assert_eq!(
+            encode_value(Some(Value::Date(
+                NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+                "%d/%m/%Y".to_string()
+            ))),
+            [vec![0xaa], b"02/01/2024".to_vec()].concat()
+        );
+    }
+
+    #[test]
+    fn given_timestamps_should_encode_smallest_extension() {
+        let format = "%Y-%m-%d %H:%M:%S".to_string();
+        assert_eq!(
+            encode_value(Some(Value::Timestamp(
+                Utc.timestamp_opt(1, 0).unwrap(),
+                format.clone()
+            ))),
+            vec![0xd6, 0xff, 0x00, 0x00, 0x00, 0x01]
+        );
+        let timestamp64 = encode_value(Some(Value::Timestamp(
+            Utc.timestamp_opt(1, 1).unwrap(),
+            format.clone(),
+        )));
+        assert_eq!(timestamp64[..2], [0xd7, 0xff]);
+        assert_eq!(
+            u64::from_be_bytes(timestamp64[2..].try_into().unwrap()),
+            (1 << 34) | 1
+        );
+        let timestamp96 = encode_value(Some(Value::Timestamp(
+            Utc.timestamp_opt(-1, 0).unwrap(),
+            format,
+        )));
+        assert_eq!(timestamp96[..3], [0xc7, 0x0c, 0xff]);
+        assert_eq!(timestamp96.len(), 15);
+    }
+
+    #[test]
+    fn given_config_should_write_one_map_per_row() {
+        let name = "target/test_generated/msgpack_rows";
+        let columns = vec![
+            get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                1.0,
+            ),
+            get_column(
+                "name",
+                Box::new(AlphanumericProvider {
+                    min_length: 1,
+                    max_length: 5,
+                }),
+                0.0,
+            ),
+            get_column(
+                "birthday",
+                Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 719163,
+                    before: 719164,
+                }),
+                1.0,
+            ),
+        ];
+        let config = get_config(columns, Some(name.to_string()), Some(3));
+        let output = OutputMessagePack;
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let content = std::fs::read(format!("{}.msgpack", name)).unwrap();
+        let mut buffer = content.as_slice();
+        for row in 0..3 {
+            assert_eq!(decode::read_map_len(&mut buffer).unwrap(), 3);
+            assert_eq!(read_str(&mut buffer), "id");
+            assert_eq!(decode::read_int::<i32, _>(&mut buffer).unwrap(), row);
+            assert_eq!(read_str(&mut buffer), "name");
+            decode::read_nil(&mut buffer).unwrap();
+            assert_eq!(read_str(&mut buffer), "birthday");
+            assert_eq!(read_str(&mut buffer), "1970-01-01");
+        }
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,352 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::generate::parquet::{generate_batches_from_config, get_schema_from_config, BATCH_SIZE};
+
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_cast::cast;
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+use log::{debug, warn};
+use orc_rust::ArrowWriterBuilder;
+use std::sync::Arc;
+
+const ORC_EXTENSION: &str = ".orc";
+
+#[derive(Debug, PartialEq)]
+pub struct OutputOrc {
+    /// Fail instead of writing as strings the columns the ORC writer does not handle
+    pub strict_types: bool,
+}
+
+impl OutputOrc {
+    pub fn new(strict_types: bool) -> OutputOrc {
+        OutputOrc { strict_types }
+    }
+}
+
+impl OutputFormat for OutputOrc {
+    fn get_extension(&self) -> &str {
+        ORC_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let schema = get_orc_schema(&get_schema_from_config(config), self.strict_types)?;
+        debug!("Writing schema: {:?}", schema);
+
+        let file = OutputStream::create(file_name)?;
+        let mut writer = match ArrowWriterBuilder::new(file, schema.clone()).try_build() {
+            Ok(value) => value,
+            Err(e) => return Err(FakeLakeError::OrcError(e)),
+        };
+
+        generate_batches_from_config(config, BATCH_SIZE, |batch| {
+            let batch = get_orc_batch(&schema, batch)?;
+            match writer.write(&batch) {
+                Ok(_) => Ok(()),
+                Err(e) => Err(FakeLakeError::OrcError(e)),
+            }
+        })?;
+
+        // writer must be closed to write footer
+        if let Err(e) = writer.close() {
+            return Err(FakeLakeError::OrcError(e));
+        }
+
+        Ok(())
+    }
+}
+
+/// The ORC writer does not handle dates, timestamps and decimals yet,
+/// they are written as strings in ISO 8601 format and exact decimal text.
+fn get_orc_type(data_type: &DataType) -> DataType {
+    match data_type {
+        DataType::Date32 | DataType::Timestamp(_, _) | DataType::Decimal128(_, _) => DataType::Utf8,
+        data_type => data_type.clone(),
+    }
+}
+
+fn get_orc_schema(schema: &Schema, strict_types: bool) -> Result<SchemaRef, FakeLakeError> {
+    let mut fields: Vec<Field> = Vec::new();
+    for field in schema.fields() {
+        let orc_type = get_orc_type(field.data_type());
+        if &orc_type != field.data_type() {
+            if strict_types {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "Column {} of type {} can't be written as ORC yet, strict_types is set.",
+                    field.name(),
+                    field.data_type()
+                )));
+            }
+            warn!(
+                "Column {} of type {} is written as a string, ORC output does not handle this type yet.",
+                field.name(),
+                field.data_type()
+            );
+        }
+        fields.push(Field::new(field.name(), orc_type, field.is_nullable()));
+    }
+
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+fn get_orc_batch(schema: &SchemaRef, batch: RecordBatch) -> Result<RecordBatch, FakeLakeError> {
+    let mut columns: Vec<ArrayRef> = Vec::new();
+    for (index, column) in batch.columns().iter().enumerate() {
+        let orc_type = schema.field(index).data_type();
+        if column.data_type() == orc_type {
+            columns.push(column.clone());
+            continue;
+        }
+
+        match cast(column, orc_type) {
+            Ok(value) => columns.push(value),
+            Err(e) => return Err(FakeLakeError::ArrowError(e)),
+        }
+    }
+
+    match RecordBatch::try_new(schema.clone(), columns) {
+        Ok(value) => Ok(value),
+        Err(e) => Err(FakeLakeError::ArrowError(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use arrow_schema::TimeUnit;
+    use orc_rust::ArrowReaderBuilder;
+    use std::fs::File;
+    use yaml_rust::YamlLoader;
+
+    fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
+        let mut columns = vec![];
+
+        for _ in 0..nb_columns {
+            columns.push(Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            });
+        }
+
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Orc(false)),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    fn count_orc_rows(file_name: &str) -> usize {
+        let file = File::open(file_name).unwrap();
+        let reader = ArrowReaderBuilder::try_new(file).unwrap().build();
+        reader.map(|batch| batch.unwrap().num_rows()).sum()
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputOrc::new(false);
+        assert_eq!(output.get_extension(), ".orc");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(0, None, None);
+        let output = OutputOrc::new(false);
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = get_config(1, None, None);
+        let output = OutputOrc::new(false);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_write_file() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_orc".to_string()),
+            Some(1000),
+        );
+        let output = OutputOrc::new(false);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(count_orc_rows("target/test_generated/output_orc.orc"), 1000);
+    }
+
+    #[test]
+    fn given_date_and_timestamp_should_be_written_as_string() {
+        assert_eq!(get_orc_type(&DataType::Date32), DataType::Utf8);
+        assert_eq!(get_orc_type(&DataType::Decimal128(10, 2)), DataType::Utf8);
+        assert_eq!(
+            get_orc_type(&DataType::Timestamp(TimeUnit::Second, None)),
+            DataType::Utf8
+        );
+        assert_eq!(get_orc_type(&DataType::Int32), DataType::Int32);
+    }
+
+    #[test]
+    fn given_strict_types_and_date_should_error() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("date", DataType::Date32, true),
+        ]);
+        assert!(get_orc_schema(&schema, false).is_ok());
+        match get_orc_schema(&schema, true) {
+            Err(FakeLakeError::BadYAMLFormat(message)) => assert!(message.contains("date")),
+            _ => panic!("Should fail"),
+        }
+
+        let schema = Schema::new(vec![Field::new("id", DataType::Int32, false)]);
+        assert!(get_orc_schema(&schema, true).is_ok());
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_file() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "bool".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "float".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "timestamp".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_orc_all".to_string()),
+                output_format: Some(OutputType::Orc(false)),
+                rows: Some(1000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputOrc::new(false);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            count_orc_rows("target/test_generated/output_orc_all.orc"),
+            1000
+        );
+
+        let file = File::open("target/test_generated/output_orc_all.orc").unwrap();
+        let schema = ArrowReaderBuilder::try_new(file).unwrap().schema();
+        let types: Vec<(&str, &DataType)> = schema
+            .fields()
+            .iter()
+            .map(|field| (field.name().as_str(), field.data_type()))
+            .collect();
+        assert_eq!(
+            types,
+            vec![
+                ("id", &DataType::Int32),
+                ("bool", &DataType::Boolean),
+                ("float", &DataType::Float64),
+                ("string", &DataType::Utf8),
+                ("date", &DataType::Utf8),
+                ("timestamp", &DataType::Utf8),
+            ]
+        );
+    }
+}
@@ -0,0 +1,795 @@
+use crate::config::{Config, STDOUT_OUTPUT_NAME};
+use crate::errors::FakeLakeError;
+
+use linked_hash_map::LinkedHashMap;
+use log::debug;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Stdout, Write};
+use std::path::{Path, PathBuf};
+
+/// Directory name used by Hive for null or empty partition values
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Writers kept open at the same time, far below the usual limit of 1024 open files
+const MAX_OPEN_FILES: usize = 256;
+
+/// Writer of one output file
+pub trait OutputFile: Sized {
+    /// Approximate number of bytes written so far, used by max_bytes_per_file
+    fn bytes_written(&self) -> u64;
+    /// Writes the buffered bytes, including the pending output of a compression,
+    /// so that bytes_written is exact. Called before each measure of max_bytes_per_file.
+    fn flush_buffers(&mut self) -> Result<(), FakeLakeError> {
+        Ok(())
+    }
+    /// Writes the end of the file, called once all its rows are written
+    fn close(self) -> Result<(), FakeLakeError>;
+}
+
+/// Destination of an output file: the standard output when the file name
+/// is the stdout output name, the file otherwise
+pub enum OutputStream {
+    File(File),
+    Stdout(Stdout),
+}
+
+impl OutputStream {
+    pub fn create<P: AsRef<Path>>(path: P) -> Result<OutputStream, io::Error> {
+        match path.as_ref() == Path::new(STDOUT_OUTPUT_NAME) {
+            true => Ok(OutputStream::Stdout(io::stdout())),
+            false => Ok(OutputStream::File(File::create(path)?)),
+        }
+    }
+}
+
+impl Write for OutputStream {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            OutputStream::File(file) => file.write(buf),
+            OutputStream::Stdout(stdout) => stdout.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            OutputStream::File(file) => file.flush(),
+            OutputStream::Stdout(stdout) => stdout.flush(),
+        }
+    }
+}
+
+/// Counts the bytes written to the inner writer
+pub struct CountingWriter<W: Write> {
+    inner: W,
+    count: u64,
+}
+
+impl<W: Write> CountingWriter<W> {
+    pub fn new(inner: W) -> CountingWriter<W> {
+        CountingWriter { inner, count: 0 }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+struct FileEntry<W> {
+    writer: W,
+    path: PathBuf,
+    index: u32,
+    rows: u64,
+    /// Rows which can still be written before measuring the bytes written again
+    rows_within_bytes: u64,
+}
+
+/// File closed by OutputFiles
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrittenFile {
+    pub path: PathBuf,
+    /// Escaped values of the partition columns, in partition_by order
+    pub partition_values: Vec<String>,
+    pub rows: u64,
+}
+
+/// Files written by an output format.
+///
+/// Without partition_by, every row goes to the single file given by
+/// Config::get_output_file_name. With partition_by, the output name is used
+/// as a directory and rows go to Hive-style `col=value/part-00000.ext` files.
+/// At most MAX_OPEN_FILES writers are kept open: the least recently used one
+/// is closed and its partition starts a new file if it gets rows again.
+/// With max_rows_per_file or max_bytes_per_file, a new file is started
+/// as soon as the current one is full, the file index being incremented.
+/// Outputs which are directories, like table formats, always write
+/// `part-00000.ext` files in their data directory.
+pub struct OutputFiles<W: OutputFile> {
+    file_name: String,
+    directory: PathBuf,
+    in_directory: bool,
+    extension: String,
+    partition_by: Vec<String>,
+    partition_indexes: Vec<usize>,
+    max_rows_per_file: Option<u64>,
+    max_bytes_per_file: Option<u64>,
+    /// Open writers, from the least to the most recently used
+    files: LinkedHashMap<Vec<String>, FileEntry<W>>,
+    /// Index of the next file of partitions whose writer has been closed
+    next_indexes: HashMap<Vec<String>, u32>,
+    written_files: Vec<WrittenFile>,
+}
+
+impl<W: OutputFile> OutputFiles<W> {
+    pub fn new(config: &Config, extension: &str) -> Result<OutputFiles<W>, FakeLakeError> {
+        let directory = PathBuf::from(config.get_output_file_name(""));
+        OutputFiles::create(config, extension, directory, false)
+    }
+
+    /// Files are written in the directory, even without partitions
+    pub fn new_in_directory(
+        config: &Config,
+        extension: &str,
+        directory: &Path,
+    ) -> Result<OutputFiles<W>, FakeLakeError> {
+        OutputFiles::create(config, extension, directory.to_path_buf(), true)
+    }
+
+    fn create(
+        config: &Config,
+        extension: &str,
+        directory: PathBuf,
+        in_directory: bool,
+    ) -> Result<OutputFiles<W>, FakeLakeError> {
+        let partition_by = config.get_partition_by();
+
+        let mut partition_indexes = Vec::new();
+        for partition in &partition_by {
+            match config
+                .columns
+                .iter()
+                .position(|column| &column.name == partition)
+            {
+                Some(index) => partition_indexes.push(index),
+                None => {
+                    return Err(FakeLakeError::BadYAMLFormat(format!(
+                        "Partition column {} is not in the columns",
+                        partition
+                    )))
+                }
+            }
+        }
+
+        if !partition_by.is_empty() && partition_indexes.len() >= config.columns.len() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "At least one column should not be a partition column".to_string(),
+            ));
+        }
+
+        let max_rows_per_file = config.get_max_rows_per_file();
+        let max_bytes_per_file = config.get_max_bytes_per_file();
+        if config.is_stdout()
+            && (!partition_by.is_empty()
+                || max_rows_per_file.is_some()
+                || max_bytes_per_file.is_some())
+        {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "partition_by and max_rows_per_file/max_bytes_per_file can't be used when writing to stdout".to_string(),
+            ));
+        }
+
+        Ok(OutputFiles {
+            file_name: config.get_output_file_name(extension),
+            directory,
+            in_directory,
+            extension: extension.to_string(),
+            partition_by,
+            partition_indexes,
+            max_rows_per_file,
+            max_bytes_per_file,
+            files: LinkedHashMap::new(),
+            next_indexes: HashMap::new(),
+            written_files: Vec::new(),
+        })
+    }
+
+    pub fn is_partitioned(&self) -> bool {
+        !self.partition_by.is_empty()
+    }
+
+    fn is_split(&self) -> bool {
+        self.max_rows_per_file.is_some() || self.max_bytes_per_file.is_some()
+    }
+
+    /// Indexes in config.columns of the partition columns, in partition_by order
+    pub fn partition_indexes(&self) -> &[usize] {
+        &self.partition_indexes
+    }
+
+    pub fn is_partition_column(&self, index: usize) -> bool {
+        self.partition_indexes.contains(&index)
+    }
+
+    /// Returns the writer of the partition for the next rows, with the number
+    /// of these rows to write in it, which is lower than rows when the file
+    /// is almost full.
+    /// create_writer is called with the file path each time a file is started.
+    /// partition_values should already be escaped with get_partition_value.
+    pub fn get_writer<F>(
+        &mut self,
+        partition_values: Vec<String>,
+        rows: u64,
+        create_writer: F,
+    ) -> Result<(&mut W, u64), FakeLakeError>
+    where
+        F: FnOnce(&Path) -> Result<W, FakeLakeError>,
+    {
+        // the partition becomes the most recently used one
+        if let Some(file) = self.files.get_refresh(&partition_values) {
+            if self.max_bytes_per_file.is_some() && file.rows_within_bytes == 0 {
+                file.writer.flush_buffers()?;
+            }
+        }
+        let next_index = match self.files.get(&partition_values) {
+            Some(file) if self.is_full(file) => Some(file.index + 1),
+            Some(_) => None,
+            None => Some(self.next_indexes.remove(&partition_values).unwrap_or(0)),
+        };
+
+        if let Some(index) = next_index {
+            if !self.files.contains_key(&partition_values) && self.files.len() >= MAX_OPEN_FILES {
+                if let Some((closed_values, closed_file)) = self.files.pop_front() {
+                    self.next_indexes
+                        .insert(closed_values.clone(), closed_file.index + 1);
+                    self.close_file(closed_values, closed_file)?;
+                }
+            }
+            let path = self.get_file_path(&partition_values, index);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            debug!("Creating output file: {:?}", path);
+            let file = FileEntry {
+                writer: create_writer(&path)?,
+                path,
+                index,
+                rows: 0,
+                rows_within_bytes: 0,
+            };
+            if let Some(full_file) = self.files.insert(partition_values.clone(), file) {
+                self.close_file(partition_values.clone(), full_file)?;
+            }
+        }
+
+        let file = self.files.get_mut(&partition_values).unwrap();
+        let rows = match self.max_rows_per_file {
+            Some(max_rows) => rows.min(max_rows - file.rows),
+            None => rows,
+        };
+        let rows = match self.max_bytes_per_file {
+            Some(max_bytes) => {
+                if file.rows_within_bytes == 0 {
+                    file.rows_within_bytes =
+                        get_rows_within_bytes(file.rows, file.writer.bytes_written(), max_bytes);
+                }
+                let rows = rows.min(file.rows_within_bytes);
+                file.rows_within_bytes -= rows;
+                rows
+            }
+            None => rows,
+        };
+        file.rows += rows;
+        Ok((&mut file.writer, rows))
+    }
+
+    /// Closes all writers, from the least to the most recently used
+    pub fn close(self) -> Result<(), FakeLakeError> {
+        self.close_files().map(|_| ())
+    }
+
+    /// Closes all writers and returns every file written, in order of closing
+    pub fn close_files(mut self) -> Result<Vec<WrittenFile>, FakeLakeError> {
+        let files = std::mem::take(&mut self.files);
+        for (partition_values, file) in files {
+            self.close_file(partition_values, file)?;
+        }
+        Ok(self.written_files)
+    }
+
+    fn close_file(
+        &mut self,
+        partition_values: Vec<String>,
+        file: FileEntry<W>,
+    ) -> Result<(), FakeLakeError> {
+        file.writer.close()?;
+        self.written_files.push(WrittenFile {
+            path: file.path,
+            partition_values,
+            rows: file.rows,
+        });
+        Ok(())
+    }
+
+    fn is_full(&self, file: &FileEntry<W>) -> bool {
+        let rows_full = match self.max_rows_per_file {
+            Some(max_rows) => file.rows >= max_rows,
+            None => false,
+        };
+        let bytes_full = match self.max_bytes_per_file {
+            Some(max_bytes) => {
+                file.rows > 0
+                    && file.rows_within_bytes == 0
+                    && file.writer.bytes_written() >= max_bytes
+            }
+            None => false,
+        };
+        rows_full || bytes_full
+    }
+
+    fn get_file_path(&self, partition_values: &[String], index: u32) -> PathBuf {
+        if !self.is_partitioned() && !self.in_directory {
+            if !self.is_split() {
+                return PathBuf::from(&self.file_name);
+            }
+            let stem = self
+                .file_name
+                .strip_suffix(&self.extension)
+                .unwrap_or(&self.file_name);
+            return PathBuf::from(format!("{}-{:05}{}", stem, index, self.extension));
+        }
+
+        let mut path = self.directory.clone();
+        for (name, value) in self.partition_by.iter().zip(partition_values) {
+            path.push(format!("{}={}", escape_partition_path(name), value));
+        }
+        path.push(format!("part-{:05}{}", index, self.extension));
+        path
+    }
+}
+
+/// Number of rows to write in a file of max_bytes before measuring it again,
+/// estimated from the bytes per row written so far. The first row of a file
+/// is written alone to get an estimate, then half of the rows which still fit
+/// so that the estimate is refined as the file fills up. Buffers are flushed
+/// before each measure and at least one row is always allowed, so a file goes
+/// over max_bytes by about one row.
+fn get_rows_within_bytes(rows: u64, bytes: u64, max_bytes: u64) -> u64 {
+    if rows == 0 {
+        return 1;
+    }
+    let bytes_per_row = bytes.div_ceil(rows).max(1);
+    (max_bytes.saturating_sub(bytes) / bytes_per_row / 2).max(1)
+}
+
+/// Value of a partition directory, following Hive conventions:
+/// null and empty values go to the default partition and
+/// special characters are percent-encoded.
+pub fn get_partition_value(value: Option<&str>) -> String {
+    match value {
+        Some(value) if !value.is_empty() => escape_partition_path(value),
+        _ => HIVE_DEFAULT_PARTITION.to_string(),
+    }
+}
+
+/// Value of a partition column from its directory value,
+/// None for the default partition
+pub fn parse_partition_value(value: &str) -> Option<String> {
+    if value == HIVE_DEFAULT_PARTITION {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut index = 0;
+    while index < value.len() {
+        let escaped = value
+            .get(index + 1..index + 3)
+            .filter(|_| value.as_bytes()[index] == b'%')
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                bytes.push(byte);
+                index += 3;
+            }
+            None => {
+                bytes.push(value.as_bytes()[index]);
+                index += 1;
+            }
+        }
+    }
+    Some(String::from_utf8_lossy(&bytes).to_string())
+}
+
+fn escape_partition_path(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\u{00}'..='\u{1F}'
+            | '"'
+            | '#'
+            | '%'
+            | '\''
+            | '*'
+            | '/'
+            | ':'
+            | '='
+            | '?'
+            | '\\'
+            | '\u{7F}'
+            | '{'
+            | '['
+            | ']'
+            | '^' => escaped.push_str(&format!("%{:02X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::generate::csv::options::CsvOptions;
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+
+    use yaml_rust::YamlLoader;
+
+    #[derive(Debug, PartialEq)]
+    struct TestFile {
+        rows: u64,
+    }
+
+    impl OutputFile for TestFile {
+        fn bytes_written(&self) -> u64 {
+            self.rows * 10
+        }
+
+        fn close(self) -> Result<(), FakeLakeError> {
+            Ok(())
+        }
+    }
+
+    fn get_config(nb_columns: u8, partition_by: Option<Vec<String>>) -> Config {
+        let mut columns = vec![];
+
+        for i in 0..nb_columns {
+            columns.push(Column {
+                name: format!("id_{}", i),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            });
+        }
+
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_files".to_string()),
+                output_format: Some(OutputType::Csv(CsvOptions::default())),
+                rows: None,
+                seed: None,
+                partition_by,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_no_partition_should_use_output_file_name() {
+        let config = get_config(2, None);
+        let files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+        assert!(!files.is_partitioned());
+        assert_eq!(
+            files.get_file_path(&[], 0),
+            PathBuf::from("target/test_generated/output_files.csv")
+        );
+    }
+
+    #[test]
+    fn given_partitions_should_use_hive_directories() {
+        let config = get_config(3, Some(vec!["id_2".to_string(), "id_0".to_string()]));
+        let files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+        assert!(files.is_partitioned());
+        assert_eq!(files.partition_indexes(), &[2, 0]);
+        assert!(files.is_partition_column(0));
+        assert!(!files.is_partition_column(1));
+        assert_eq!(
+            files.get_file_path(&["a".to_string(), "b".to_string()], 0),
+            PathBuf::from("target/test_generated/output_files/id_2=a/id_0=b/part-00000.csv")
+        );
+    }
+
+    #[test]
+    fn given_in_directory_should_write_parts_in_directory() {
+        let config = get_config(2, None);
+        let files: OutputFiles<TestFile> =
+            OutputFiles::new_in_directory(&config, ".parquet", Path::new("table/data")).unwrap();
+        assert_eq!(
+            files.get_file_path(&[], 1),
+            PathBuf::from("table/data/part-00001.parquet")
+        );
+    }
+
+    #[test]
+    fn given_closed_files_should_return_written_files() {
+        let mut config = get_config(2, Some(vec!["id_1".to_string()]));
+        config.info.as_mut().unwrap().max_rows_per_file = Some(2);
+        let mut files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+        for (value, rows) in [("a", 3), ("b", 1)] {
+            let mut rows_left = rows;
+            while rows_left > 0 {
+                let (_, rows) = files
+                    .get_writer(vec![value.to_string()], rows_left, |_| {
+                        Ok(TestFile { rows: 0 })
+                    })
+                    .unwrap();
+                rows_left -= rows;
+            }
+        }
+
+        let written: Vec<(String, Vec<String>, u64)> = files
+            .close_files()
+            .unwrap()
+            .into_iter()
+            .map(|file| {
+                (
+                    file.path.to_string_lossy().to_string(),
+                    file.partition_values,
+                    file.rows,
+                )
+            })
+            .collect();
+        assert_eq!(
+            written,
+            vec![
+                (
+                    "target/test_generated/output_files/id_1=a/part-00000.csv".to_string(),
+                    vec!["a".to_string()],
+                    2
+                ),
+                (
+                    "target/test_generated/output_files/id_1=a/part-00001.csv".to_string(),
+                    vec!["a".to_string()],
+                    1
+                ),
+                (
+                    "target/test_generated/output_files/id_1=b/part-00000.csv".to_string(),
+                    vec!["b".to_string()],
+                    1
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_unknown_partition_column_should_error() {
+        let config = get_config(2, Some(vec!["unknown".to_string()]));
+        match OutputFiles::<TestFile>::new(&config, ".csv") {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_only_partition_columns_should_error() {
+        let config = get_config(1, Some(vec!["id_0".to_string()]));
+        match OutputFiles::<TestFile>::new(&config, ".csv") {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_same_partition_should_create_writer_once() {
+        let config = get_config(2, Some(vec!["id_0".to_string()]));
+        let mut files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+        let mut created = 0;
+        for value in ["a", "b", "a"] {
+            let (writer, rows) = files
+                .get_writer(vec![value.to_string()], 1, |_| {
+                    created += 1;
+                    Ok(TestFile { rows: 0 })
+                })
+                .unwrap();
+            writer.rows += rows;
+        }
+        assert_eq!(created, 2);
+        // from the least to the most recently used
+        let rows: Vec<u64> = files.files.values().map(|file| file.writer.rows).collect();
+        assert_eq!(rows, vec![1, 2]);
+    }
+
+    #[test]
+    fn given_more_partitions_than_open_files_should_close_least_recently_used() {
+        let config = get_config(2, Some(vec!["id_0".to_string()]));
+        let mut files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+        let mut paths = vec![];
+        let values = (0..MAX_OPEN_FILES + 10).chain([0, 20]);
+        for value in values {
+            let (writer, rows) = files
+                .get_writer(vec![value.to_string()], 1, |path| {
+                    paths.push(path.to_path_buf());
+                    Ok(TestFile { rows: 0 })
+                })
+                .unwrap();
+            writer.rows += rows;
+            assert!(files.files.len() <= MAX_OPEN_FILES);
+        }
+
+        // partition 0 was closed and starts a new file, partition 20 is still open
+        assert_eq!(paths.len(), MAX_OPEN_FILES + 11);
+        assert_eq!(
+            paths.last().unwrap(),
+            &PathBuf::from("target/test_generated/output_files/id_0=0/part-00001.csv")
+        );
+
+        let written = files.close_files().unwrap();
+        assert_eq!(written.len(), MAX_OPEN_FILES + 11);
+        assert_eq!(
+            written.iter().map(|file| file.rows).sum::<u64>(),
+            MAX_OPEN_FILES as u64 + 12
+        );
+    }
+
+    #[test]
+    fn given_max_rows_per_file_should_split_files() {
+        let mut config = get_config(1, None);
+        config.info.as_mut().unwrap().max_rows_per_file = Some(3);
+        let mut files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+
+        let mut paths = vec![];
+        let mut written = vec![];
+        let mut rows_left = 8;
+        while rows_left > 0 {
+            let (_, rows) = files
+                .get_writer(vec![], rows_left, |path| {
+                    paths.push(path.to_path_buf());
+                    Ok(TestFile { rows: 0 })
+                })
+                .unwrap();
+            written.push(rows);
+            rows_left -= rows;
+        }
+
+        assert_eq!(written, vec![3, 3, 2]);
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("target/test_generated/output_files-00000.csv"),
+                PathBuf::from("target/test_generated/output_files-00001.csv"),
+                PathBuf::from("target/test_generated/output_files-00002.csv"),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_max_bytes_per_file_should_split_files() {
+        let mut config = get_config(2, Some(vec!["id_0".to_string()]));
+        config.info.as_mut().unwrap().max_bytes_per_file = Some(25);
+        let mut files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+
+        let mut paths = vec![];
+        for _ in 0..5 {
+            let (writer, rows) = files
+                .get_writer(vec!["a".to_string()], 1, |path| {
+                    paths.push(path.to_path_buf());
+                    Ok(TestFile { rows: 0 })
+                })
+                .unwrap();
+            writer.rows += rows;
+        }
+
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("target/test_generated/output_files/id_0=a/part-00000.csv"),
+                PathBuf::from("target/test_generated/output_files/id_0=a/part-00001.csv"),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_max_bytes_per_file_should_limit_rows_of_writer() {
+        let mut config = get_config(1, None);
+        config.info.as_mut().unwrap().max_bytes_per_file = Some(95);
+        let mut files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+
+        let mut written = vec![];
+        let mut rows_left = 20;
+        while rows_left > 0 {
+            let (writer, rows) = files
+                .get_writer(vec![], rows_left, |_| Ok(TestFile { rows: 0 }))
+                .unwrap();
+            writer.rows += rows;
+            written.push(rows);
+            rows_left -= rows;
+        }
+
+        // 10 bytes per row: a first row alone, then half of the rows left
+        // up to the limit and one more row
+        assert_eq!(written, vec![1, 4, 2, 1, 1, 1, 1, 4, 2, 1, 1, 1]);
+    }
+
+    #[test]
+    fn given_bytes_written_should_return_rows_within_bytes() {
+        assert_eq!(get_rows_within_bytes(0, 0, 100), 1);
+        assert_eq!(get_rows_within_bytes(2, 20, 100), 4);
+        assert_eq!(get_rows_within_bytes(9, 90, 100), 1);
+        assert_eq!(get_rows_within_bytes(10, 100, 100), 1);
+        assert_eq!(get_rows_within_bytes(10, 0, 100), 50);
+    }
+
+    #[test]
+    fn given_stdout_and_partitions_should_error() {
+        let mut config = get_config(2, Some(vec!["id_0".to_string()]));
+        config.info.as_mut().unwrap().output_name = Some("-".to_string());
+        match OutputFiles::<TestFile>::new(&config, ".csv") {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_stdout_should_use_stdout_path() {
+        let mut config = get_config(2, None);
+        config.info.as_mut().unwrap().output_name = Some("-".to_string());
+        let files: OutputFiles<TestFile> = OutputFiles::new(&config, ".csv").unwrap();
+        assert_eq!(files.get_file_path(&[], 0), PathBuf::from("-"));
+        assert!(matches!(
+            OutputStream::create("-").unwrap(),
+            OutputStream::Stdout(_)
+        ));
+    }
+
+    #[test]
+    fn given_counting_writer_should_count_bytes() {
+        let mut writer = CountingWriter::new(Vec::new());
+        writer.write_all(b"hello").unwrap();
+        writer.write_all(b" world").unwrap();
+        assert_eq!(writer.count(), 11);
+    }
+
+    #[test]
+    fn given_null_or_empty_value_should_return_default_partition() {
+        assert_eq!(get_partition_value(None), HIVE_DEFAULT_PARTITION);
+        assert_eq!(get_partition_value(Some("")), HIVE_DEFAULT_PARTITION);
+    }
+
+    #[test]
+    fn given_special_characters_should_be_escaped() {
+        assert_eq!(get_partition_value(Some("FR")), "FR");
+        assert_eq!(
+            get_partition_value(Some("2024-01-01 10:00:00")),
+            "2024-01-01 10%3A00%3A00"
+        );
+        assert_eq!(get_partition_value(Some("a/b=c%")), "a%2Fb%3Dc%25");
+        assert_eq!(get_partition_value(Some("é")), "é");
+    }
+
+    #[test]
+    fn given_partition_directory_value_should_return_value() {
+        assert_eq!(parse_partition_value(HIVE_DEFAULT_PARTITION), None);
+        for value in ["FR", "2024-01-01 10:00:00", "a/b=c%", "é%2", "100%"] {
+            assert_eq!(
+                parse_partition_value(&get_partition_value(Some(value))),
+                Some(value.to_string())
+            );
+        }
+    }
+}
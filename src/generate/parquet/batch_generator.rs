@@ -2,8 +2,8 @@ use super::utils::get_parquet_type_from_column;
 use crate::config::Column;
 use crate::providers::provider::Value;
 use arrow_array::{
-    Array, ArrayRef, BooleanArray, Date32Array, Float64Array, Int32Array, StringArray,
-    TimestampSecondArray,
+    Array, ArrayRef, BooleanArray, Date32Array, Decimal128Array, Float64Array, Int32Array,
+    Int64Array, StringArray, TimestampSecondArray,
 };
 use arrow_schema::{DataType, TimeUnit};
 use chrono::{Datelike, NaiveDate};
@@ -23,7 +23,7 @@ where
 }
 
 pub trait ParquetBatchGenerator: CloneParquetBatchGenerator + Send + Sync {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array>;
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array>;
     fn name(&self) -> &str;
     fn new(column: Column) -> Self
     where
@@ -41,9 +41,9 @@ struct BoolBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for BoolBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
         let mut vec: Vec<Option<bool>> = Vec::new();
-        for i in 0..rows_to_generate {
+        for i in first_row..first_row + rows_to_generate {
             if self.column.is_next_present() {
                 match self.column.provider.value(i) {
                     Value::Bool(value) => vec.push(Some(value)),
@@ -68,9 +68,9 @@ struct IntBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for IntBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
         let mut vec: Vec<Option<i32>> = Vec::new();
-        for i in 0..rows_to_generate {
+        for i in first_row..first_row + rows_to_generate {
             if self.column.is_next_present() {
                 match self.column.provider.value(i) {
                     Value::Int32(value) => vec.push(Some(value)),
@@ -92,14 +92,84 @@ impl ParquetBatchGenerator for IntBatchGenerator {
     }
 }
 
+#[derive(Clone)]
+struct LongBatchGenerator {
+    column: Column,
+}
+impl ParquetBatchGenerator for LongBatchGenerator {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
+        let mut vec: Vec<Option<i64>> = Vec::new();
+        for i in first_row..first_row + rows_to_generate {
+            if self.column.is_next_present() {
+                match self.column.provider.value(i) {
+                    Value::Int64(value) => vec.push(Some(value)),
+                    _ => panic!("Wrong provider type"),
+                }
+            } else {
+                vec.push(None)
+            }
+        }
+        Arc::new(Int64Array::from(vec)) as ArrayRef
+    }
+
+    fn name(&self) -> &str {
+        &self.column.name
+    }
+
+    fn new(column: Column) -> LongBatchGenerator {
+        LongBatchGenerator { column }
+    }
+}
+
+#[derive(Clone)]
+struct DecimalBatchGenerator {
+    column: Column,
+    precision: u8,
+    scale: i8,
+}
+impl ParquetBatchGenerator for DecimalBatchGenerator {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
+        let mut vec: Vec<Option<i128>> = Vec::new();
+        for i in first_row..first_row + rows_to_generate {
+            if self.column.is_next_present() {
+                match self.column.provider.value(i) {
+                    Value::Decimal(value, _, _) => vec.push(Some(value)),
+                    _ => panic!("Wrong provider type"),
+                }
+            } else {
+                vec.push(None)
+            }
+        }
+        let array = Decimal128Array::from(vec)
+            .with_precision_and_scale(self.precision, self.scale)
+            .unwrap();
+        Arc::new(array) as ArrayRef
+    }
+
+    fn name(&self) -> &str {
+        &self.column.name
+    }
+
+    fn new(column: Column) -> DecimalBatchGenerator {
+        match get_parquet_type_from_column(column.clone()) {
+            DataType::Decimal128(precision, scale) => DecimalBatchGenerator {
+                column,
+                precision,
+                scale,
+            },
+            _ => panic!("Wrong provider type"),
+        }
+    }
+}
+
 #[derive(Clone)]
 struct FloatBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for FloatBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
         let mut vec: Vec<Option<f64>> = Vec::new();
-        for i in 0..rows_to_generate {
+        for i in first_row..first_row + rows_to_generate {
             if self.column.is_next_present() {
                 match self.column.provider.value(i) {
                     Value::Float64(value) => vec.push(Some(value)),
@@ -126,9 +196,9 @@ struct StrBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for StrBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
         let mut vec: Vec<Option<String>> = Vec::new();
-        for i in 0..rows_to_generate {
+        for i in first_row..first_row + rows_to_generate {
             if self.column.is_next_present() {
                 match self.column.provider.value(i) {
                     Value::String(value) => vec.push(Some(value)),
@@ -155,13 +225,13 @@ struct DateBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for DateBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
         let epoch_days = NaiveDate::from_ymd_opt(1970, 1, 1)
             .unwrap()
             .num_days_from_ce();
 
         let mut vec: Vec<Option<i32>> = Vec::new();
-        for i in 0..rows_to_generate {
+        for i in first_row..first_row + rows_to_generate {
             if self.column.is_next_present() {
                 match self.column.provider.value(i) {
                     Value::Date(value, _) => vec.push(Some(value.num_days_from_ce() - epoch_days)),
@@ -188,9 +258,9 @@ struct TimestampBatchGenerator {
     column: Column,
 }
 impl ParquetBatchGenerator for TimestampBatchGenerator {
-    fn batch_array(&self, rows_to_generate: u32) -> Arc<dyn Array> {
+    fn batch_array(&self, first_row: u32, rows_to_generate: u32) -> Arc<dyn Array> {
         let mut vec: Vec<Option<i64>> = Vec::new();
-        for i in 0..rows_to_generate {
+        for i in first_row..first_row + rows_to_generate {
             if self.column.is_next_present() {
                 match self.column.provider.value(i) {
                     Value::Timestamp(value, _) => vec.push(Some(value.timestamp())),
@@ -214,6 +284,8 @@ pub fn parquet_batch_generator_builder(column: Column) -> Box<dyn ParquetBatchGe
     match get_parquet_type_from_column(column.clone()) {
         DataType::Boolean => Box::new(BoolBatchGenerator::new(column.clone())),
         DataType::Int32 => Box::new(IntBatchGenerator::new(column.clone())),
+        DataType::Int64 => Box::new(LongBatchGenerator::new(column.clone())),
+        DataType::Decimal128(_, _) => Box::new(DecimalBatchGenerator::new(column.clone())),
         DataType::Float64 => Box::new(FloatBatchGenerator::new(column.clone())),
         DataType::Utf8 => Box::new(StrBatchGenerator::new(column.clone())),
         DataType::Date32 => Box::new(DateBatchGenerator::new(column.clone())),
@@ -228,10 +300,12 @@ pub fn parquet_batch_generator_builder(column: Column) -> Box<dyn ParquetBatchGe
 mod tests {
     use super::*;
     use crate::options::presence::new_from_yaml;
+    use crate::providers::random::number::decimal::DecimalProvider;
     use crate::providers::random::number::f64::F64Provider;
     use crate::providers::{
-        increment::integer::IncrementIntegerProvider, random::bool::BoolProvider,
-        random::date::date::DateProvider, random::date::datetime::DatetimeProvider,
+        increment::integer::IncrementIntegerProvider, increment::long::IncrementLongProvider,
+        random::bool::BoolProvider, random::date::date::DateProvider,
+        random::date::datetime::DatetimeProvider,
         random::string::alphanumeric::AlphanumericProvider,
     };
 
@@ -244,6 +318,7 @@ mod tests {
             name: "bool_column".to_string(),
             provider: Box::new(BoolProvider {}),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
 
         let ret = parquet_batch_generator_builder(column);
@@ -256,9 +331,10 @@ mod tests {
             name: "bool_column".to_string(),
             provider: Box::new(BoolProvider {}),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         let batch_generator = BoolBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -271,9 +347,10 @@ mod tests {
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
+            path: None,
         };
         let batch_generator = BoolBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -288,9 +365,10 @@ mod tests {
                 max_length: 11,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
         };
         let batch_generator = BoolBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // Int32 batch generator
@@ -300,6 +378,7 @@ mod tests {
             name: "int_column".to_string(),
             provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
 
         let ret = parquet_batch_generator_builder(column);
@@ -312,13 +391,30 @@ mod tests {
             name: "int_column".to_string(),
             provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         let batch_generator = IntBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
 
+    #[test]
+    fn given_first_row_should_give_row_indexes_to_provider() {
+        let column = Column {
+            name: "int_column".to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
+        };
+        let batch_generator = IntBatchGenerator { column };
+        let arr = batch_generator.batch_array(1000, 10);
+
+        let values = arr.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(values.value(0), 1000);
+        assert_eq!(values.value(9), 1009);
+    }
+
     #[test]
     fn given_int_batch_generator_with_presence_should_batch_correctly() {
         let column = Column {
@@ -327,9 +423,10 @@ mod tests {
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
+            path: None,
         };
         let batch_generator = IntBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -344,9 +441,98 @@ mod tests {
                 max_length: 11,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
         };
         let batch_generator = IntBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
+    }
+
+    // Int64 batch generator
+    #[test]
+    fn given_long_provider_should_return_batch_generator() {
+        let column = Column {
+            name: "long_column".to_string(),
+            provider: Box::new(IncrementLongProvider { start: 0, step: 1 }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
+        };
+
+        let ret = parquet_batch_generator_builder(column);
+        assert_eq!(ret.name(), "long_column");
+    }
+
+    #[test]
+    fn given_long_batch_generator_should_batch_past_i32_max() {
+        let column = Column {
+            name: "long_column".to_string(),
+            provider: Box::new(IncrementLongProvider {
+                start: i32::MAX as i64,
+                step: 1,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
+        };
+        let batch_generator = LongBatchGenerator { column };
+        let arr = batch_generator.batch_array(0, 1000);
+
+        assert_eq!(arr.len(), 1000);
+        let values = arr.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(values.value(999), i32::MAX as i64 + 999);
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_long_batch_generator_with_wrong_provider_should_panic() {
+        let column = Column {
+            name: "long_column".to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
+        };
+        let batch_generator = LongBatchGenerator { column };
+        let _ = batch_generator.batch_array(0, 1);
+    }
+
+    // Decimal batch generator
+    #[test]
+    fn given_decimal_provider_should_return_batch_generator() {
+        let column = Column {
+            name: "decimal_column".to_string(),
+            provider: Box::new(DecimalProvider {
+                precision: 12,
+                scale: 3,
+                min: -1000,
+                max: 1000,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
+        };
+
+        let ret = parquet_batch_generator_builder(column);
+        assert_eq!(ret.name(), "decimal_column");
+        let arr = ret.batch_array(0, 1000);
+        assert_eq!(arr.len(), 1000);
+        assert_eq!(arr.data_type(), &DataType::Decimal128(12, 3));
+        let values = arr.as_any().downcast_ref::<Decimal128Array>().unwrap();
+        assert!(values
+            .iter()
+            .all(|value| (-1000..=1000).contains(&value.unwrap())));
+    }
+
+    #[test]
+    #[should_panic]
+    fn given_decimal_batch_generator_with_wrong_provider_should_panic() {
+        let column = Column {
+            name: "decimal_column".to_string(),
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.0,
+                distribution: None,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
+        };
+        let _ = DecimalBatchGenerator::new(column);
     }
 
     // Float64 batch generator
@@ -354,8 +540,13 @@ mod tests {
     fn given_float_provider_should_return_batch_generator() {
         let column = Column {
             name: "float_column".to_string(),
-            provider: Box::new(F64Provider { min: 0.0, max: 1.0 }),
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.0,
+                distribution: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
 
         let ret = parquet_batch_generator_builder(column);
@@ -366,11 +557,16 @@ mod tests {
     fn given_float_batch_generator_should_batch_correctly() {
         let column = Column {
             name: "float_column".to_string(),
-            provider: Box::new(F64Provider { min: 0.0, max: 1.0 }),
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.0,
+                distribution: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         let batch_generator = FloatBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -379,13 +575,18 @@ mod tests {
     fn given_float_batch_generator_with_presence_should_batch_correctly() {
         let column = Column {
             name: "float_column".to_string(),
-            provider: Box::new(F64Provider { min: 0.0, max: 1.0 }),
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.0,
+                distribution: None,
+            }),
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
+            path: None,
         };
         let batch_generator = FloatBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -400,9 +601,10 @@ mod tests {
                 max_length: 11,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
         };
         let batch_generator = FloatBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // String batch generator
@@ -415,6 +617,7 @@ mod tests {
                 max_length: 11,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
 
         let ret = parquet_batch_generator_builder(column);
@@ -430,9 +633,10 @@ mod tests {
                 max_length: 11,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         let batch_generator = StrBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -448,9 +652,10 @@ mod tests {
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
+            path: None,
         };
         let batch_generator = StrBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -462,9 +667,10 @@ mod tests {
             name: "str_column".to_string(),
             provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
         };
         let batch_generator = StrBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // Date batch generator
@@ -478,6 +684,7 @@ mod tests {
                 after: 0,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
 
         let ret = parquet_batch_generator_builder(column);
@@ -494,9 +701,10 @@ mod tests {
                 after: 0,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         let batch_generator = DateBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -513,9 +721,10 @@ mod tests {
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
+            path: None,
         };
         let batch_generator = DateBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -527,9 +736,10 @@ mod tests {
             name: "date_column".to_string(),
             provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
         };
         let batch_generator = DateBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 
     // Timestamp batch generator
@@ -543,6 +753,7 @@ mod tests {
                 before: 12_000_000,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
 
         let ret = parquet_batch_generator_builder(column);
@@ -559,9 +770,10 @@ mod tests {
                 before: 12_000_000,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         let batch_generator = TimestampBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -578,9 +790,10 @@ mod tests {
             presence: new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
             ),
+            path: None,
         };
         let batch_generator = TimestampBatchGenerator { column };
-        let arr = batch_generator.batch_array(1000);
+        let arr = batch_generator.batch_array(0, 1000);
 
         assert_eq!(arr.len(), 1000);
     }
@@ -592,8 +805,9 @@ mod tests {
             name: "timestamp_column".to_string(),
             provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: temp").unwrap()[0]),
+            path: None,
         };
         let batch_generator = TimestampBatchGenerator { column };
-        let _ = batch_generator.batch_array(1);
+        let _ = batch_generator.batch_array(0, 1);
     }
 }
@@ -1,22 +1,236 @@
 pub mod batch_generator;
+pub mod options;
+pub mod statistics;
 pub mod utils;
 
 use crate::config::Config;
 use crate::errors::FakeLakeError;
+use crate::generate::output_files::{
+    get_partition_value, OutputFile, OutputFiles, OutputStream, WrittenFile,
+};
 use crate::generate::output_format::OutputFormat;
 use batch_generator::{parquet_batch_generator_builder, ParquetBatchGenerator};
+use options::ParquetOptions;
 
-use arrow_array::{ArrayRef, Int32Array, RecordBatch};
-use arrow_schema::{Field, Schema};
-use log::debug;
-use parquet::{arrow::ArrowWriter, basic::Compression, file::properties::WriterProperties};
+use arrow_array::{
+    Array, ArrayRef, FixedSizeBinaryArray, Int32Array, RecordBatch, StringArray, UInt32Array,
+};
+use arrow_cast::cast;
+use arrow_cast::display::{ArrayFormatter, FormatOptions};
+use arrow_schema::extension::Uuid;
+use arrow_schema::{DataType, Field, Schema, SchemaRef, TimeUnit};
+use arrow_select::take::take_record_batch;
+use linked_hash_map::LinkedHashMap;
+use log::{debug, warn};
+use parquet::arrow::{ArrowWriter, PARQUET_FIELD_ID_META_KEY};
 use rayon::prelude::*;
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 const PARQUET_EXTENSION: &str = ".parquet";
+pub const BATCH_SIZE: u32 = 8192 * 8;
+
+/// Settings of the data files of table formats
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataFileSettings {
+    /// Parquet has no timestamp type in seconds, which are written as integers.
+    /// Table formats need timestamps to be written in another unit.
+    pub timestamp_unit: TimeUnit,
+    /// Columns get their index in the config, starting at 1, as parquet field id
+    pub field_ids: bool,
+    /// Partition columns are written in the files and not only in directory names
+    pub partition_columns: bool,
+    /// Columns set as uuid in the parquet section are written as 16 bytes,
+    /// table formats keep them as strings
+    pub uuid_columns: bool,
+}
+
+impl Default for DataFileSettings {
+    fn default() -> Self {
+        DataFileSettings {
+            timestamp_unit: TimeUnit::Second,
+            field_ids: false,
+            partition_columns: false,
+            uuid_columns: true,
+        }
+    }
+}
 
 #[derive(Debug, PartialEq)]
-pub struct OutputParquet;
+pub struct OutputParquet {
+    options: ParquetOptions,
+    settings: DataFileSettings,
+}
+
+impl OutputParquet {
+    pub fn new(options: ParquetOptions) -> OutputParquet {
+        OutputParquet::new_with_settings(options, DataFileSettings::default())
+    }
+
+    pub fn new_with_settings(options: ParquetOptions, settings: DataFileSettings) -> OutputParquet {
+        OutputParquet { options, settings }
+    }
+
+    fn is_uuid_column(&self, field: &Field) -> bool {
+        self.settings.uuid_columns
+            && field.data_type() == &DataType::Utf8
+            && self
+                .options
+                .columns
+                .iter()
+                .any(|column| column.uuid && &column.name == field.name())
+    }
+
+    fn get_written_schema(&self, schema: Schema) -> Schema {
+        let fields: Vec<Field> = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let mut field = field.as_ref().clone();
+                if let DataType::Timestamp(_, timezone) = field.data_type() {
+                    let data_type =
+                        DataType::Timestamp(self.settings.timestamp_unit, timezone.clone());
+                    field = field.with_data_type(data_type);
+                }
+                if self.is_uuid_column(&field) {
+                    field = Field::new(
+                        field.name(),
+                        DataType::FixedSizeBinary(16),
+                        field.is_nullable(),
+                    )
+                    .with_extension_type(Uuid);
+                }
+                if self.settings.field_ids {
+                    field = field.with_metadata(HashMap::from([(
+                        PARQUET_FIELD_ID_META_KEY.to_string(),
+                        (index + 1).to_string(),
+                    )]));
+                }
+                field
+            })
+            .collect();
+        Schema::new(fields)
+    }
+
+    /// Casts the generated columns to the written schema types
+    fn cast_batch(
+        &self,
+        batch: RecordBatch,
+        schema: &SchemaRef,
+    ) -> Result<RecordBatch, FakeLakeError> {
+        if batch.schema().fields() == schema.fields() {
+            return Ok(batch);
+        }
+
+        let mut columns = Vec::with_capacity(batch.num_columns());
+        for (column, field) in batch.columns().iter().zip(schema.fields()) {
+            if column.data_type() == field.data_type() {
+                columns.push(column.clone());
+                continue;
+            }
+            let array = match field.data_type() {
+                DataType::FixedSizeBinary(_) => get_uuid_array(column, field.name())?,
+                data_type => match cast(column, data_type) {
+                    Ok(value) => value,
+                    Err(e) => return Err(FakeLakeError::ArrowError(e)),
+                },
+            };
+            columns.push(array);
+        }
+        match RecordBatch::try_new(schema.clone(), columns) {
+            Ok(value) => Ok(value),
+            Err(e) => Err(FakeLakeError::ArrowError(e)),
+        }
+    }
+
+    /// Writes the rows of the config in the parquet files and returns them once closed
+    pub fn write_files(
+        &self,
+        config: &Config,
+        mut files: OutputFiles<ArrowWriter<OutputStream>>,
+    ) -> Result<Vec<WrittenFile>, FakeLakeError> {
+        // partition columns are only written in the directory names by default
+        let data_indexes: Vec<usize> = (0..config.columns.len())
+            .filter(|index| self.settings.partition_columns || !files.is_partition_column(*index))
+            .collect();
+        let schema = match self
+            .get_written_schema(get_schema_from_config(config))
+            .project(&data_indexes)
+        {
+            Ok(value) => Arc::new(value),
+            Err(e) => return Err(FakeLakeError::ArrowError(e)),
+        };
+        debug!("Writing schema: {:?}", schema);
+
+        for column in &self.options.columns {
+            if column.uuid && !self.settings.uuid_columns {
+                warn!(
+                    "parquet uuid of column {} is not supported by table formats. It is ignored.",
+                    column.name
+                );
+            }
+            if !schema
+                .fields()
+                .iter()
+                .any(|field| field.name() == &column.name)
+            {
+                warn!(
+                    "parquet settings of column {} are ignored as it is not written.",
+                    column.name
+                );
+            }
+        }
+        let props = self.options.get_writer_properties();
+
+        let create_writer = |path: &Path| {
+            let file = OutputStream::create(path)?;
+            match ArrowWriter::try_new(file, schema.clone(), Some(props.clone())) {
+                Ok(value) => Ok(value),
+                Err(e) => Err(FakeLakeError::ParquetError(e)),
+            }
+        };
+
+        // without partitions, the file is written even without rows
+        if !files.is_partitioned() {
+            files.get_writer(vec![], 0, create_writer)?;
+        }
+
+        generate_batches_from_config(config, self.options.batch_size, |batch| {
+            let data_batch = match batch.project(&data_indexes) {
+                Ok(value) => self.cast_batch(value, &schema)?,
+                Err(e) => return Err(FakeLakeError::ArrowError(e)),
+            };
+            for (partition_values, rows) in get_batch_partitions(&batch, files.partition_indexes())?
+            {
+                let partition_batch = match files.is_partitioned() {
+                    true => match take_record_batch(&data_batch, &UInt32Array::from(rows)) {
+                        Ok(value) => value,
+                        Err(e) => return Err(FakeLakeError::ArrowError(e)),
+                    },
+                    false => data_batch.clone(),
+                };
+
+                // the batch is sliced when the current file gets full
+                let mut offset = 0;
+                while offset < partition_batch.num_rows() {
+                    let rows_left = (partition_batch.num_rows() - offset) as u64;
+                    let (writer, rows) =
+                        files.get_writer(partition_values.clone(), rows_left, create_writer)?;
+                    let slice = partition_batch.slice(offset, rows as usize);
+                    if let Err(e) = writer.write(&slice) {
+                        return Err(FakeLakeError::ParquetError(e));
+                    }
+                    offset += rows as usize;
+                }
+            }
+            Ok(())
+        })?;
+
+        files.close_files()
+    }
+}
 
 impl OutputFormat for OutputParquet {
     fn get_extension(&self) -> &str {
@@ -30,63 +244,147 @@ impl OutputFormat for OutputParquet {
             ));
         }
 
-        let file_name = config.get_output_file_name(self.get_extension());
-        let rows = config.get_number_of_rows();
+        let files = OutputFiles::new(config, self.get_extension())?;
+        self.write_files(config, files).map(|_| ())
+    }
+}
 
-        let schema = get_schema_from_config(config);
-        debug!("Writing schema: {:?}", schema);
+impl OutputFile for ArrowWriter<OutputStream> {
+    fn bytes_written(&self) -> u64 {
+        (self.bytes_written() + self.in_progress_size()) as u64
+    }
 
-        // WriterProperties can be used to set Parquet file options
-        let props = WriterProperties::builder()
-            .set_compression(Compression::SNAPPY)
-            .build();
+    // writer must be closed to write footer
+    fn close(self) -> Result<(), FakeLakeError> {
+        match ArrowWriter::close(self) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(FakeLakeError::ParquetError(e)),
+        }
+    }
+}
 
-        let batch_size = 8192 * 8;
-        // ceil division
-        let iterations = (rows as f64 / batch_size as f64).ceil() as u32;
+/// Generates the rows of the config batch by batch, columns being generated in parallel.
+/// Each RecordBatch is given to write_batch as soon as it is complete.
+pub fn generate_batches_from_config<F>(
+    config: &Config,
+    batch_size: u32,
+    mut write_batch: F,
+) -> Result<(), FakeLakeError>
+where
+    F: FnMut(RecordBatch) -> Result<(), FakeLakeError>,
+{
+    let rows = config.get_number_of_rows();
+    // ceil division
+    let iterations = (rows as f64 / batch_size as f64).ceil() as u32;
 
-        let file = std::fs::File::create(file_name).unwrap();
-        let mut writer = ArrowWriter::try_new(file, Arc::new(schema.clone()), Some(props)).unwrap();
+    let mut schema_cols: Vec<(String, ArrayRef)> = Vec::new();
+    let mut provider_generators: Vec<Box<dyn ParquetBatchGenerator>> = Vec::new();
+    config.columns.clone().into_iter().for_each(|column| {
+        schema_cols.push((
+            column.clone().name,
+            Arc::new(Int32Array::from(vec![0])) as ArrayRef,
+        ));
+        provider_generators.push(parquet_batch_generator_builder(column.clone()))
+    });
 
-        let mut schema_cols: Vec<(String, ArrayRef)> = Vec::new();
-        let mut provider_generators: Vec<Box<dyn ParquetBatchGenerator>> = Vec::new();
-        config.columns.clone().into_iter().for_each(|column| {
-            schema_cols.push((
-                column.clone().name,
-                Arc::new(Int32Array::from(vec![0])) as ArrayRef,
-            ));
-            provider_generators.push(parquet_batch_generator_builder(column.clone()))
-        });
+    for i in 0..iterations {
+        debug!("Generating batch {} of {}...", i, iterations);
+        let rows_to_generate = if i == iterations - 1 {
+            rows - (i * batch_size)
+        } else {
+            batch_size
+        };
 
-        for i in 0..iterations {
-            debug!("Generating batch {} of {}...", i, iterations);
-            let rows_to_generate = if i == iterations - 1 {
-                rows - (i * batch_size)
-            } else {
-                batch_size
-            };
+        let schema_cols: Mutex<Vec<(String, ArrayRef)>> = Mutex::new(schema_cols.clone());
+        let provider_generators = provider_generators.clone();
 
-            let schema_cols: Mutex<Vec<(String, ArrayRef)>> = Mutex::new(schema_cols.clone());
-            let provider_generators = provider_generators.clone();
+        provider_generators
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(index, provider_generator)| {
+                let array = provider_generator.batch_array(i * batch_size, rows_to_generate);
+                schema_cols.lock().unwrap()[index] = (provider_generator.name().to_string(), array);
+            });
 
-            provider_generators.into_par_iter().enumerate().for_each(
-                |(index, provider_generator)| {
-                    let array = provider_generator.batch_array(rows_to_generate);
-                    schema_cols.lock().unwrap()[index] =
-                        (provider_generator.name().to_string(), array);
-                },
-            );
+        let batch = RecordBatch::try_from_iter(schema_cols.lock().unwrap().clone()).unwrap();
+        write_batch(batch)?;
+    }
+
+    Ok(())
+}
 
-            let batch = RecordBatch::try_from_iter(schema_cols.lock().unwrap().clone()).unwrap();
-            writer.write(&batch).expect("Writing batch");
+/// Groups the rows of the batch by the values of the partition columns.
+/// Without partition columns, all rows are in a single group.
+pub fn get_batch_partitions(
+    batch: &RecordBatch,
+    partition_indexes: &[usize],
+) -> Result<LinkedHashMap<Vec<String>, Vec<u32>>, FakeLakeError> {
+    let mut partitions: LinkedHashMap<Vec<String>, Vec<u32>> = LinkedHashMap::new();
+    if partition_indexes.is_empty() {
+        partitions.insert(vec![], (0..batch.num_rows() as u32).collect());
+        return Ok(partitions);
+    }
+
+    let options = FormatOptions::default();
+    let mut formatters = Vec::new();
+    for index in partition_indexes {
+        let array = batch.column(*index);
+        match ArrayFormatter::try_new(array.as_ref(), &options) {
+            Ok(formatter) => formatters.push((array, formatter)),
+            Err(e) => return Err(FakeLakeError::ArrowError(e)),
         }
-        // writer must be closed to write footer
-        writer.close().unwrap();
-        Ok(())
+    }
+
+    for row in 0..batch.num_rows() {
+        let partition_values: Vec<String> = formatters
+            .iter()
+            .map(|(array, formatter)| match array.is_null(row) {
+                true => get_partition_value(None),
+                false => get_partition_value(Some(&formatter.value(row).to_string())),
+            })
+            .collect();
+        match partitions.get_mut(&partition_values) {
+            Some(rows) => rows.push(row as u32),
+            None => {
+                partitions.insert(partition_values, vec![row as u32]);
+            }
+        }
+    }
+
+    Ok(partitions)
+}
+
+/// Parses the uuid strings of the column to their 16 bytes
+fn get_uuid_array(column: &ArrayRef, name: &str) -> Result<ArrayRef, FakeLakeError> {
+    let strings = match column.as_any().downcast_ref::<StringArray>() {
+        Some(value) => value,
+        None => {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "parquet uuid column {} should be a string column",
+                name
+            )))
+        }
+    };
+    let mut uuids = Vec::with_capacity(strings.len());
+    for value in strings {
+        match value.map(uuid::Uuid::parse_str) {
+            Some(Ok(value)) => uuids.push(Some(value.into_bytes())),
+            Some(Err(_)) => {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "parquet uuid column {} has values which are not uuids",
+                    name
+                )))
+            }
+            None => uuids.push(None),
+        }
+    }
+    match FixedSizeBinaryArray::try_from_sparse_iter_with_size(uuids.into_iter(), 16) {
+        Ok(value) => Ok(Arc::new(value)),
+        Err(e) => Err(FakeLakeError::ArrowError(e)),
     }
 }
 
-fn get_schema_from_config(config: &Config) -> Schema {
+pub fn get_schema_from_config(config: &Config) -> Schema {
     let mut fields = Vec::new();
 
     for column in &config.columns {
@@ -106,7 +404,15 @@ mod tests {
     use crate::config::{Column, Config, Info};
     use crate::options::presence;
     use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+    use crate::providers::random::uuid::RandomUuidProvider;
 
+    use arrow_array::TimestampMicrosecondArray;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use parquet::basic::{Compression, GzipLevel, LogicalType};
+    use std::fs::File;
     use yaml_rust::YamlLoader;
 
     #[ctor]
@@ -122,6 +428,7 @@ mod tests {
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
+            path: None,
         }];
 
         let config = Config {
@@ -131,6 +438,9 @@ mod tests {
                 output_format: None,
                 rows: None,
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         };
         let schema = get_schema_from_config(&config);
@@ -140,7 +450,7 @@ mod tests {
     }
     #[test]
     fn given_get_extension() {
-        let output_parquet = OutputParquet {};
+        let output_parquet = OutputParquet::new(ParquetOptions::default());
         assert_eq!(output_parquet.get_extension(), ".parquet");
     }
     #[test]
@@ -151,6 +461,7 @@ mod tests {
             presence: presence::new_from_yaml(
                 &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
             ),
+            path: None,
         }];
         let config = Config {
             columns,
@@ -159,10 +470,13 @@ mod tests {
                 output_format: None,
                 rows: None,
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         };
 
-        let output_parquet = OutputParquet {};
+        let output_parquet = OutputParquet::new(ParquetOptions::default());
         match output_parquet.generate_from_config(&config) {
             Ok(_) => (),
             _ => panic!(),
@@ -178,13 +492,466 @@ mod tests {
                 output_format: None,
                 rows: None,
                 seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output_parquet = OutputParquet::new(ParquetOptions::default());
+        match output_parquet.generate_from_config(&config) {
+            Err(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_no_partition_should_return_all_rows_in_one_group() {
+        let batch = RecordBatch::try_from_iter(vec![(
+            "id",
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        )])
+        .unwrap();
+        let partitions = get_batch_partitions(&batch, &[]).unwrap();
+        assert_eq!(partitions.len(), 1);
+        assert_eq!(partitions.get(&vec![]), Some(&vec![0, 1, 2]));
+    }
+
+    #[test]
+    fn given_partition_should_group_rows_by_value() {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "id",
+                Arc::new(Int32Array::from(vec![1, 2, 3, 4])) as ArrayRef,
+            ),
+            (
+                "group",
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(1), Some(2)])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let partitions = get_batch_partitions(&batch, &[1]).unwrap();
+        let partitions: Vec<(Vec<String>, Vec<u32>)> = partitions.into_iter().collect();
+        assert_eq!(
+            partitions,
+            vec![
+                (vec!["1".to_string()], vec![0, 2]),
+                (vec!["__HIVE_DEFAULT_PARTITION__".to_string()], vec![1]),
+                (vec!["2".to_string()], vec![3]),
+            ]
+        );
+    }
+
+    #[test]
+    fn given_partition_by_should_write_hive_directories() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "flag".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: flag\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+        let directory = "target/test_generated/output_parquet_partitioned";
+        fs::remove_dir_all(directory).ok();
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some(directory.to_string()),
+                output_format: None,
+                rows: Some(100_000),
+                seed: None,
+                partition_by: Some(vec!["flag".to_string()]),
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output_parquet = OutputParquet::new(ParquetOptions::default());
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+
+        let mut total_rows = 0;
+        for partition in ["true", "false"] {
+            let file_name = format!("{}/flag={}/part-00000.parquet", directory, partition);
+            let file = File::open(file_name).unwrap();
+            let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+            assert_eq!(reader.schema().fields().len(), 1);
+            assert_eq!(reader.schema().field(0).name(), "id");
+            total_rows += reader.metadata().file_metadata().num_rows();
+        }
+        assert_eq!(total_rows, 100_000);
+    }
+
+    #[test]
+    fn given_max_rows_per_file_should_split_files() {
+        let columns = vec![Column {
+            name: "id".to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+            ),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_parquet_split".to_string()),
+                output_format: None,
+                rows: Some(100_000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: Some(40_000),
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output_parquet = OutputParquet::new(ParquetOptions::default());
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+
+        let mut file_rows = vec![];
+        for index in 0..3 {
+            let file_name = format!(
+                "target/test_generated/output_parquet_split-{:05}.parquet",
+                index
+            );
+            let file = File::open(file_name).unwrap();
+            let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+            file_rows.push(reader.metadata().file_metadata().num_rows());
+        }
+        assert_eq!(file_rows, vec![40_000, 40_000, 20_000]);
+        assert!(!Path::new("target/test_generated/output_parquet_split-00003.parquet").exists());
+    }
+
+    #[test]
+    fn given_max_bytes_per_file_should_split_files_within_limit() {
+        let directory = "target/test_generated/output_parquet_bytes";
+        std::fs::remove_dir_all(directory).ok();
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "name".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 20,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: name\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some(format!("{}/output", directory)),
+                output_format: None,
+                rows: Some(200_000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: Some(512 * 1024),
+            }),
+        };
+
+        let output_parquet = OutputParquet::new(ParquetOptions::default());
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+
+        let mut total_rows = 0;
+        let mut files = 0;
+        for entry in std::fs::read_dir(directory).unwrap() {
+            let path = entry.unwrap().path();
+            let size = std::fs::metadata(&path).unwrap().len();
+            // a row is an integer and a string of at most 20 characters
+            assert!(size <= 512 * 1024 + 32);
+            let reader =
+                ParquetRecordBatchReaderBuilder::try_new(File::open(path).unwrap()).unwrap();
+            total_rows += reader.metadata().file_metadata().num_rows();
+            files += 1;
+        }
+        assert!(files > 1);
+        assert_eq!(total_rows, 200_000);
+    }
+
+    #[test]
+    fn given_several_batches_should_continue_increment_across_them() {
+        let columns = vec![Column {
+            name: "id".to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: presence::new_from_yaml(&YamlLoader::load_from_str("name: id").unwrap()[0]),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: None,
+                output_format: None,
+                rows: Some(2_500),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
             }),
         };
 
-        let output_parquet = OutputParquet {};
+        let mut values = Vec::new();
+        generate_batches_from_config(&config, 1000, |batch| {
+            let ids = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            values.extend(ids.values().iter().copied());
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(values, (0..2_500).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn given_options_should_write_file_with_options() {
+        let columns = vec![Column {
+            name: "id".to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+            ),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_parquet_options".to_string()),
+                output_format: None,
+                rows: Some(10_000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+        let options = ParquetOptions {
+            compression: Compression::GZIP(GzipLevel::default()),
+            batch_size: 1000,
+            row_group_size: Some(4000),
+            ..ParquetOptions::default()
+        };
+
+        let output_parquet = OutputParquet::new(options);
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+
+        let file = File::open("target/test_generated/output_parquet_options.parquet").unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+        let metadata = reader.metadata();
+        assert_eq!(metadata.file_metadata().num_rows(), 10_000);
+        assert_eq!(metadata.num_row_groups(), 3);
+        assert_eq!(metadata.row_group(0).num_rows(), 4000);
+        assert_eq!(
+            metadata.row_group(0).column(0).compression(),
+            Compression::GZIP(GzipLevel::default())
+        );
+    }
+
+    #[test]
+    fn given_table_settings_should_write_timestamps_in_unit_with_field_ids() {
+        let columns = vec![Column {
+            name: "created".to_string(),
+            provider: Box::new(DatetimeProvider {
+                format: "%Y-%m-%d %H:%M:%S".to_string(),
+                after: 1_000_000,
+                before: 1_000_001,
+            }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: created\npresence: 1").unwrap()[0],
+            ),
+            path: None,
+        }];
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_parquet_micros".to_string()),
+                output_format: None,
+                rows: Some(2),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output_parquet = OutputParquet::new_with_settings(
+            ParquetOptions::default(),
+            DataFileSettings {
+                timestamp_unit: TimeUnit::Microsecond,
+                field_ids: true,
+                ..Default::default()
+            },
+        );
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+
+        let file = File::open("target/test_generated/output_parquet_micros.parquet").unwrap();
+        let mut reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let batch = reader.next().unwrap().unwrap();
+        let values = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampMicrosecondArray>()
+            .unwrap();
+        assert_eq!(values.value(0), 1_000_000_000_000);
+        assert_eq!(
+            batch
+                .schema()
+                .field(0)
+                .metadata()
+                .get(PARQUET_FIELD_ID_META_KEY),
+            Some(&"1".to_string())
+        );
+    }
+
+    fn get_uuid_config(
+        name: &str,
+        provider: Box<dyn crate::providers::provider::Provider>,
+    ) -> Config {
+        Config {
+            columns: vec![Column {
+                name: "id".to_string(),
+                provider,
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            }],
+            info: Some(Info {
+                output_name: Some(name.to_string()),
+                output_format: None,
+                rows: Some(25),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    fn get_uuid_options() -> ParquetOptions {
+        ParquetOptions::new_from_yaml(
+            &YamlLoader::load_from_str("columns:\n  id:\n    uuid: true").unwrap()[0],
+        )
+    }
+
+    #[test]
+    fn given_uuid_column_should_write_fixed_size_binary_with_uuid_type() {
+        let name = "target/test_generated/output_parquet_uuid";
+        let config = get_uuid_config(name, Box::new(RandomUuidProvider {}));
+        let output_parquet = OutputParquet::new(get_uuid_options());
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+
+        let file = File::open(format!("{}.parquet", name)).unwrap();
+        let builder = ParquetRecordBatchReaderBuilder::try_new(file).unwrap();
+        let column = builder.parquet_schema().column(0);
+        assert_eq!(column.logical_type(), Some(LogicalType::Uuid));
+        assert_eq!(column.type_length(), 16);
+
+        let batch = builder.build().unwrap().next().unwrap().unwrap();
+        let values = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<FixedSizeBinaryArray>()
+            .unwrap();
+        assert!(values.null_count() > 0);
+        let value = values.iter().flatten().next().unwrap();
+        let uuid = uuid::Uuid::from_slice(value).unwrap();
+        assert_eq!(uuid.get_version(), Some(uuid::Version::Random));
+    }
+
+    #[test]
+    fn given_uuid_column_with_other_strings_should_error() {
+        let config = get_uuid_config(
+            "target/test_generated/output_parquet_not_uuid",
+            Box::new(AlphanumericProvider {
+                min_length: 1,
+                max_length: 5,
+            }),
+        );
+        let output_parquet = OutputParquet::new(get_uuid_options());
         match output_parquet.generate_from_config(&config) {
             Err(_) => (),
+            _ => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_batches_should_give_row_indexes_across_batches() {
+        let name = "target/test_generated/output_parquet_batches";
+        let mut config = get_uuid_config(
+            name,
+            Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+        );
+        config.columns[0].presence = presence::new_from_yaml(
+            &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+        );
+        let output_parquet = OutputParquet::new(ParquetOptions {
+            batch_size: 10,
+            ..Default::default()
+        });
+        match output_parquet.generate_from_config(&config) {
+            Ok(_) => (),
             _ => panic!(),
         }
+
+        let file = File::open(format!("{}.parquet", name)).unwrap();
+        let reader = ParquetRecordBatchReaderBuilder::try_new(file)
+            .unwrap()
+            .build()
+            .unwrap();
+        let mut values = Vec::new();
+        for batch in reader {
+            let batch = batch.unwrap();
+            let column = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            values.extend(column.values().iter().copied());
+        }
+        assert_eq!(values, (0..25).collect::<Vec<i32>>());
     }
 }
@@ -0,0 +1,477 @@
+use crate::config::parse_positive_integer;
+use crate::generate::parquet::BATCH_SIZE;
+
+use log::warn;
+use parquet::basic::{BrotliLevel, Compression, GzipLevel, ZstdLevel};
+use parquet::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
+use parquet::schema::types::ColumnPath;
+use yaml_rust::Yaml;
+
+/// Parquet writer settings of the info.parquet section
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParquetOptions {
+    pub compression: Compression,
+    /// Number of rows generated and written at once
+    pub batch_size: u32,
+    pub row_group_size: Option<usize>,
+    pub data_page_size: Option<usize>,
+    pub dictionary: bool,
+    pub statistics: EnabledStatistics,
+    pub writer_version: WriterVersion,
+    pub columns: Vec<ParquetColumnOptions>,
+}
+
+/// Settings of one column, overriding the file settings
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParquetColumnOptions {
+    pub name: String,
+    pub dictionary: Option<bool>,
+    pub bloom_filter: bool,
+    pub bloom_filter_fpp: Option<f64>,
+    pub bloom_filter_ndv: Option<u64>,
+    /// Uuid strings are written as 16 bytes with the UUID logical type
+    pub uuid: bool,
+}
+
+impl Default for ParquetOptions {
+    fn default() -> Self {
+        ParquetOptions {
+            compression: Compression::SNAPPY,
+            batch_size: BATCH_SIZE,
+            row_group_size: None,
+            data_page_size: None,
+            dictionary: true,
+            statistics: EnabledStatistics::Page,
+            writer_version: WriterVersion::PARQUET_1_0,
+            columns: vec![],
+        }
+    }
+}
+
+impl ParquetOptions {
+    pub fn new_from_yaml(section: &Yaml) -> ParquetOptions {
+        let mut options = ParquetOptions::default();
+
+        match section {
+            Yaml::Hash(_) => (),
+            Yaml::BadValue => return options,
+            _ => {
+                warn!("parquet section should be a map of settings. Default settings are taken.");
+                return options;
+            }
+        }
+
+        if let Some(compression) = parse_compression(section) {
+            options.compression = compression;
+        }
+
+        if let Some(batch_size) = parse_setting(section, "batch_size", false) {
+            match u32::try_from(batch_size) {
+                Ok(value) => options.batch_size = value,
+                Err(_) => warn!("parquet batch_size is too big. Default value is taken."),
+            }
+        }
+        options.row_group_size =
+            parse_setting(section, "row_group_size", false).map(|value| value as usize);
+        options.data_page_size =
+            parse_setting(section, "data_page_size", true).map(|value| value as usize);
+
+        match section["dictionary"] {
+            Yaml::Boolean(value) => options.dictionary = value,
+            Yaml::BadValue => (),
+            _ => warn!("parquet dictionary should be a bool. Default value 'true' is taken."),
+        }
+
+        match section["statistics"].as_str() {
+            Some("none") => options.statistics = EnabledStatistics::None,
+            Some("chunk") => options.statistics = EnabledStatistics::Chunk,
+            Some("page") => options.statistics = EnabledStatistics::Page,
+            None if section["statistics"].is_badvalue() => (),
+            _ => {
+                warn!("parquet statistics should be none, chunk or page. Default 'page' is taken.")
+            }
+        }
+
+        // writer_version could be a float (1.0) or a str ("1.0")
+        let writer_version = match &section["writer_version"] {
+            Yaml::Real(value) | Yaml::String(value) => Some(value.as_str()),
+            Yaml::Integer(1) => Some("1.0"),
+            Yaml::Integer(2) => Some("2.0"),
+            Yaml::BadValue => None,
+            _ => Some(""),
+        };
+        match writer_version {
+            Some("1.0") => options.writer_version = WriterVersion::PARQUET_1_0,
+            Some("2.0") => options.writer_version = WriterVersion::PARQUET_2_0,
+            None => (),
+            _ => warn!("parquet writer_version should be 1.0 or 2.0. Default '1.0' is taken."),
+        }
+
+        match &section["columns"] {
+            Yaml::Hash(columns) => {
+                for (name, column) in columns {
+                    match name.as_str() {
+                        Some(name) => options.columns.push(parse_column(name, column)),
+                        None => warn!("parquet columns should be a map of column names."),
+                    }
+                }
+            }
+            Yaml::BadValue => (),
+            _ => warn!("parquet columns should be a map of column names."),
+        }
+
+        options
+    }
+
+    pub fn get_writer_properties(&self) -> WriterProperties {
+        let mut builder = WriterProperties::builder()
+            .set_compression(self.compression)
+            .set_dictionary_enabled(self.dictionary)
+            .set_statistics_enabled(self.statistics)
+            .set_writer_version(self.writer_version);
+
+        if let Some(row_group_size) = self.row_group_size {
+            builder = builder.set_max_row_group_size(row_group_size);
+        }
+        if let Some(data_page_size) = self.data_page_size {
+            builder = builder.set_data_page_size_limit(data_page_size);
+        }
+
+        for column in &self.columns {
+            let path = ColumnPath::from(column.name.as_str());
+            if let Some(dictionary) = column.dictionary {
+                builder = builder.set_column_dictionary_enabled(path.clone(), dictionary);
+            }
+            if column.bloom_filter {
+                builder = builder.set_column_bloom_filter_enabled(path.clone(), true);
+                if let Some(fpp) = column.bloom_filter_fpp {
+                    builder = builder.set_column_bloom_filter_fpp(path.clone(), fpp);
+                }
+                if let Some(ndv) = column.bloom_filter_ndv {
+                    builder = builder.set_column_bloom_filter_ndv(path, ndv);
+                }
+            }
+        }
+
+        builder.build()
+    }
+}
+
+fn parse_setting(section: &Yaml, name: &str, allow_units: bool) -> Option<u64> {
+    let value = &section[name];
+    if value.is_badvalue() {
+        return None;
+    }
+
+    let setting = parse_positive_integer(value, allow_units);
+    if setting.is_none() {
+        warn!(
+            "parquet {} should be a positive integer. Default value is taken.",
+            name
+        );
+    }
+    setting
+}
+
+fn parse_compression(section: &Yaml) -> Option<Compression> {
+    let codec = match section["compression"].as_str() {
+        Some(value) => value.to_lowercase(),
+        None if section["compression"].is_badvalue() => return None,
+        None => "".to_string(),
+    };
+    let level = match &section["compression_level"] {
+        Yaml::Integer(value) => Some(*value),
+        Yaml::BadValue => None,
+        _ => {
+            warn!("parquet compression_level should be an integer. Default level is taken.");
+            None
+        }
+    };
+
+    let compression = match codec.as_str() {
+        "none" | "uncompressed" => Compression::UNCOMPRESSED,
+        "snappy" => Compression::SNAPPY,
+        "lz4" | "lz4_raw" => Compression::LZ4_RAW,
+        "gzip" => match level.map(|level| GzipLevel::try_new(level as u32)) {
+            Some(Ok(level)) => Compression::GZIP(level),
+            Some(Err(_)) => {
+                warn!("gzip compression_level should be between 0 and 9. Default level is taken.");
+                Compression::GZIP(GzipLevel::default())
+            }
+            None => Compression::GZIP(GzipLevel::default()),
+        },
+        "brotli" => match level.map(|level| BrotliLevel::try_new(level as u32)) {
+            Some(Ok(level)) => Compression::BROTLI(level),
+            Some(Err(_)) => {
+                warn!(
+                    "brotli compression_level should be between 0 and 11. Default level is taken."
+                );
+                Compression::BROTLI(BrotliLevel::default())
+            }
+            None => Compression::BROTLI(BrotliLevel::default()),
+        },
+        "zstd" => match level.map(|level| ZstdLevel::try_new(level as i32)) {
+            Some(Ok(level)) => Compression::ZSTD(level),
+            Some(Err(_)) => {
+                warn!("zstd compression_level should be between 1 and 22. Default level is taken.");
+                Compression::ZSTD(ZstdLevel::default())
+            }
+            None => Compression::ZSTD(ZstdLevel::default()),
+        },
+        _ => {
+            warn!("parquet compression should be none, snappy, gzip, lz4, brotli or zstd. Default 'snappy' is taken.");
+            return None;
+        }
+    };
+
+    if level.is_some()
+        && matches!(
+            compression,
+            Compression::UNCOMPRESSED | Compression::SNAPPY | Compression::LZ4_RAW
+        )
+    {
+        warn!("parquet compression_level is only used by gzip, brotli and zstd. It is ignored.");
+    }
+
+    Some(compression)
+}
+
+fn parse_column(name: &str, column: &Yaml) -> ParquetColumnOptions {
+    let dictionary = match column["dictionary"] {
+        Yaml::Boolean(value) => Some(value),
+        Yaml::BadValue => None,
+        _ => {
+            warn!(
+                "parquet dictionary of column {} should be a bool. It is ignored.",
+                name
+            );
+            None
+        }
+    };
+    let bloom_filter = match column["bloom_filter"] {
+        Yaml::Boolean(value) => value,
+        Yaml::BadValue => false,
+        _ => {
+            warn!(
+                "parquet bloom_filter of column {} should be a bool. It is disabled.",
+                name
+            );
+            false
+        }
+    };
+    let bloom_filter_fpp = match column["bloom_filter_fpp"].as_f64() {
+        Some(value) if value > 0.0 && value < 1.0 => Some(value),
+        None if column["bloom_filter_fpp"].is_badvalue() => None,
+        _ => {
+            warn!("parquet bloom_filter_fpp of column {} should be between 0 and 1. Default value is taken.", name);
+            None
+        }
+    };
+    let bloom_filter_ndv = match &column["bloom_filter_ndv"] {
+        Yaml::BadValue => None,
+        value => {
+            let ndv = parse_positive_integer(value, false);
+            if ndv.is_none() {
+                warn!("parquet bloom_filter_ndv of column {} should be a positive integer. Default value is taken.", name);
+            }
+            ndv
+        }
+    };
+
+    let uuid = match column["uuid"] {
+        Yaml::Boolean(value) => value,
+        Yaml::BadValue => false,
+        _ => {
+            warn!(
+                "parquet uuid of column {} should be a bool. It is disabled.",
+                name
+            );
+            false
+        }
+    };
+
+    ParquetColumnOptions {
+        name: name.to_string(),
+        dictionary,
+        bloom_filter,
+        bloom_filter_fpp,
+        bloom_filter_ndv,
+        uuid,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_options(yaml: &str) -> ParquetOptions {
+        let yaml = YamlLoader::load_from_str(yaml).unwrap();
+        ParquetOptions::new_from_yaml(&yaml[0]["parquet"])
+    }
+
+    #[test]
+    fn given_no_section_should_return_default() {
+        let options = get_options("info: 1");
+        assert_eq!(options, ParquetOptions::default());
+        assert_eq!(options.compression, Compression::SNAPPY);
+        assert_eq!(options.batch_size, BATCH_SIZE);
+    }
+
+    #[test]
+    fn given_invalid_section_should_return_default() {
+        let options = get_options("parquet: zstd");
+        assert_eq!(options, ParquetOptions::default());
+    }
+
+    #[test]
+    fn given_compression_should_return_codec() {
+        for (codec, expected) in [
+            ("none", Compression::UNCOMPRESSED),
+            ("snappy", Compression::SNAPPY),
+            ("lz4", Compression::LZ4_RAW),
+            ("gzip", Compression::GZIP(GzipLevel::default())),
+            ("brotli", Compression::BROTLI(BrotliLevel::default())),
+            ("ZSTD", Compression::ZSTD(ZstdLevel::default())),
+        ] {
+            let options = get_options(&format!("parquet:\n  compression: {}", codec));
+            assert_eq!(options.compression, expected);
+        }
+    }
+
+    #[test]
+    fn given_compression_level_should_return_codec_with_level() {
+        let options = get_options("parquet:\n  compression: zstd\n  compression_level: 9");
+        assert_eq!(
+            options.compression,
+            Compression::ZSTD(ZstdLevel::try_new(9).unwrap())
+        );
+
+        let options = get_options("parquet:\n  compression: gzip\n  compression_level: 2");
+        assert_eq!(
+            options.compression,
+            Compression::GZIP(GzipLevel::try_new(2).unwrap())
+        );
+    }
+
+    #[test]
+    fn given_invalid_compression_should_return_default() {
+        let options = get_options("parquet:\n  compression: lzo");
+        assert_eq!(options.compression, Compression::SNAPPY);
+
+        let options = get_options("parquet:\n  compression: zstd\n  compression_level: 99");
+        assert_eq!(options.compression, Compression::ZSTD(ZstdLevel::default()));
+    }
+
+    #[test]
+    fn given_sizes_should_return_sizes() {
+        let options = get_options(
+            "parquet:\n  batch_size: 1_000\n  row_group_size: 100_000\n  data_page_size: 1MB",
+        );
+        assert_eq!(options.batch_size, 1000);
+        assert_eq!(options.row_group_size, Some(100_000));
+        assert_eq!(options.data_page_size, Some(1024 * 1024));
+    }
+
+    #[test]
+    fn given_invalid_sizes_should_return_default() {
+        let options = get_options("parquet:\n  batch_size: 0\n  row_group_size: many");
+        assert_eq!(options.batch_size, BATCH_SIZE);
+        assert_eq!(options.row_group_size, None);
+    }
+
+    #[test]
+    fn given_statistics_and_writer_version_should_return_them() {
+        let options = get_options("parquet:\n  statistics: chunk\n  writer_version: 2.0");
+        assert_eq!(options.statistics, EnabledStatistics::Chunk);
+        assert_eq!(options.writer_version, WriterVersion::PARQUET_2_0);
+
+        let options = get_options("parquet:\n  statistics: none\n  writer_version: '1.0'");
+        assert_eq!(options.statistics, EnabledStatistics::None);
+        assert_eq!(options.writer_version, WriterVersion::PARQUET_1_0);
+    }
+
+    #[test]
+    fn given_invalid_statistics_and_writer_version_should_return_default() {
+        let options = get_options("parquet:\n  statistics: all\n  writer_version: 3.0");
+        assert_eq!(options.statistics, EnabledStatistics::Page);
+        assert_eq!(options.writer_version, WriterVersion::PARQUET_1_0);
+    }
+
+    #[test]
+    fn given_columns_should_return_column_options() {
+        let options = get_options(
+            "
+parquet:
+  dictionary: false
+  columns:
+    id:
+      dictionary: true
+      bloom_filter: true
+      bloom_filter_fpp: 0.01
+      bloom_filter_ndv: 1_000
+    name:
+      bloom_filter: true
+      uuid: true
+",
+        );
+        assert!(!options.dictionary);
+        assert_eq!(
+            options.columns,
+            vec![
+                ParquetColumnOptions {
+                    name: "id".to_string(),
+                    dictionary: Some(true),
+                    bloom_filter: true,
+                    bloom_filter_fpp: Some(0.01),
+                    bloom_filter_ndv: Some(1000),
+                    uuid: false,
+                },
+                ParquetColumnOptions {
+                    name: "name".to_string(),
+                    dictionary: None,
+                    bloom_filter: true,
+                    bloom_filter_fpp: None,
+                    bloom_filter_ndv: None,
+                    uuid: true,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn given_options_should_return_writer_properties() {
+        let options = get_options(
+            "
+parquet:
+  compression: zstd
+  row_group_size: 1_000
+  data_page_size: 2048
+  dictionary: false
+  statistics: chunk
+  writer_version: 2.0
+  columns:
+    id:
+      dictionary: true
+      bloom_filter: true
+",
+        );
+        let props = options.get_writer_properties();
+        let id = ColumnPath::from("id");
+        let other = ColumnPath::from("other");
+
+        assert_eq!(
+            props.compression(&other),
+            Compression::ZSTD(ZstdLevel::default())
+        );
+        assert_eq!(props.max_row_group_size(), 1000);
+        assert_eq!(props.data_page_size_limit(), 2048);
+        assert!(!props.dictionary_enabled(&other));
+        assert!(props.dictionary_enabled(&id));
+        assert_eq!(props.statistics_enabled(&other), EnabledStatistics::Chunk);
+        assert_eq!(props.writer_version(), WriterVersion::PARQUET_2_0);
+        assert!(props.bloom_filter_properties(&id).is_some());
+        assert!(props.bloom_filter_properties(&other).is_none());
+    }
+}
@@ -0,0 +1,200 @@
+use crate::errors::FakeLakeError;
+
+use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::file::statistics::Statistics;
+use std::fs::File;
+use std::path::Path;
+
+/// Minimum or maximum of a column in a parquet file
+#[derive(Debug, Clone, PartialEq, PartialOrd)]
+pub enum Bound {
+    Boolean(bool),
+    Integer(i64),
+    Double(f64),
+    String(String),
+}
+
+/// Statistics of a column in a parquet file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStatistics {
+    /// Compressed size of the column chunks
+    pub size: u64,
+    pub null_count: Option<u64>,
+    /// Minimum and maximum, only given when all the row groups with values have them
+    pub bounds: Option<(Bound, Bound)>,
+}
+
+/// Statistics of a parquet file, read from its footer
+#[derive(Debug, Clone, PartialEq)]
+pub struct FileStatistics {
+    pub rows: u64,
+    /// Statistics of the columns, in the order of the file schema
+    pub columns: Vec<ColumnStatistics>,
+}
+
+fn get_bounds(statistics: &Statistics) -> Option<(Bound, Bound)> {
+    match statistics {
+        Statistics::Boolean(value) => Some((
+            Bound::Boolean(*value.min_opt()?),
+            Bound::Boolean(*value.max_opt()?),
+        )),
+        Statistics::Int32(value) => Some((
+            Bound::Integer(*value.min_opt()? as i64),
+            Bound::Integer(*value.max_opt()? as i64),
+        )),
+        Statistics::Int64(value) => Some((
+            Bound::Integer(*value.min_opt()?),
+            Bound::Integer(*value.max_opt()?),
+        )),
+        Statistics::Double(value) => {
+            let (min, max) = (*value.min_opt()?, *value.max_opt()?);
+            // infinite bounds can't be written by every table format
+            match min.is_finite() && max.is_finite() {
+                true => Some((Bound::Double(min), Bound::Double(max))),
+                false => None,
+            }
+        }
+        Statistics::ByteArray(value) => Some((
+            Bound::String(value.min_opt()?.as_utf8().ok()?.to_string()),
+            Bound::String(value.max_opt()?.as_utf8().ok()?.to_string()),
+        )),
+        _ => None,
+    }
+}
+
+fn merge_bounds(bounds: (Bound, Bound), other: (Bound, Bound)) -> (Bound, Bound) {
+    let min = match other.0 < bounds.0 {
+        true => other.0,
+        false => bounds.0,
+    };
+    let max = match other.1 > bounds.1 {
+        true => other.1,
+        false => bounds.1,
+    };
+    (min, max)
+}
+
+pub fn read_file_statistics(path: &Path) -> Result<FileStatistics, FakeLakeError> {
+    let reader = match SerializedFileReader::new(File::open(path)?) {
+        Ok(value) => value,
+        Err(e) => return Err(FakeLakeError::ParquetError(e)),
+    };
+    let metadata = reader.metadata();
+
+    let mut columns = Vec::new();
+    for index in 0..metadata.file_metadata().schema_descr().num_columns() {
+        let mut size = 0;
+        let mut null_count = Some(0);
+        let mut bounds: Option<(Bound, Bound)> = None;
+        let mut has_bounds = true;
+        for row_group in metadata.row_groups() {
+            let column = row_group.column(index);
+            size += column.compressed_size() as u64;
+            let statistics = match column.statistics() {
+                Some(value) => value,
+                None => {
+                    has_bounds = false;
+                    null_count = None;
+                    continue;
+                }
+            };
+            let row_group_nulls = statistics.null_count_opt();
+            null_count = null_count.zip(row_group_nulls).map(|(a, b)| a + b);
+
+            // only null values have no bounds
+            if row_group_nulls == Some(row_group.num_rows() as u64) {
+                continue;
+            }
+            match (get_bounds(statistics), bounds.take()) {
+                (Some(row_group_bounds), Some(current)) => {
+                    bounds = Some(merge_bounds(current, row_group_bounds))
+                }
+                (Some(row_group_bounds), None) => bounds = Some(row_group_bounds),
+                (None, _) => has_bounds = false,
+            }
+        }
+
+        columns.push(ColumnStatistics {
+            size,
+            null_count,
+            bounds: bounds.filter(|_| has_bounds),
+        });
+    }
+
+    Ok(FileStatistics {
+        rows: metadata.file_metadata().num_rows() as u64,
+        columns,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow_array::{ArrayRef, Float64Array, Int32Array, RecordBatch, StringArray};
+    use parquet::arrow::ArrowWriter;
+    use parquet::file::properties::{EnabledStatistics, WriterProperties};
+    use std::sync::Arc;
+
+    fn write_file(path: &str, statistics: EnabledStatistics) {
+        let batch = RecordBatch::try_from_iter(vec![
+            (
+                "id",
+                Arc::new(Int32Array::from(vec![Some(3), None, Some(-1)])) as ArrayRef,
+            ),
+            (
+                "name",
+                Arc::new(StringArray::from(vec!["b", "a", "c"])) as ArrayRef,
+            ),
+            (
+                "score",
+                Arc::new(Float64Array::from(vec![1.0, f64::INFINITY, 2.0])) as ArrayRef,
+            ),
+        ])
+        .unwrap();
+        let props = WriterProperties::builder()
+            .set_statistics_enabled(statistics)
+            .set_max_row_group_size(2)
+            .build();
+        let file = File::create(path).unwrap();
+        let mut writer = ArrowWriter::try_new(file, batch.schema(), Some(props)).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+    }
+
+    #[test]
+    fn given_file_should_merge_row_groups_statistics() {
+        let path = "target/test_generated/parquet_statistics.parquet";
+        write_file(path, EnabledStatistics::Chunk);
+
+        let statistics = read_file_statistics(Path::new(path)).unwrap();
+        assert_eq!(statistics.rows, 3);
+        assert_eq!(statistics.columns.len(), 3);
+        assert_eq!(statistics.columns[0].null_count, Some(1));
+        assert_eq!(
+            statistics.columns[0].bounds,
+            Some((Bound::Integer(-1), Bound::Integer(3)))
+        );
+        assert_eq!(
+            statistics.columns[1].bounds,
+            Some((
+                Bound::String("a".to_string()),
+                Bound::String("c".to_string())
+            ))
+        );
+        // an infinite bound makes the column without bounds
+        assert_eq!(statistics.columns[2].bounds, None);
+        assert!(statistics.columns[2].size > 0);
+    }
+
+    #[test]
+    fn given_file_without_statistics_should_only_count_rows() {
+        let path = "target/test_generated/parquet_no_statistics.parquet";
+        write_file(path, EnabledStatistics::None);
+
+        let statistics = read_file_statistics(Path::new(path)).unwrap();
+        assert_eq!(statistics.rows, 3);
+        assert_eq!(statistics.columns[0].null_count, None);
+        assert_eq!(statistics.columns[0].bounds, None);
+    }
+}
@@ -6,6 +6,8 @@ pub fn get_parquet_type_from_column(column: Column) -> DataType {
     match column.provider.value(0) {
         Value::Bool(_) => DataType::Boolean,
         Value::Int32(_) => DataType::Int32,
+        Value::Int64(_) => DataType::Int64,
+        Value::Decimal(_, precision, scale) => DataType::Decimal128(precision, scale as i8),
         Value::Float64(_) => DataType::Float64,
         Value::String(_) => DataType::Utf8,
         Value::Date(_, _) => DataType::Date32,
@@ -19,10 +21,12 @@ mod tests {
 
     use crate::config::Column;
     use crate::options::presence::new_from_yaml;
+    use crate::providers::random::number::decimal::DecimalProvider;
     use crate::providers::random::number::f64::F64Provider;
     use crate::providers::{
-        increment::integer::IncrementIntegerProvider, random::bool::BoolProvider,
-        random::date::date::DateProvider, random::date::datetime::DatetimeProvider,
+        increment::integer::IncrementIntegerProvider, increment::long::IncrementLongProvider,
+        random::bool::BoolProvider, random::date::date::DateProvider,
+        random::date::datetime::DatetimeProvider,
         random::string::alphanumeric::AlphanumericProvider,
     };
 
@@ -35,6 +39,7 @@ mod tests {
             name: "bool_column".to_string(),
             provider: Box::new(BoolProvider {}),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         assert_eq!(get_parquet_type_from_column(column), DataType::Boolean);
     }
@@ -45,16 +50,52 @@ mod tests {
             name: "int_column".to_string(),
             provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         assert_eq!(get_parquet_type_from_column(column), DataType::Int32);
     }
 
+    #[test]
+    fn given_long_provider_should_return_long_datatype() {
+        let column = Column {
+            name: "long_column".to_string(),
+            provider: Box::new(IncrementLongProvider { start: 0, step: 1 }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
+        };
+        assert_eq!(get_parquet_type_from_column(column), DataType::Int64);
+    }
+
+    #[test]
+    fn given_decimal_provider_should_return_decimal_datatype() {
+        let column = Column {
+            name: "decimal_column".to_string(),
+            provider: Box::new(DecimalProvider {
+                precision: 12,
+                scale: 3,
+                min: 0,
+                max: 1,
+            }),
+            presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
+        };
+        assert_eq!(
+            get_parquet_type_from_column(column),
+            DataType::Decimal128(12, 3)
+        );
+    }
+
     #[test]
     fn given_float_provider_should_return_float_datatype() {
         let column = Column {
             name: "float_column".to_string(),
-            provider: Box::new(F64Provider { min: 0.0, max: 1.0 }),
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.0,
+                distribution: None,
+            }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         assert_eq!(get_parquet_type_from_column(column), DataType::Float64);
     }
@@ -68,6 +109,7 @@ mod tests {
                 max_length: 11,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         assert_eq!(get_parquet_type_from_column(column), DataType::Utf8);
     }
@@ -82,6 +124,7 @@ mod tests {
                 after: 0,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         assert_eq!(get_parquet_type_from_column(column), DataType::Date32);
     }
@@ -96,6 +139,7 @@ mod tests {
                 before: 12_000_000,
             }),
             presence: new_from_yaml(&YamlLoader::load_from_str("name: test").unwrap()[0]),
+            path: None,
         };
         assert_eq!(
             get_parquet_type_from_column(column),
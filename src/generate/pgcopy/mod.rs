@@ -0,0 +1,533 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use std::io::{BufWriter, Write};
+
+const PG_COPY_TEXT_EXTENSION: &str = ".copy";
+const PG_COPY_BINARY_EXTENSION: &str = ".pgcopy";
+
+const PG_COPY_TEXT_NULL: &[u8] = b"\\N";
+const PG_COPY_TEXT_DATE_FORMAT: &str = "%Y-%m-%d";
+const PG_COPY_TEXT_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Signature, flags field and header extension area length
+const PG_COPY_BINARY_HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+const PG_COPY_BINARY_TRAILER: &[u8] = &(-1_i16).to_be_bytes();
+const PG_NUMERIC_POSITIVE: u16 = 0x0000;
+const PG_NUMERIC_NEGATIVE: u16 = 0x4000;
+
+#[derive(Debug, PartialEq)]
+pub struct OutputPgCopy {
+    binary: bool,
+}
+
+impl OutputPgCopy {
+    pub fn new(binary: bool) -> OutputPgCopy {
+        OutputPgCopy { binary }
+    }
+}
+
+impl OutputFormat for OutputPgCopy {
+    fn get_extension(&self) -> &str {
+        match self.binary {
+            true => PG_COPY_BINARY_EXTENSION,
+            false => PG_COPY_TEXT_EXTENSION,
+        }
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let mut buffer = BufWriter::new(OutputStream::create(file_name)?);
+        let rows = config.get_number_of_rows();
+        let field_count = (config.columns.len() as i16).to_be_bytes();
+
+        if self.binary {
+            buffer.write_all(PG_COPY_BINARY_HEADER)?;
+        }
+
+        for i in 0..rows {
+            if self.binary {
+                buffer.write_all(&field_count)?;
+            }
+
+            for (index, column) in config.columns.iter().enumerate() {
+                let value = match column.is_next_present() {
+                    true => Some(column.provider.value(i)),
+                    false => None,
+                };
+
+                match self.binary {
+                    true => write_binary_field(&mut buffer, value)?,
+                    false => {
+                        if index > 0 {
+                            buffer.write_all(b"\t")?;
+                        }
+                        write_text_field(&mut buffer, value)?
+                    }
+                }
+            }
+
+            if !self.binary {
+                buffer.write_all(b"\n")?;
+            }
+        }
+
+        if self.binary {
+            buffer.write_all(PG_COPY_BINARY_TRAILER)?;
+        }
+
+        buffer.flush()?;
+        Ok(())
+    }
+}
+
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{b}' => escaped.push_str("\\v"),
+            '\u{c}' => escaped.push_str("\\f"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn write_text_field<W: Write>(buffer: &mut W, value: Option<Value>) -> std::io::Result<()> {
+    let str_value = match value {
+        None => return buffer.write_all(PG_COPY_TEXT_NULL),
+        Some(Value::Bool(value)) => match value {
+            true => "t".to_string(),
+            false => "f".to_string(),
+        },
+        Some(Value::Int32(value)) => value.to_string(),
+        Some(Value::Int64(value)) => value.to_string(),
+        Some(Value::Decimal(value, _, scale)) => format_decimal(value, scale),
+        Some(Value::Float64(value)) => value.to_string(),
+        Some(Value::String(value)) => escape_text(&value),
+        Some(Value::Date(value, _)) => value.format(PG_COPY_TEXT_DATE_FORMAT).to_string(),
+        Some(Value::Timestamp(value, _)) => value.format(PG_COPY_TEXT_TIMESTAMP_FORMAT).to_string(),
+    };
+    buffer.write_all(str_value.as_bytes())
+}
+
+/// Numerics are base 10000 digits aligned on the decimal point, with the weight
+/// of the first digit, the sign and the number of decimal digits
+fn get_numeric_bytes(value: i128, scale: u8) -> Vec<u8> {
+    let text = format_decimal(value.abs(), scale);
+    let (integer, fraction) = text.split_once('.').unwrap_or((&text, ""));
+    let integer = format!(
+        "{:0>width$}",
+        integer,
+        width = integer.len().div_ceil(4) * 4
+    );
+    let fraction = format!(
+        "{:0<width$}",
+        fraction,
+        width = fraction.len().div_ceil(4) * 4
+    );
+
+    let mut digits: Vec<i16> = format!("{}{}", integer, fraction)
+        .as_bytes()
+        .chunks(4)
+        .map(|chunk| std::str::from_utf8(chunk).unwrap().parse().unwrap())
+        .collect();
+    let mut weight = (integer.len() / 4) as i16 - 1;
+    while digits.first() == Some(&0) {
+        digits.remove(0);
+        weight -= 1;
+    }
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    if digits.is_empty() {
+        weight = 0;
+    }
+    let sign: u16 = match value < 0 {
+        true => PG_NUMERIC_NEGATIVE,
+        false => PG_NUMERIC_POSITIVE,
+    };
+
+    let mut bytes = Vec::with_capacity(8 + digits.len() * 2);
+    bytes.extend_from_slice(&(digits.len() as i16).to_be_bytes());
+    bytes.extend_from_slice(&weight.to_be_bytes());
+    bytes.extend_from_slice(&sign.to_be_bytes());
+    bytes.extend_from_slice(&(scale as u16).to_be_bytes());
+    for digit in digits {
+        bytes.extend_from_slice(&digit.to_be_bytes());
+    }
+    bytes
+}
+
+/// Each field is written as its length in bytes followed by its binary representation.
+/// Dates and timestamps are relative to the PostgreSQL epoch 2000-01-01.
+fn write_binary_field<W: Write>(buffer: &mut W, value: Option<Value>) -> std::io::Result<()> {
+    let bytes: Vec<u8> = match value {
+        None => return buffer.write_all(&(-1_i32).to_be_bytes()),
+        Some(Value::Bool(value)) => vec![value as u8],
+        Some(Value::Int32(value)) => value.to_be_bytes().to_vec(),
+        Some(Value::Int64(value)) => value.to_be_bytes().to_vec(),
+        Some(Value::Decimal(value, _, scale)) => get_numeric_bytes(value, scale),
+        Some(Value::Float64(value)) => value.to_be_bytes().to_vec(),
+        Some(Value::String(value)) => value.into_bytes(),
+        Some(Value::Date(value, _)) => {
+            let pg_epoch = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+            let days = value.signed_duration_since(pg_epoch).num_days() as i32;
+            days.to_be_bytes().to_vec()
+        }
+        Some(Value::Timestamp(value, _)) => {
+            let pg_epoch = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+            let micros = value
+                .signed_duration_since(pg_epoch)
+                .num_microseconds()
+                .unwrap_or(i64::MAX);
+            micros.to_be_bytes().to_vec()
+        }
+    };
+    buffer.write_all(&(bytes.len() as i32).to_be_bytes())?;
+    buffer.write_all(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
+        let mut columns = vec![];
+
+        for _ in 0..nb_columns {
+            columns.push(Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            });
+        }
+
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::PgCopy(false)),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    fn text_field(value: Option<Value>) -> String {
+        let mut buffer: Vec<u8> = Vec::new();
+        write_text_field(&mut buffer, value).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    fn binary_field(value: Option<Value>) -> Vec<u8> {
+        let mut buffer: Vec<u8> = Vec::new();
+        write_binary_field(&mut buffer, value).unwrap();
+        buffer
+    }
+
+    #[test]
+    fn given_decimal_should_return_numeric_bytes() {
+        // 12345.678: digits 1, 2345 and 6780 from weight 1
+        assert_eq!(
+            get_numeric_bytes(12_345_678, 3),
+            vec![0, 3, 0, 1, 0, 0, 0, 3, 0, 1, 0x09, 0x29, 0x1a, 0x7c]
+        );
+        // -0.0001: digit 1 of weight -1
+        assert_eq!(
+            get_numeric_bytes(-1, 4),
+            vec![0, 1, 0xff, 0xff, 0x40, 0, 0, 4, 0, 1]
+        );
+        // 10000: digit 1 of weight 1, trailing zero digits are dropped
+        assert_eq!(
+            get_numeric_bytes(10_000, 0),
+            vec![0, 1, 0, 1, 0, 0, 0, 0, 0, 1]
+        );
+        assert_eq!(get_numeric_bytes(0, 2), vec![0, 0, 0, 0, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn given_get_extension() {
+        assert_eq!(OutputPgCopy { binary: false }.get_extension(), ".copy");
+        assert_eq!(OutputPgCopy { binary: true }.get_extension(), ".pgcopy");
+    }
+
+    #[test]
+    fn given_null_should_write_text_null_marker() {
+        assert_eq!(text_field(None), "\\N");
+    }
+
+    #[test]
+    fn given_empty_string_should_not_write_text_null_marker() {
+        assert_eq!(text_field(Some(Value::String(String::new()))), "");
+    }
+
+    #[test]
+    fn given_special_characters_should_escape_text() {
+        assert_eq!(
+            text_field(Some(Value::String("a\tb\nc\\d\re".to_string()))),
+            "a\\tb\\nc\\\\d\\re"
+        );
+    }
+
+    #[test]
+    fn given_values_should_write_text() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let timestamp = Utc.with_ymd_and_hms(2024, 2, 29, 13, 5, 59).unwrap();
+
+        assert_eq!(text_field(Some(Value::Bool(true))), "t");
+        assert_eq!(text_field(Some(Value::Bool(false))), "f");
+        assert_eq!(text_field(Some(Value::Int32(-42))), "-42");
+        assert_eq!(
+            text_field(Some(Value::Int64(-10_000_000_000))),
+            "-10000000000"
+        );
+        assert_eq!(text_field(Some(Value::Float64(1.5))), "1.5");
+        assert_eq!(
+            text_field(Some(Value::Date(date, "%d/%m/%Y".to_string()))),
+            "2024-02-29"
+        );
+        assert_eq!(
+            text_field(Some(Value::Timestamp(timestamp, "%s".to_string()))),
+            "2024-02-29 13:05:59"
+        );
+    }
+
+    #[test]
+    fn given_null_should_write_binary_minus_one_length() {
+        assert_eq!(binary_field(None), vec![0xff, 0xff, 0xff, 0xff]);
+    }
+
+    #[test]
+    fn given_values_should_write_binary() {
+        assert_eq!(binary_field(Some(Value::Bool(true))), vec![0, 0, 0, 1, 1]);
+        assert_eq!(
+            binary_field(Some(Value::Int32(258))),
+            vec![0, 0, 0, 4, 0, 0, 1, 2]
+        );
+        assert_eq!(
+            binary_field(Some(Value::Int64(258))),
+            vec![0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1, 2]
+        );
+        assert_eq!(
+            text_field(Some(Value::Decimal(-100_005, 10, 2))),
+            "-1000.05"
+        );
+        assert_eq!(
+            binary_field(Some(Value::Float64(1.0))),
+            vec![0, 0, 0, 8, 0x3f, 0xf0, 0, 0, 0, 0, 0, 0]
+        );
+        assert_eq!(
+            binary_field(Some(Value::String("ab".to_string()))),
+            vec![0, 0, 0, 2, b'a', b'b']
+        );
+    }
+
+    #[test]
+    fn given_dates_should_write_binary_from_postgres_epoch() {
+        let date = NaiveDate::from_ymd_opt(2000, 1, 2).unwrap();
+        assert_eq!(
+            binary_field(Some(Value::Date(date, "%Y-%m-%d".to_string()))),
+            vec![0, 0, 0, 4, 0, 0, 0, 1]
+        );
+
+        let date = NaiveDate::from_ymd_opt(1999, 12, 31).unwrap();
+        assert_eq!(
+            binary_field(Some(Value::Date(date, "%Y-%m-%d".to_string()))),
+            vec![0, 0, 0, 4, 0xff, 0xff, 0xff, 0xff]
+        );
+
+        let timestamp = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 1).unwrap();
+        assert_eq!(
+            binary_field(Some(Value::Timestamp(timestamp, "%s".to_string()))),
+            [vec![0, 0, 0, 8], 1_000_000_i64.to_be_bytes().to_vec()].concat()
+        );
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(0, None, None);
+        let output = OutputPgCopy { binary: false };
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = get_config(1, None, None);
+        let output = OutputPgCopy { binary: false };
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_write_text_file() {
+        let config = get_config(
+            2,
+            Some("target/test_generated/output_pg_copy".to_string()),
+            Some(3),
+        );
+        let output = OutputPgCopy { binary: false };
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "0\t0\n1\t1\n2\t2\n",
+            std::fs::read_to_string("target/test_generated/output_pg_copy.copy").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_config_should_write_binary_file() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_pg_copy".to_string()),
+            Some(2),
+        );
+        let output = OutputPgCopy { binary: true };
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let expected = [
+            PG_COPY_BINARY_HEADER.to_vec(),
+            vec![0, 1, 0, 0, 0, 4, 0, 0, 0, 0],
+            vec![0, 1, 0, 0, 0, 4, 0, 0, 0, 1],
+            vec![0xff, 0xff],
+        ]
+        .concat();
+        assert_eq!(
+            expected,
+            std::fs::read("target/test_generated/output_pg_copy.pgcopy").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_file() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "bool".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "float".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "timestamp".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_pg_copy_all".to_string()),
+                output_format: Some(OutputType::PgCopy(true)),
+                rows: Some(1000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        for binary in [false, true] {
+            let output = OutputPgCopy { binary };
+            match output.generate_from_config(&config) {
+                Ok(_) => (),
+                Err(_) => panic!("Error"),
+            }
+        }
+    }
+}
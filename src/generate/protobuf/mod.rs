@@ -0,0 +1,547 @@
+use crate::config::{Column, Config};
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+
+use chrono::NaiveDate;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+const PROTOBUF_EXTENSION: &str = ".pb";
+const PROTO_SCHEMA_EXTENSION: &str = "proto";
+const DEFAULT_MESSAGE_NAME: &str = "Row";
+
+const WIRE_TYPE_VARINT: u8 = 0;
+const WIRE_TYPE_FIXED64: u8 = 1;
+const WIRE_TYPE_LENGTH_DELIMITED: u8 = 2;
+
+#[derive(Debug, PartialEq)]
+pub struct OutputProtobuf {
+    message_name: Option<String>,
+}
+
+impl OutputProtobuf {
+    pub fn new(message_name: Option<String>) -> OutputProtobuf {
+        OutputProtobuf { message_name }
+    }
+
+    fn get_message_name(&self) -> String {
+        match &self.message_name {
+            Some(message_name) => get_proto_name(message_name),
+            None => DEFAULT_MESSAGE_NAME.to_string(),
+        }
+    }
+
+    /// Schema of the messages, the field numbers being the column positions
+    pub fn get_proto_schema(&self, columns: &[Column]) -> Result<String, FakeLakeError> {
+        let names = get_field_names(columns)?;
+        let has_timestamp = columns
+            .iter()
+            .any(|column| matches!(column.provider.value(0), Value::Timestamp(_, _)));
+
+        let mut schema = String::from("syntax = \"proto3\";\n\n");
+        if has_timestamp {
+            schema.push_str("import \"google/protobuf/timestamp.proto\";\n\n");
+        }
+        schema.push_str(&format!("message {} {{\n", self.get_message_name()));
+        for (index, (column, name)) in columns.iter().zip(names).enumerate() {
+            let value = column.provider.value(0);
+            // messages already have an explicit presence
+            let label = match column.can_be_null() && !matches!(value, Value::Timestamp(_, _)) {
+                true => "optional ",
+                false => "",
+            };
+            match value {
+                Value::Date(_, _) => schema.push_str("  // days since 1970-01-01\n"),
+                Value::Decimal(_, precision, scale) => {
+                    schema.push_str(&format!("  // decimal({}, {}) as text\n", precision, scale))
+                }
+                _ => (),
+            }
+            schema.push_str(&format!(
+                "  {}{} {} = {};\n",
+                label,
+                get_proto_type(&value),
+                name,
+                index + 1
+            ));
+        }
+        schema.push_str("}\n");
+        Ok(schema)
+    }
+}
+
+impl OutputFormat for OutputProtobuf {
+    fn get_extension(&self) -> &str {
+        PROTOBUF_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let schema = self.get_proto_schema(&config.columns)?;
+        // the schema is written next to the messages, only the messages go to standard output
+        if !config.is_stdout() {
+            let mut proto =
+                File::create(Path::new(&file_name).with_extension(PROTO_SCHEMA_EXTENSION))?;
+            proto.write_all(schema.as_bytes())?;
+        }
+
+        let mut buffer = BufWriter::new(OutputStream::create(file_name)?);
+        let rows = config.get_number_of_rows();
+        let mut message = Vec::new();
+        for i in 0..rows {
+            message.clear();
+            for (index, column) in config.columns.iter().enumerate() {
+                if column.is_next_present() {
+                    write_field(
+                        &mut message,
+                        index as u32 + 1,
+                        column.provider.value(i),
+                        column.can_be_null(),
+                    );
+                }
+            }
+
+            // messages are length delimited, as writeDelimitedTo
+            write_varint(&mut buffer, message.len() as u64)?;
+            buffer.write_all(&message)?;
+        }
+
+        buffer.flush()?;
+        Ok(())
+    }
+}
+
+/// Protobuf identifiers only have letters, digits and '_', without a leading digit
+fn get_proto_name(name: &str) -> String {
+    let mut proto_name: String = name
+        .chars()
+        .map(|c| match c.is_ascii_alphanumeric() {
+            true => c,
+            false => '_',
+        })
+        .collect();
+    if !proto_name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        proto_name.insert(0, '_');
+    }
+    proto_name
+}
+
+fn get_field_names(columns: &[Column]) -> Result<Vec<String>, FakeLakeError> {
+    let names: Vec<String> = columns
+        .iter()
+        .map(|column| get_proto_name(&column.name))
+        .collect();
+    for (index, name) in names.iter().enumerate() {
+        if names[..index].contains(name) {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "Protobuf field {} is given to more than one column",
+                name
+            )));
+        }
+    }
+    Ok(names)
+}
+
+fn get_proto_type(value: &Value) -> &str {
+    match value {
+        Value::Bool(_) => "bool",
+        Value::Int32(_) | Value::Date(_, _) => "int32",
+        Value::Int64(_) => "int64",
+        Value::Float64(_) => "double",
+        Value::String(_) | Value::Decimal(_, _, _) => "string",
+        Value::Timestamp(_, _) => "google.protobuf.Timestamp",
+    }
+}
+
+fn write_varint<W: Write>(buffer: &mut W, mut value: u64) -> std::io::Result<()> {
+    let mut bytes = [0_u8; 10];
+    let mut length = 0;
+    loop {
+        bytes[length] = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            break;
+        }
+        bytes[length] |= 0x80;
+        length += 1;
+    }
+    buffer.write_all(&bytes[..=length])
+}
+
+fn write_key(message: &mut Vec<u8>, number: u32, wire_type: u8) {
+    // writing to a vector can't fail
+    write_varint(message, ((number as u64) << 3) | wire_type as u64).unwrap();
+}
+
+/// Negative int32 are sign extended to ten bytes varints
+fn write_int32(message: &mut Vec<u8>, number: u32, value: i32) {
+    write_key(message, number, WIRE_TYPE_VARINT);
+    write_varint(message, value as i64 as u64).unwrap();
+}
+
+fn write_bytes(message: &mut Vec<u8>, number: u32, value: &[u8]) {
+    write_key(message, number, WIRE_TYPE_LENGTH_DELIMITED);
+    write_varint(message, value.len() as u64).unwrap();
+    message.extend_from_slice(value);
+}
+
+/// Fields without explicit presence are not written with their default value,
+/// as protobuf serializers do
+fn write_field(message: &mut Vec<u8>, number: u32, value: Value, explicit_presence: bool) {
+    match value {
+        Value::Bool(value) => {
+            if value || explicit_presence {
+                write_key(message, number, WIRE_TYPE_VARINT);
+                message.push(value as u8);
+            }
+        }
+        Value::Int32(value) => {
+            if value != 0 || explicit_presence {
+                write_int32(message, number, value);
+            }
+        }
+        Value::Int64(value) => {
+            if value != 0 || explicit_presence {
+                write_key(message, number, WIRE_TYPE_VARINT);
+                write_varint(message, value as u64).unwrap();
+            }
+        }
+        Value::Float64(value) => {
+            if value.to_bits() != 0 || explicit_presence {
+                write_key(message, number, WIRE_TYPE_FIXED64);
+                message.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        Value::String(value) => {
+            if !value.is_empty() || explicit_presence {
+                write_bytes(message, number, value.as_bytes());
+            }
+        }
+        // decimal texts are never empty
+        Value::Decimal(value, _, scale) => {
+            write_bytes(message, number, format_decimal(value, scale).as_bytes())
+        }
+        Value::Date(value, _) => {
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+            let days = value.signed_duration_since(epoch).num_days() as i32;
+            if days != 0 || explicit_presence {
+                write_int32(message, number, days);
+            }
+        }
+        Value::Timestamp(value, _) => {
+            let mut timestamp = Vec::new();
+            if value.timestamp() != 0 {
+                write_key(&mut timestamp, 1, WIRE_TYPE_VARINT);
+                write_varint(&mut timestamp, value.timestamp() as u64).unwrap();
+            }
+            if value.timestamp_subsec_nanos() != 0 {
+                write_int32(&mut timestamp, 2, value.timestamp_subsec_nanos() as i32);
+            }
+            write_bytes(message, number, &timestamp);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::decimal::DecimalProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use chrono::{TimeZone, Utc};
+    use prost::Message;
+    use yaml_rust::YamlLoader;
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Timestamp {
+        #[prost(int64, tag = "1")]
+        seconds: i64,
+        #[prost(int32, tag = "2")]
+        nanos: i32,
+    }
+
+    #[derive(Clone, PartialEq, prost::Message)]
+    struct Row {
+        #[prost(int32, tag = "1")]
+        id: i32,
+        #[prost(bool, optional, tag = "2")]
+        active: Option<bool>,
+        #[prost(string, optional, tag = "3")]
+        name: Option<String>,
+        #[prost(double, tag = "4")]
+        score: f64,
+        #[prost(int32, tag = "5")]
+        birthday: i32,
+        #[prost(message, optional, tag = "6")]
+        created: Option<Timestamp>,
+    }
+
+    fn get_column(
+        name: &str,
+        provider: Box<dyn crate::providers::provider::Provider>,
+        presence: f64,
+    ) -> Column {
+        Column {
+            name: name.to_string(),
+            provider,
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: {}\npresence: {}", name, presence))
+                    .unwrap()[0],
+            ),
+            path: None,
+        }
+    }
+
+    fn get_config(columns: Vec<Column>, name: Option<String>, rows: Option<u32>) -> Config {
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Protobuf(None)),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    fn get_row_columns() -> Vec<Column> {
+        vec![
+            get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: -1, step: 1 }),
+                1.0,
+            ),
+            get_column("active", Box::new(BoolProvider {}), 0.5),
+            get_column(
+                "name",
+                Box::new(AlphanumericProvider {
+                    min_length: 0,
+                    max_length: 5,
+                }),
+                0.5,
+            ),
+            get_column(
+                "score",
+                Box::new(F64Provider {
+                    min: 1.0,
+                    max: 2.0,
+                    distribution: None,
+                }),
+                1.0,
+            ),
+            get_column(
+                "birthday",
+                Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 719163,
+                    before: 719173,
+                }),
+                1.0,
+            ),
+            get_column(
+                "created",
+                Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 1_000_000,
+                    before: 1_000_001,
+                }),
+                1.0,
+            ),
+        ]
+    }
+
+    fn encode(value: Value, explicit_presence: bool) -> Vec<u8> {
+        let mut message = Vec::new();
+        write_field(&mut message, 1, value, explicit_presence);
+        message
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputProtobuf::new(None);
+        assert_eq!(output.get_extension(), ".pb");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(vec![], None, None);
+        let output = OutputProtobuf::new(None);
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_duplicated_field_names_should_error() {
+        let columns = vec![
+            get_column(
+                "first name",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                1.0,
+            ),
+            get_column(
+                "first_name",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                1.0,
+            ),
+        ];
+        let output = OutputProtobuf::new(None);
+        match output.get_proto_schema(&columns) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_columns_should_return_proto_schema() {
+        let output = OutputProtobuf::new(Some("user-event".to_string()));
+        let schema = output.get_proto_schema(&get_row_columns()).unwrap();
+        assert_eq!(
+            schema,
+            "syntax = \"proto3\";\n\n\
+            import \"google/protobuf/timestamp.proto\";\n\n\
+            message user_event {\n  \
+            int32 id = 1;\n  \
+            optional bool active = 2;\n  \
+            optional string name = 3;\n  \
+            double score = 4;\n  \
+            // days since 1970-01-01\n  \
+            int32 birthday = 5;\n  \
+            google.protobuf.Timestamp created = 6;\n\
+            }\n"
+        );
+    }
+
+    #[test]
+    fn given_decimal_column_should_return_string_field() {
+        let columns = vec![get_column(
+            "amount",
+            Box::new(DecimalProvider {
+                precision: 12,
+                scale: 2,
+                min: 0,
+                max: 100,
+            }),
+            1.0,
+        )];
+        let output = OutputProtobuf::new(Some("payment".to_string()));
+        assert_eq!(
+            output.get_proto_schema(&columns).unwrap(),
+            "syntax = \"proto3\";\n\n\
+            message payment {\n  \
+            // decimal(12, 2) as text\n  \
+            string amount = 1;\n\
+            }\n"
+        );
+    }
+
+    #[test]
+    fn given_names_should_return_proto_names() {
+        assert_eq!(get_proto_name("id"), "id");
+        assert_eq!(get_proto_name("first name"), "first_name");
+        assert_eq!(get_proto_name("1st"), "_1st");
+    }
+
+    #[test]
+    fn given_values_should_encode_fields() {
+        assert_eq!(encode(Value::Bool(true), false), vec![0x08, 0x01]);
+        assert_eq!(encode(Value::Int32(300), false), vec![0x08, 0xac, 0x02]);
+        assert_eq!(encode(Value::Int32(-1), false).len(), 11);
+        assert_eq!(
+            encode(Value::Int64(1 << 35), false),
+            vec![0x08, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01]
+        );
+        assert!(encode(Value::Int64(0), false).is_empty());
+        assert_eq!(
+            encode(Value::Decimal(0, 3, 1), false),
+            vec![0x0a, 0x03, b'0', b'.', b'0']
+        );
+        assert_eq!(
+            encode(Value::String("ab".to_string()), false),
+            vec![0x0a, 0x02, b'a', b'b']
+        );
+        assert_eq!(
+            encode(Value::Float64(1.0), false),
+            [vec![0x09], 1.0_f64.to_le_bytes().to_vec()].concat()
+        );
+        assert_eq!(
+            encode(
+                Value::Timestamp(
+                    Utc.timestamp_opt(1, 5).unwrap(),
+                    "%Y-%m-%d %H:%M:%S".to_string()
+                ),
+                false
+            ),
+            vec![0x0a, 0x04, 0x08, 0x01, 0x10, 0x05]
+        );
+    }
+
+    #[test]
+    fn given_default_values_should_only_encode_with_explicit_presence() {
+        assert_eq!(encode(Value::Bool(false), false), Vec::<u8>::new());
+        assert_eq!(encode(Value::Int32(0), false), Vec::<u8>::new());
+        assert_eq!(
+            encode(Value::String(String::new()), false),
+            Vec::<u8>::new()
+        );
+        assert_eq!(encode(Value::Bool(false), true), vec![0x08, 0x00]);
+        assert_eq!(encode(Value::String(String::new()), true), vec![0x0a, 0x00]);
+    }
+
+    #[test]
+    fn given_config_should_write_length_delimited_messages() {
+        let name = "target/test_generated/protobuf_rows";
+        let config = get_config(get_row_columns(), Some(name.to_string()), Some(20));
+        let output = OutputProtobuf::new(None);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let schema = std::fs::read_to_string(format!("{}.proto", name)).unwrap();
+        assert!(schema.contains("message Row {"));
+
+        let messages = std::fs::read(format!("{}.pb", name)).unwrap();
+        let mut buffer = messages.as_slice();
+        let mut rows = Vec::new();
+        while !buffer.is_empty() {
+            rows.push(Row::decode_length_delimited(&mut buffer).unwrap());
+        }
+        assert_eq!(rows.len(), 20);
+        for (index, row) in rows.iter().enumerate() {
+            assert_eq!(row.id, index as i32 - 1);
+            assert!(row.score >= 1.0 && row.score < 2.0);
+            assert!((0..10).contains(&row.birthday));
+            assert_eq!(
+                row.created,
+                Some(Timestamp {
+                    seconds: 1_000_000,
+                    nanos: 0
+                })
+            );
+        }
+        assert!(rows.iter().any(|row| row.name.is_none()));
+        assert!(rows.iter().any(|row| row.name.is_some()));
+    }
+}
@@ -0,0 +1,747 @@
+use crate::config::{Column, Config};
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+const SQL_EXTENSION: &str = ".sql";
+const DEFAULT_TABLE_NAME: &str = "output";
+const ROWS_PER_INSERT: u32 = 1000;
+
+pub const SQL_DATE_FORMAT: &str = "%Y-%m-%d";
+pub const SQL_TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SqlDialect {
+    PostgreSql,
+    MySql,
+    Sqlite,
+}
+
+impl SqlDialect {
+    pub fn from_name(name: &str) -> Option<SqlDialect> {
+        match name.to_lowercase().as_str() {
+            "postgresql" | "postgres" => Some(SqlDialect::PostgreSql),
+            "mysql" => Some(SqlDialect::MySql),
+            "sqlite" => Some(SqlDialect::Sqlite),
+            _ => None,
+        }
+    }
+
+    pub fn quote_identifier(&self, identifier: &str) -> String {
+        match self {
+            SqlDialect::MySql => format!("`{}`", identifier.replace('`', "``")),
+            SqlDialect::PostgreSql | SqlDialect::Sqlite => {
+                format!("\"{}\"", identifier.replace('"', "\"\""))
+            }
+        }
+    }
+
+    fn quote_string(&self, value: &str) -> String {
+        match self {
+            // MySQL treats backslash as an escape character by default
+            SqlDialect::MySql => format!(
+                "'{}'",
+                value
+                    .replace('\\', "\\\\")
+                    .replace('\'', "''")
+                    .replace('\0', "\\0")
+            ),
+            SqlDialect::PostgreSql | SqlDialect::Sqlite => {
+                format!("'{}'", value.replace('\'', "''"))
+            }
+        }
+    }
+
+    fn stores_float(&self, value: f64) -> bool {
+        match self {
+            SqlDialect::PostgreSql => true,
+            // MySQL has no infinity nor NaN
+            SqlDialect::MySql => value.is_finite(),
+            // SQLite stores NaN as NULL
+            SqlDialect::Sqlite => !value.is_nan(),
+        }
+    }
+
+    /// Value written in the column, None for NULL.
+    /// Floats the dialect can't store are NULL when the column can be null,
+    /// otherwise infinities become the largest finite floats and NaN is an error.
+    pub fn get_column_value(
+        &self,
+        column: &Column,
+        value: Value,
+    ) -> Result<Option<Value>, FakeLakeError> {
+        match value {
+            Value::Float64(value) if !self.stores_float(value) => {
+                match (column.can_be_null(), value.is_nan()) {
+                    (true, _) => Ok(None),
+                    (false, false) => Ok(Some(Value::Float64(f64::MAX.copysign(value)))),
+                    (false, true) => Err(FakeLakeError::BadYAMLFormat(format!(
+                        "Column {} is NOT NULL and NaN can't be written with {:?}",
+                        column.name, self
+                    ))),
+                }
+            }
+            value => Ok(Some(value)),
+        }
+    }
+
+    fn sql_type(&self, value: &Value) -> String {
+        let sql_type = match (self, value) {
+            (SqlDialect::PostgreSql, Value::Decimal(_, precision, scale)) => {
+                return format!("NUMERIC({}, {})", precision, scale)
+            }
+            (SqlDialect::MySql, Value::Decimal(_, precision, scale)) => {
+                return format!("DECIMAL({}, {})", precision, scale)
+            }
+            // SQLite would store decimals as floats
+            (SqlDialect::Sqlite, Value::Decimal(_, _, _)) => "TEXT",
+            (SqlDialect::Sqlite, Value::Bool(_)) => "INTEGER",
+            (_, Value::Bool(_)) => "BOOLEAN",
+            (SqlDialect::MySql, Value::Int32(_)) => "INT",
+            (_, Value::Int32(_)) => "INTEGER",
+            (SqlDialect::Sqlite, Value::Int64(_)) => "INTEGER",
+            (_, Value::Int64(_)) => "BIGINT",
+            (SqlDialect::PostgreSql, Value::Float64(_)) => "DOUBLE PRECISION",
+            (SqlDialect::MySql, Value::Float64(_)) => "DOUBLE",
+            (SqlDialect::Sqlite, Value::Float64(_)) => "REAL",
+            (_, Value::String(_)) => "TEXT",
+            (SqlDialect::Sqlite, Value::Date(_, _)) => "TEXT",
+            (_, Value::Date(_, _)) => "DATE",
+            (SqlDialect::PostgreSql, Value::Timestamp(_, _)) => "TIMESTAMP",
+            (SqlDialect::MySql, Value::Timestamp(_, _)) => "DATETIME",
+            (SqlDialect::Sqlite, Value::Timestamp(_, _)) => "TEXT",
+        };
+        sql_type.to_string()
+    }
+
+    fn sql_value(&self, value: Value) -> String {
+        match (self, value) {
+            (SqlDialect::Sqlite, Value::Bool(value)) => (value as i32).to_string(),
+            (_, Value::Bool(value)) => value.to_string().to_uppercase(),
+            (_, Value::Int32(value)) => value.to_string(),
+            (_, Value::Int64(value)) => value.to_string(),
+            (SqlDialect::Sqlite, Value::Decimal(value, _, scale)) => {
+                format!("'{}'", format_decimal(value, scale))
+            }
+            (_, Value::Decimal(value, _, scale)) => format_decimal(value, scale),
+            (_, Value::Float64(value)) if value.is_finite() => value.to_string(),
+            (SqlDialect::PostgreSql, Value::Float64(value)) => match value {
+                value if value.is_nan() => "'NaN'".to_string(),
+                value if value > 0.0 => "'Infinity'".to_string(),
+                _ => "'-Infinity'".to_string(),
+            },
+            // SQLite reads out of range literals as infinity
+            (SqlDialect::Sqlite, Value::Float64(value)) if !value.is_nan() => match value {
+                value if value > 0.0 => "9e999".to_string(),
+                _ => "-9e999".to_string(),
+            },
+            // MySQL has no representation of infinity and NaN
+            (_, Value::Float64(_)) => "NULL".to_string(),
+            (_, Value::String(value)) => self.quote_string(&value),
+            (SqlDialect::PostgreSql, Value::Date(value, _)) => {
+                format!("DATE '{}'", value.format(SQL_DATE_FORMAT))
+            }
+            (_, Value::Date(value, _)) => format!("'{}'", value.format(SQL_DATE_FORMAT)),
+            (SqlDialect::PostgreSql, Value::Timestamp(value, _)) => {
+                format!("TIMESTAMP '{}'", value.format(SQL_TIMESTAMP_FORMAT))
+            }
+            (_, Value::Timestamp(value, _)) => {
+                format!("'{}'", value.format(SQL_TIMESTAMP_FORMAT))
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OutputSql {
+    dialect: SqlDialect,
+    table_name: Option<String>,
+}
+
+impl OutputSql {
+    pub fn new(dialect: SqlDialect, table_name: Option<String>) -> OutputSql {
+        OutputSql {
+            dialect,
+            table_name,
+        }
+    }
+
+    /// If not specified, the table takes the name of the generated file
+    pub fn get_table_name(&self, config: &Config) -> String {
+        match &self.table_name {
+            Some(table_name) => table_name.to_string(),
+            None if config.is_stdout() => DEFAULT_TABLE_NAME.to_string(),
+            None => {
+                let file_name = config.get_output_file_name(self.get_extension());
+                match Path::new(&file_name).file_stem() {
+                    Some(stem) => stem.to_string_lossy().to_string(),
+                    None => DEFAULT_TABLE_NAME.to_string(),
+                }
+            }
+        }
+    }
+
+    pub fn get_create_table(&self, table_name: &str, columns: &[Column]) -> String {
+        let definitions: Vec<String> = columns
+            .iter()
+            .map(|column| {
+                let sql_type = self.dialect.sql_type(&column.provider.value(0));
+                let not_null = match column.can_be_null() {
+                    true => "",
+                    false => " NOT NULL",
+                };
+                format!(
+                    "    {} {}{}",
+                    self.dialect.quote_identifier(&column.name),
+                    sql_type,
+                    not_null
+                )
+            })
+            .collect();
+
+        format!(
+            "CREATE TABLE {} (\n{}\n);\n",
+            self.dialect.quote_identifier(table_name),
+            definitions.join(",\n")
+        )
+    }
+
+    fn get_insert_into(&self, table_name: &str, columns: &[Column]) -> String {
+        let names: Vec<String> = columns
+            .iter()
+            .map(|column| self.dialect.quote_identifier(&column.name))
+            .collect();
+
+        format!(
+            "INSERT INTO {} ({}) VALUES\n",
+            self.dialect.quote_identifier(table_name),
+            names.join(", ")
+        )
+    }
+}
+
+impl OutputFormat for OutputSql {
+    fn get_extension(&self) -> &str {
+        SQL_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let mut buffer = BufWriter::new(OutputStream::create(file_name)?);
+        let rows = config.get_number_of_rows();
+
+        let table_name = self.get_table_name(config);
+        buffer.write_all(
+            self.get_create_table(&table_name, &config.columns)
+                .as_bytes(),
+        )?;
+
+        let insert_into = self.get_insert_into(&table_name, &config.columns);
+        for i in 0..rows {
+            match i % ROWS_PER_INSERT {
+                0 => buffer.write_all(insert_into.as_bytes())?,
+                _ => buffer.write_all(b",\n")?,
+            }
+
+            let mut row: Vec<String> = Vec::with_capacity(config.columns.len());
+            for column in &config.columns {
+                let value = match column.is_next_present() {
+                    true => self
+                        .dialect
+                        .get_column_value(column, column.provider.value(i))?,
+                    false => None,
+                };
+                let value = match value {
+                    Some(value) => self.dialect.sql_value(value),
+                    None => "NULL".to_string(),
+                };
+                row.push(value);
+            }
+            write!(buffer, "({})", row.join(", "))?;
+
+            if i % ROWS_PER_INSERT == ROWS_PER_INSERT - 1 || i == rows - 1 {
+                buffer.write_all(b";\n")?;
+            }
+        }
+
+        buffer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use chrono::{NaiveDate, TimeZone, Utc};
+    use yaml_rust::YamlLoader;
+
+    fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
+        let mut columns = vec![];
+
+        for _ in 0..nb_columns {
+            columns.push(Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            });
+        }
+
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Sql(SqlDialect::PostgreSql, None)),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputSql::new(SqlDialect::PostgreSql, None);
+        assert_eq!(output.get_extension(), ".sql");
+    }
+
+    #[test]
+    fn given_dialect_names_should_return_dialect() {
+        assert_eq!(
+            SqlDialect::from_name("postgresql"),
+            Some(SqlDialect::PostgreSql)
+        );
+        assert_eq!(
+            SqlDialect::from_name("Postgres"),
+            Some(SqlDialect::PostgreSql)
+        );
+        assert_eq!(SqlDialect::from_name("MySQL"), Some(SqlDialect::MySql));
+        assert_eq!(SqlDialect::from_name("sqlite"), Some(SqlDialect::Sqlite));
+        assert_eq!(SqlDialect::from_name("oracle"), None);
+    }
+
+    #[test]
+    fn given_identifier_should_be_quoted_by_dialect() {
+        assert_eq!(
+            SqlDialect::PostgreSql.quote_identifier("my \"id\""),
+            "\"my \"\"id\"\"\""
+        );
+        assert_eq!(SqlDialect::MySql.quote_identifier("my `id`"), "`my ``id```");
+        assert_eq!(SqlDialect::Sqlite.quote_identifier("id"), "\"id\"");
+    }
+
+    #[test]
+    fn given_string_should_be_escaped_by_dialect() {
+        assert_eq!(
+            SqlDialect::PostgreSql.sql_value(Value::String("it's \\n".to_string())),
+            "'it''s \\n'"
+        );
+        assert_eq!(
+            SqlDialect::MySql.sql_value(Value::String("it's \\n".to_string())),
+            "'it''s \\\\n'"
+        );
+        assert_eq!(
+            SqlDialect::Sqlite.sql_value(Value::String("it's".to_string())),
+            "'it''s'"
+        );
+    }
+
+    #[test]
+    fn given_long_should_be_bigint_by_dialect() {
+        let value = Value::Int64(10_000_000_000);
+        assert_eq!(SqlDialect::PostgreSql.sql_type(&value), "BIGINT");
+        assert_eq!(SqlDialect::MySql.sql_type(&value), "BIGINT");
+        assert_eq!(SqlDialect::Sqlite.sql_type(&value), "INTEGER");
+        assert_eq!(SqlDialect::MySql.sql_value(value), "10000000000");
+    }
+
+    #[test]
+    fn given_decimal_should_be_exact_by_dialect() {
+        let value = Value::Decimal(-12345, 12, 3);
+        assert_eq!(SqlDialect::PostgreSql.sql_type(&value), "NUMERIC(12, 3)");
+        assert_eq!(SqlDialect::MySql.sql_type(&value), "DECIMAL(12, 3)");
+        assert_eq!(SqlDialect::Sqlite.sql_type(&value), "TEXT");
+        assert_eq!(SqlDialect::PostgreSql.sql_value(value.clone()), "-12.345");
+        assert_eq!(SqlDialect::Sqlite.sql_value(value), "'-12.345'");
+    }
+
+    #[test]
+    fn given_not_finite_float_should_be_written_by_dialect() {
+        assert_eq!(
+            SqlDialect::PostgreSql.sql_value(Value::Float64(f64::INFINITY)),
+            "'Infinity'"
+        );
+        assert_eq!(
+            SqlDialect::PostgreSql.sql_value(Value::Float64(f64::NEG_INFINITY)),
+            "'-Infinity'"
+        );
+        assert_eq!(
+            SqlDialect::PostgreSql.sql_value(Value::Float64(f64::NAN)),
+            "'NaN'"
+        );
+        assert_eq!(
+            SqlDialect::Sqlite.sql_value(Value::Float64(f64::INFINITY)),
+            "9e999"
+        );
+        assert_eq!(
+            SqlDialect::Sqlite.sql_value(Value::Float64(f64::NAN)),
+            "NULL"
+        );
+        assert_eq!(
+            SqlDialect::MySql.sql_value(Value::Float64(f64::INFINITY)),
+            "NULL"
+        );
+        assert_eq!(SqlDialect::MySql.sql_value(Value::Float64(1.5)), "1.5");
+    }
+
+    #[test]
+    fn given_bool_should_be_written_by_dialect() {
+        assert_eq!(SqlDialect::PostgreSql.sql_value(Value::Bool(true)), "TRUE");
+        assert_eq!(SqlDialect::MySql.sql_value(Value::Bool(false)), "FALSE");
+        assert_eq!(SqlDialect::Sqlite.sql_value(Value::Bool(true)), "1");
+    }
+
+    #[test]
+    fn given_date_and_timestamp_should_be_written_by_dialect() {
+        let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        let timestamp = Utc.with_ymd_and_hms(2024, 2, 29, 13, 5, 59).unwrap();
+
+        assert_eq!(
+            SqlDialect::PostgreSql.sql_value(Value::Date(date, "%d/%m/%Y".to_string())),
+            "DATE '2024-02-29'"
+        );
+        assert_eq!(
+            SqlDialect::MySql.sql_value(Value::Date(date, "%d/%m/%Y".to_string())),
+            "'2024-02-29'"
+        );
+        assert_eq!(
+            SqlDialect::PostgreSql.sql_value(Value::Timestamp(timestamp, "%s".to_string())),
+            "TIMESTAMP '2024-02-29 13:05:59'"
+        );
+        assert_eq!(
+            SqlDialect::Sqlite.sql_value(Value::Timestamp(timestamp, "%s".to_string())),
+            "'2024-02-29 13:05:59'"
+        );
+    }
+
+    #[test]
+    fn given_no_table_name_should_use_file_name() {
+        let config = get_config(1, Some("target/test_generated/users".to_string()), None);
+        let output = OutputSql::new(SqlDialect::PostgreSql, None);
+        assert_eq!(output.get_table_name(&config), "users");
+
+        let output = OutputSql::new(SqlDialect::PostgreSql, Some("customers".to_string()));
+        assert_eq!(output.get_table_name(&config), "customers");
+    }
+
+    #[test]
+    fn given_stdout_without_table_name_should_use_default_table_name() {
+        let config = get_config(1, Some("-".to_string()), None);
+        let output = OutputSql::new(SqlDialect::PostgreSql, None);
+        assert_eq!(output.get_table_name(&config), "output");
+    }
+
+    #[test]
+    fn given_presence_should_create_table_with_not_null() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "score".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let output = OutputSql::new(SqlDialect::MySql, None);
+        assert_eq!(
+            output.get_create_table("users", &columns),
+            "CREATE TABLE `users` (\n    `id` INT NOT NULL,\n    `score` DOUBLE\n);\n"
+        );
+    }
+
+    #[test]
+    fn given_float_column_should_create_table_with_not_null() {
+        let columns = vec![Column {
+            name: "score".to_string(),
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.1,
+                distribution: None,
+            }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+            ),
+            path: None,
+        }];
+
+        let output = OutputSql::new(SqlDialect::MySql, None);
+        assert_eq!(
+            output.get_create_table("users", &columns),
+            "CREATE TABLE `users` (\n    `score` DOUBLE NOT NULL\n);\n"
+        );
+
+        let output = OutputSql::new(SqlDialect::PostgreSql, None);
+        assert_eq!(
+            output.get_create_table("users", &columns),
+            "CREATE TABLE \"users\" (\n    \"score\" DOUBLE PRECISION NOT NULL\n);\n"
+        );
+    }
+
+    #[test]
+    fn given_not_finite_float_should_follow_column_presence() {
+        let column = |presence: &str| Column {
+            name: "score".to_string(),
+            provider: Box::new(F64Provider {
+                min: 0.0,
+                max: 1.1,
+                distribution: None,
+            }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: id\npresence: {}", presence)).unwrap()
+                    [0],
+            ),
+            path: None,
+        };
+        let (nullable, not_null) = (column("0.5"), column("1"));
+
+        for value in [1.5, f64::INFINITY, f64::NAN] {
+            assert!(matches!(
+                SqlDialect::PostgreSql.get_column_value(&not_null, Value::Float64(value)),
+                Ok(Some(Value::Float64(_)))
+            ));
+        }
+        assert_eq!(
+            SqlDialect::MySql
+                .get_column_value(&not_null, Value::Float64(1.5))
+                .unwrap(),
+            Some(Value::Float64(1.5))
+        );
+        assert_eq!(
+            SqlDialect::MySql
+                .get_column_value(&nullable, Value::Float64(f64::INFINITY))
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            SqlDialect::MySql
+                .get_column_value(&not_null, Value::Float64(f64::NEG_INFINITY))
+                .unwrap(),
+            Some(Value::Float64(f64::MIN))
+        );
+        assert!(SqlDialect::MySql
+            .get_column_value(&not_null, Value::Float64(f64::NAN))
+            .is_err());
+        assert_eq!(
+            SqlDialect::Sqlite
+                .get_column_value(&not_null, Value::Float64(f64::INFINITY))
+                .unwrap(),
+            Some(Value::Float64(f64::INFINITY))
+        );
+        assert_eq!(
+            SqlDialect::Sqlite
+                .get_column_value(&nullable, Value::Float64(f64::NAN))
+                .unwrap(),
+            None
+        );
+        assert!(SqlDialect::Sqlite
+            .get_column_value(&not_null, Value::Float64(f64::NAN))
+            .is_err());
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(0, None, None);
+        let output = OutputSql::new(SqlDialect::PostgreSql, None);
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = get_config(1, None, None);
+        let output = OutputSql::new(SqlDialect::PostgreSql, None);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_batch_inserts() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_sql_batch".to_string()),
+            Some(2500),
+        );
+        let output = OutputSql::new(SqlDialect::Sqlite, Some("ids".to_string()));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let content =
+            std::fs::read_to_string("target/test_generated/output_sql_batch.sql").unwrap();
+        assert!(content.starts_with("CREATE TABLE \"ids\" (\n    \"id\" INTEGER NOT NULL\n);\n"));
+        assert_eq!(
+            content
+                .matches("INSERT INTO \"ids\" (\"id\") VALUES\n")
+                .count(),
+            3
+        );
+        assert_eq!(content.matches(";\n").count(), 4);
+        assert!(content.ends_with("(2499);\n"));
+    }
+
+    #[test]
+    fn given_small_config_should_write_expected_file() {
+        let config = get_config(
+            1,
+            Some("target/test_generated/output_sql_small".to_string()),
+            Some(3),
+        );
+        let output = OutputSql::new(SqlDialect::PostgreSql, Some("ids".to_string()));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "CREATE TABLE \"ids\" (\n    \"id\" INTEGER NOT NULL\n);\nINSERT INTO \"ids\" (\"id\") VALUES\n(0),\n(1),\n(2);\n",
+            std::fs::read_to_string("target/test_generated/output_sql_small.sql").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_file() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "bool".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "float".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%Y-%m-%d".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "timestamp".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_sql_all".to_string()),
+                output_format: Some(OutputType::Sql(SqlDialect::MySql, None)),
+                rows: Some(1000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        for dialect in [
+            SqlDialect::PostgreSql,
+            SqlDialect::MySql,
+            SqlDialect::Sqlite,
+        ] {
+            let output = OutputSql::new(dialect, None);
+            match output.generate_from_config(&config) {
+                Ok(_) => (),
+                Err(_) => panic!("Error"),
+            }
+        }
+    }
+}
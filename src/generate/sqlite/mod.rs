@@ -0,0 +1,400 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_format::OutputFormat;
+use crate::generate::sql::{OutputSql, SqlDialect, SQL_DATE_FORMAT, SQL_TIMESTAMP_FORMAT};
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+
+use rusqlite::types::Value as SqliteValue;
+use rusqlite::{params_from_iter, Connection};
+use std::path::Path;
+
+const SQLITE_EXTENSION: &str = ".sqlite";
+const ROWS_PER_TRANSACTION: u32 = 10_000;
+
+#[derive(Debug, PartialEq)]
+pub struct OutputSqlite {
+    table_name: Option<String>,
+}
+
+impl OutputSqlite {
+    pub fn new(table_name: Option<String>) -> OutputSqlite {
+        OutputSqlite { table_name }
+    }
+
+    fn sqlite_value(value: Value) -> SqliteValue {
+        match value {
+            Value::Bool(value) => SqliteValue::Integer(value as i64),
+            Value::Int32(value) => SqliteValue::Integer(value as i64),
+            Value::Int64(value) => SqliteValue::Integer(value),
+            Value::Decimal(value, _, scale) => SqliteValue::Text(format_decimal(value, scale)),
+            Value::Float64(value) => SqliteValue::Real(value),
+            Value::String(value) => SqliteValue::Text(value),
+            Value::Date(value, _) => SqliteValue::Text(value.format(SQL_DATE_FORMAT).to_string()),
+            Value::Timestamp(value, _) => {
+                SqliteValue::Text(value.format(SQL_TIMESTAMP_FORMAT).to_string())
+            }
+        }
+    }
+
+    fn write_database(&self, config: &Config, file_name: &str) -> Result<(), FakeLakeError> {
+        // the table is the same as the one of the sql output
+        let sql = OutputSql::new(SqlDialect::Sqlite, self.table_name.clone());
+        let table_name = sql.get_table_name(config);
+
+        let mut connection = Connection::open(file_name)?;
+        connection.execute_batch(&sql.get_create_table(&table_name, &config.columns))?;
+
+        let placeholders = vec!["?"; config.columns.len()].join(", ");
+        let names: Vec<String> = config
+            .columns
+            .iter()
+            .map(|column| SqlDialect::Sqlite.quote_identifier(&column.name))
+            .collect();
+        let insert_into = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            SqlDialect::Sqlite.quote_identifier(&table_name),
+            names.join(", "),
+            placeholders
+        );
+
+        let rows = config.get_number_of_rows();
+        let mut first_row = 0;
+        while first_row < rows {
+            let last_row = rows.min(first_row.saturating_add(ROWS_PER_TRANSACTION));
+            let transaction = connection.transaction()?;
+            {
+                let mut statement = transaction.prepare_cached(&insert_into)?;
+                for i in first_row..last_row {
+                    let mut row = Vec::with_capacity(config.columns.len());
+                    for column in &config.columns {
+                        // floats SQLite can't store are handled as with the sql output
+                        let value = match column.is_next_present() {
+                            true => SqlDialect::Sqlite
+                                .get_column_value(column, column.provider.value(i))?,
+                            false => None,
+                        };
+                        row.push(match value {
+                            Some(value) => OutputSqlite::sqlite_value(value),
+                            None => SqliteValue::Null,
+                        });
+                    }
+                    statement.execute(params_from_iter(row))?;
+                }
+            }
+            transaction.commit()?;
+            first_row = last_row;
+        }
+
+        match connection.close() {
+            Ok(_) => Ok(()),
+            Err((_, e)) => Err(FakeLakeError::SqliteError(e)),
+        }
+    }
+}
+
+impl OutputFormat for OutputSqlite {
+    fn get_extension(&self) -> &str {
+        SQLITE_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+        if config.is_stdout() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "SQLite databases can't be written to standard output".to_string(),
+            ));
+        }
+
+        // the database is created again instead of adding rows to an existing one
+        let file_name = config.get_output_file_name(self.get_extension());
+        if Path::new(&file_name).exists() {
+            std::fs::remove_file(&file_name)?;
+        }
+
+        self.write_database(config, &file_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_config(nb_columns: u8, name: Option<String>, rows: Option<u32>) -> Config {
+        let mut columns = vec![];
+
+        for index in 0..nb_columns {
+            columns.push(Column {
+                name: format!("id{}", index),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            });
+        }
+
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Sqlite(None)),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputSqlite::new(None);
+        assert_eq!(output.get_extension(), ".sqlite");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(0, None, None);
+        let output = OutputSqlite::new(None);
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_stdout_should_error() {
+        let config = get_config(1, Some("-".to_string()), Some(1));
+        let output = OutputSqlite::new(None);
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_should_insert_rows_in_table_named_after_file() {
+        let config = get_config(
+            2,
+            Some("target/test_generated/output_sqlite_rows".to_string()),
+            Some(25_001),
+        );
+        let output = OutputSqlite::new(None);
+        for _ in 0..2 {
+            // generating twice replaces the database
+            match output.generate_from_config(&config) {
+                Ok(_) => (),
+                Err(_) => panic!("Error"),
+            }
+        }
+
+        let connection =
+            Connection::open("target/test_generated/output_sqlite_rows.sqlite").unwrap();
+        let (count, sum): (i64, i64) = connection
+            .query_row(
+                "SELECT COUNT(*), SUM(id1) FROM output_sqlite_rows",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(count, 25_001);
+        assert_eq!(sum, 25_000 * 25_001 / 2);
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_table() {
+        let columns = vec![
+            Column {
+                name: "id".to_string(),
+                provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 0.5").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "bool".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "float".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%d/%m/%Y".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "timestamp".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let config = Config {
+            columns,
+            info: Some(Info {
+                output_name: Some("target/test_generated/output_sqlite_all".to_string()),
+                output_format: Some(OutputType::Sqlite(Some("users".to_string()))),
+                rows: Some(1000),
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        };
+
+        let output = OutputSqlite::new(Some("users".to_string()));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let connection =
+            Connection::open("target/test_generated/output_sqlite_all.sqlite").unwrap();
+        let mut statement = connection
+            .prepare("SELECT name, type, \"notnull\" FROM pragma_table_info('users')")
+            .unwrap();
+        let columns: Vec<(String, String, bool)> = statement
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+            .unwrap()
+            .map(|column| column.unwrap())
+            .collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("id".to_string(), "INTEGER".to_string(), false),
+                ("bool".to_string(), "INTEGER".to_string(), true),
+                ("float".to_string(), "REAL".to_string(), true),
+                ("string".to_string(), "TEXT".to_string(), true),
+                ("date".to_string(), "TEXT".to_string(), true),
+                ("timestamp".to_string(), "TEXT".to_string(), true),
+            ]
+        );
+
+        let (nulls, dates): (i64, i64) = connection
+            .query_row(
+                "SELECT COUNT(*) - COUNT(id), COUNT(date(date)) FROM users",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert!(nulls > 0);
+        // dates are stored in ISO format to be used by SQLite date functions
+        assert_eq!(dates, 1000);
+    }
+
+    /// NaN for odd rows
+    #[derive(Clone)]
+    struct NanProvider;
+
+    impl crate::providers::provider::Provider for NanProvider {
+        fn value(&self, index: u32) -> Value {
+            match index % 2 {
+                0 => Value::Float64(1.5),
+                _ => Value::Float64(f64::NAN),
+            }
+        }
+        fn corrupted_value(&self, index: u32) -> Value {
+            self.value(index)
+        }
+    }
+
+    #[test]
+    fn given_nan_should_write_null_or_error_by_presence() {
+        let mut config = get_config(
+            1,
+            Some("target/test_generated/output_sqlite_nan".to_string()),
+            Some(100),
+        );
+        config.columns.push(Column {
+            name: "score".to_string(),
+            provider: Box::new(NanProvider),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 0.99").unwrap()[0],
+            ),
+            path: None,
+        });
+        let output = OutputSqlite::new(None);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let connection =
+            Connection::open("target/test_generated/output_sqlite_nan.sqlite").unwrap();
+        let (count, nulls, odd_values): (i64, i64, i64) = connection
+            .query_row(
+                "SELECT COUNT(*), COUNT(*) - COUNT(score), COUNT(CASE WHEN id0 % 2 = 1 THEN score END) FROM output_sqlite_nan",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(count, 100);
+        assert!(nulls >= 50);
+        assert_eq!(odd_values, 0);
+
+        // NaN can't be written in a NOT NULL column
+        config.columns[1].presence = presence::new_from_yaml(
+            &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+        );
+        match output.generate_from_config(&config) {
+            Err(FakeLakeError::BadYAMLFormat(_)) => (),
+            _ => panic!("Should fail"),
+        }
+    }
+}
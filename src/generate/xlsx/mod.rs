@@ -0,0 +1,341 @@
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use std::io::Write;
+
+const XLSX_EXTENSION: &str = ".xlsx";
+/// Rows of a sheet, the first one being the header
+const XLSX_MAX_ROWS: u32 = 1_048_576;
+/// Significant digits kept by Excel numbers
+const XLSX_MAX_DIGITS: u8 = 15;
+const XLSX_MAX_EXACT_INTEGER: i64 = 10_i64.pow(XLSX_MAX_DIGITS as u32) - 1;
+
+const XLSX_DATE_FORMAT: &str = "yyyy-mm-dd";
+const XLSX_TIMESTAMP_FORMAT: &str = "yyyy-mm-dd hh:mm:ss";
+
+/// Number format showing every digit of the scale
+fn get_decimal_format(scale: u8) -> String {
+    match scale {
+        0 => "0".to_string(),
+        scale => format!("0.{}", "0".repeat(scale as usize)),
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OutputXlsx {
+    sheet_name: Option<String>,
+}
+
+impl OutputXlsx {
+    pub fn new(sheet_name: Option<String>) -> OutputXlsx {
+        OutputXlsx { sheet_name }
+    }
+
+    fn write_workbook(&self, config: &Config) -> Result<Vec<u8>, XlsxError> {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        if let Some(sheet_name) = &self.sheet_name {
+            worksheet.set_name(sheet_name)?;
+        }
+
+        let header_format = Format::new().set_bold();
+        let date_format = Format::new().set_num_format(XLSX_DATE_FORMAT);
+        let timestamp_format = Format::new().set_num_format(XLSX_TIMESTAMP_FORMAT);
+
+        for (col, column) in config.columns.iter().enumerate() {
+            worksheet.write_string_with_format(0, col as u16, &column.name, &header_format)?;
+        }
+
+        for i in 0..config.get_number_of_rows() {
+            let row = i + 1;
+            for (col, column) in config.columns.iter().enumerate() {
+                let col = col as u16;
+                if !column.is_next_present() {
+                    continue;
+                }
+                match column.provider.value(i) {
+                    Value::Bool(value) => worksheet.write_boolean(row, col, value)?,
+                    Value::Int32(value) => worksheet.write_number(row, col, value)?,
+                    Value::Int64(value) if value.abs() <= XLSX_MAX_EXACT_INTEGER => {
+                        worksheet.write_number(row, col, value as f64)?
+                    }
+                    // written as text instead of losing the last digits
+                    Value::Int64(value) => worksheet.write_string(row, col, value.to_string())?,
+                    Value::Decimal(value, precision, scale) if precision <= XLSX_MAX_DIGITS => {
+                        let format = Format::new().set_num_format(get_decimal_format(scale));
+                        let value = format_decimal(value, scale).parse::<f64>().unwrap();
+                        worksheet.write_number_with_format(row, col, value, &format)?
+                    }
+                    Value::Decimal(value, _, scale) => {
+                        worksheet.write_string(row, col, format_decimal(value, scale))?
+                    }
+                    Value::Float64(value) if value.is_finite() => {
+                        worksheet.write_number(row, col, value)?
+                    }
+                    // Excel has no representation of infinity and NaN
+                    Value::Float64(value) => worksheet.write_string(row, col, value.to_string())?,
+                    Value::String(value) => worksheet.write_string(row, col, value)?,
+                    Value::Date(value, _) => {
+                        worksheet.write_datetime_with_format(row, col, value, &date_format)?
+                    }
+                    Value::Timestamp(value, _) => worksheet.write_datetime_with_format(
+                        row,
+                        col,
+                        value.naive_utc(),
+                        &timestamp_format,
+                    )?,
+                };
+            }
+        }
+
+        workbook.save_to_buffer()
+    }
+}
+
+impl OutputFormat for OutputXlsx {
+    fn get_extension(&self) -> &str {
+        XLSX_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+        if config.get_number_of_rows() >= XLSX_MAX_ROWS {
+            return Err(FakeLakeError::BadYAMLFormat(format!(
+                "An Excel sheet can't have more than {} rows",
+                XLSX_MAX_ROWS - 1
+            )));
+        }
+
+        // the workbook is zipped once all the rows are written
+        let content = match self.write_workbook(config) {
+            Ok(content) => content,
+            Err(e) => return Err(FakeLakeError::XlsxError(e)),
+        };
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let mut file = OutputStream::create(file_name)?;
+        file.write_all(&content)?;
+        file.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use calamine::{open_workbook, Data, Reader, Xlsx};
+    use yaml_rust::YamlLoader;
+
+    fn get_column(name: &str, provider: Box<dyn crate::providers::provider::Provider>) -> Column {
+        Column {
+            name: name.to_string(),
+            provider,
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: {}\npresence: 1", name)).unwrap()[0],
+            ),
+            path: None,
+        }
+    }
+
+    fn get_config(columns: Vec<Column>, name: Option<String>, rows: Option<u32>) -> Config {
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Xlsx(None)),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputXlsx::new(None);
+        assert_eq!(output.get_extension(), ".xlsx");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(vec![], None, None);
+        let output = OutputXlsx::new(None);
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_too_many_rows_should_error() {
+        let config = get_config(
+            vec![get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            )],
+            None,
+            Some(XLSX_MAX_ROWS),
+        );
+        let output = OutputXlsx::new(None);
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_invalid_sheet_name_should_error() {
+        let config = get_config(
+            vec![get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            )],
+            Some("target/test_generated/output_xlsx_invalid".to_string()),
+            Some(1),
+        );
+        let output = OutputXlsx::new(Some("users[1]".to_string()));
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = Config {
+            columns: vec![get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            )],
+            info: None,
+        };
+        let output = OutputXlsx::new(None);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_typed_cells() {
+        let columns = vec![
+            get_column(
+                "id",
+                Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            ),
+            get_column("bool", Box::new(BoolProvider {})),
+            get_column(
+                "float",
+                Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+            ),
+            get_column(
+                "string",
+                Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+            ),
+            get_column(
+                "date",
+                Box::new(DateProvider {
+                    format: "%d/%m/%Y".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+            ),
+            get_column(
+                "timestamp",
+                Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+            ),
+        ];
+        let config = get_config(
+            columns,
+            Some("target/test_generated/output_xlsx_all".to_string()),
+            Some(100),
+        );
+        let output = OutputXlsx::new(Some("users".to_string()));
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let mut workbook: Xlsx<_> =
+            open_workbook("target/test_generated/output_xlsx_all.xlsx").unwrap();
+        let range = workbook.worksheet_range("users").unwrap();
+        assert_eq!(range.height(), 101);
+        assert_eq!(range.get((0, 0)), Some(&Data::String("id".to_string())));
+        assert_eq!(
+            range.get((0, 5)),
+            Some(&Data::String("timestamp".to_string()))
+        );
+        assert_eq!(range.get((1, 0)), Some(&Data::Float(0.0)));
+        assert_eq!(range.get((100, 0)), Some(&Data::Float(99.0)));
+        assert!(matches!(range.get((1, 1)), Some(Data::Bool(_))));
+        assert!(matches!(range.get((1, 2)), Some(Data::Float(_))));
+        assert!(matches!(range.get((1, 3)), Some(Data::String(_))));
+        assert!(matches!(range.get((1, 4)), Some(Data::DateTime(_))));
+        assert!(matches!(range.get((1, 5)), Some(Data::DateTime(_))));
+    }
+
+    #[test]
+    fn given_null_values_should_write_empty_cells() {
+        let column = Column {
+            name: "id".to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str("name: id\npresence: 0").unwrap()[0],
+            ),
+            path: None,
+        };
+        let config = get_config(
+            vec![
+                column,
+                get_column(
+                    "other",
+                    Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+                ),
+            ],
+            Some("target/test_generated/output_xlsx_null".to_string()),
+            Some(2),
+        );
+        let output = OutputXlsx::new(None);
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let mut workbook: Xlsx<_> =
+            open_workbook("target/test_generated/output_xlsx_null.xlsx").unwrap();
+        let range = workbook.worksheet_range("Sheet1").unwrap();
+        assert_eq!(range.get((1, 0)), Some(&Data::Empty));
+        assert_eq!(range.get((2, 1)), Some(&Data::Float(1.0)));
+    }
+}
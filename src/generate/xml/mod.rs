@@ -0,0 +1,441 @@
+pub mod options;
+
+use crate::config::Config;
+use crate::errors::FakeLakeError;
+use crate::generate::output_files::OutputStream;
+use crate::generate::output_format::OutputFormat;
+use crate::providers::provider::Value;
+use crate::providers::utils::decimal::format_decimal;
+use options::XmlOptions;
+
+use log::warn;
+use std::io::{BufWriter, Write};
+
+const XML_EXTENSION: &str = ".xml";
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n";
+
+/// Element and attribute names, without the characters only allowed outside of ASCII
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' || c == ':' => (),
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'))
+        && !name.to_lowercase().starts_with("xml")
+}
+
+/// Escapes markup characters, chars not allowed in XML 1.0 are replaced
+fn escape(value: &str, is_attribute: bool) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' if is_attribute => escaped.push_str("&quot;"),
+            // attribute values would be normalized as spaces by parsers
+            '\t' if is_attribute => escaped.push_str("&#9;"),
+            '\n' if is_attribute => escaped.push_str("&#10;"),
+            '\r' => escaped.push_str("&#13;"),
+            '\t' | '\n' => escaped.push(c),
+            c if (c as u32) < 0x20 || c == '\u{FFFE}' || c == '\u{FFFF}' => {
+                escaped.push(char::REPLACEMENT_CHARACTER)
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[derive(Debug, PartialEq)]
+pub struct OutputXml {
+    options: XmlOptions,
+}
+
+impl OutputXml {
+    pub fn new(options: XmlOptions) -> OutputXml {
+        OutputXml { options }
+    }
+
+    fn check_names(&self, config: &Config) -> Result<(), FakeLakeError> {
+        let mut names = vec![&self.options.root_element, &self.options.row_element];
+        names.extend(config.columns.iter().map(|column| &column.name));
+        for name in names {
+            if !is_xml_name(name) {
+                return Err(FakeLakeError::BadYAMLFormat(format!(
+                    "{} is not a valid XML element or attribute name",
+                    name
+                )));
+            }
+        }
+
+        for attribute in &self.options.attributes {
+            match config
+                .columns
+                .iter()
+                .filter(|column| &column.name == attribute)
+                .count()
+            {
+                0 => warn!(
+                    "XML attribute {} is not a column. It is ignored.",
+                    attribute
+                ),
+                1 => (),
+                _ => {
+                    return Err(FakeLakeError::BadYAMLFormat(format!(
+                        "XML attribute {} is given by multiple columns",
+                        attribute
+                    )))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl OutputFormat for OutputXml {
+    fn get_extension(&self) -> &str {
+        XML_EXTENSION
+    }
+
+    fn generate_from_config(&self, config: &Config) -> Result<(), FakeLakeError> {
+        if config.columns.is_empty() {
+            return Err(FakeLakeError::BadYAMLFormat(
+                "No columns to generate".to_string(),
+            ));
+        }
+        self.check_names(config)?;
+
+        let file_name = config.get_output_file_name(self.get_extension());
+        let mut buffer = BufWriter::new(OutputStream::create(file_name)?);
+        let rows = config.get_number_of_rows();
+
+        let is_attribute: Vec<bool> = config
+            .columns
+            .iter()
+            .map(|column| self.options.attributes.contains(&column.name))
+            .collect();
+
+        buffer.write_all(XML_DECLARATION.as_bytes())?;
+        writeln!(buffer, "<{}>", self.options.root_element)?;
+
+        for i in 0..rows {
+            let mut values: Vec<Option<String>> = Vec::with_capacity(config.columns.len());
+            for column in &config.columns {
+                let mut str_value = None;
+                if column.is_next_present() {
+                    str_value = Some(match column.provider.value(i) {
+                        Value::Bool(value) => value.to_string(),
+                        Value::Int32(value) => value.to_string(),
+                        Value::Int64(value) => value.to_string(),
+                        Value::Decimal(value, _, scale) => format_decimal(value, scale),
+                        Value::Float64(value) => value.to_string(),
+                        Value::String(value) => value,
+                        Value::Date(value, date_format) => value.format(&date_format).to_string(),
+                        Value::Timestamp(value, date_format) => {
+                            value.format(&date_format).to_string()
+                        }
+                    });
+                }
+                values.push(str_value);
+            }
+
+            write!(buffer, "  <{}", self.options.row_element)?;
+            for (index, value) in values.iter().enumerate() {
+                if let (true, Some(value)) = (is_attribute[index], value) {
+                    write!(
+                        buffer,
+                        " {}=\"{}\"",
+                        config.columns[index].name,
+                        escape(value, true)
+                    )?;
+                }
+            }
+
+            let mut has_elements = false;
+            for (index, value) in values.iter().enumerate() {
+                if let (false, Some(value)) = (is_attribute[index], value) {
+                    if !has_elements {
+                        buffer.write_all(b">\n")?;
+                        has_elements = true;
+                    }
+                    let name = &config.columns[index].name;
+                    writeln!(buffer, "    <{}>{}</{}>", name, escape(value, false), name)?;
+                }
+            }
+            match has_elements {
+                true => writeln!(buffer, "  </{}>", self.options.row_element)?,
+                false => buffer.write_all(b"/>\n")?,
+            }
+        }
+
+        writeln!(buffer, "</{}>", self.options.root_element)?;
+        buffer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Column, Config, Info, OutputType};
+    use crate::options::presence;
+    use crate::providers::increment::integer::IncrementIntegerProvider;
+    use crate::providers::random::bool::BoolProvider;
+    use crate::providers::random::date::date::DateProvider;
+    use crate::providers::random::date::datetime::DatetimeProvider;
+    use crate::providers::random::number::f64::F64Provider;
+    use crate::providers::random::string::alphanumeric::AlphanumericProvider;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_column(name: &str, presence: f64) -> Column {
+        Column {
+            name: name.to_string(),
+            provider: Box::new(IncrementIntegerProvider { start: 0, step: 1 }),
+            presence: presence::new_from_yaml(
+                &YamlLoader::load_from_str(&format!("name: {}\npresence: {}", name, presence))
+                    .unwrap()[0],
+            ),
+            path: None,
+        }
+    }
+
+    fn get_config(columns: Vec<Column>, name: Option<String>, rows: Option<u32>) -> Config {
+        Config {
+            columns,
+            info: Some(Info {
+                output_name: name,
+                output_format: Some(OutputType::Xml(XmlOptions::default())),
+                rows,
+                seed: None,
+                partition_by: None,
+                max_rows_per_file: None,
+                max_bytes_per_file: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn given_get_extension() {
+        let output = OutputXml::new(XmlOptions::default());
+        assert_eq!(output.get_extension(), ".xml");
+    }
+
+    #[test]
+    fn given_names_should_check_xml_names() {
+        assert!(is_xml_name("id"));
+        assert!(is_xml_name("_user-name.first"));
+        assert!(is_xml_name("ns:id"));
+        assert!(!is_xml_name(""));
+        assert!(!is_xml_name("1id"));
+        assert!(!is_xml_name("user name"));
+        assert!(!is_xml_name("xml_id"));
+    }
+
+    #[test]
+    fn given_values_should_be_escaped() {
+        assert_eq!(escape("a<b>&\"c\"", false), "a&lt;b&gt;&amp;\"c\"");
+        assert_eq!(escape("a<b>&\"c\"", true), "a&lt;b&gt;&amp;&quot;c&quot;");
+        assert_eq!(escape("a\tb\nc\r", false), "a\tb\nc&#13;");
+        assert_eq!(escape("a\tb\nc", true), "a&#9;b&#10;c");
+        assert_eq!(escape("a\u{0}b", false), "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn given_config_without_columns_should_error() {
+        let config = get_config(vec![], None, None);
+        let output = OutputXml::new(XmlOptions::default());
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_invalid_names_should_error() {
+        let output = OutputXml::new(XmlOptions::default());
+        let config = get_config(vec![get_column("user id", 1.0)], None, Some(1));
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+
+        let output = OutputXml::new(XmlOptions {
+            row_element: "1row".to_string(),
+            ..Default::default()
+        });
+        let config = get_config(vec![get_column("id", 1.0)], None, Some(1));
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_attribute_of_multiple_columns_should_error() {
+        let config = get_config(
+            vec![get_column("id", 1.0), get_column("id", 1.0)],
+            None,
+            Some(1),
+        );
+        let output = OutputXml::new(XmlOptions {
+            attributes: vec!["id".to_string()],
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Err(_) => (),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn given_config_without_info_should_write_file() {
+        let config = Config {
+            columns: vec![get_column("id", 1.0)],
+            info: None,
+        };
+        let output = OutputXml::new(XmlOptions::default());
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+    }
+
+    #[test]
+    fn given_options_should_write_expected_file() {
+        let config = get_config(
+            vec![
+                get_column("id", 1.0),
+                get_column("code", 1.0),
+                get_column("value", 0.0),
+            ],
+            Some("target/test_generated/output_xml_small".to_string()),
+            Some(2),
+        );
+        let output = OutputXml::new(XmlOptions {
+            root_element: "users".to_string(),
+            row_element: "user".to_string(),
+            attributes: vec!["id".to_string(), "unknown".to_string()],
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<users>\n  <user id=\"0\">\n    <code>0</code>\n  </user>\n  <user id=\"1\">\n    <code>1</code>\n  </user>\n</users>\n",
+            std::fs::read_to_string("target/test_generated/output_xml_small.xml").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_only_attributes_should_write_empty_elements() {
+        let config = get_config(
+            vec![get_column("id", 1.0)],
+            Some("target/test_generated/output_xml_attributes".to_string()),
+            Some(1),
+        );
+        let output = OutputXml::new(XmlOptions {
+            attributes: vec!["id".to_string()],
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        assert_eq!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rows>\n  <row id=\"0\"/>\n</rows>\n",
+            std::fs::read_to_string("target/test_generated/output_xml_attributes.xml").unwrap()
+        );
+    }
+
+    #[test]
+    fn given_all_providers_values_should_write_file() {
+        let columns = vec![
+            get_column("id", 0.5),
+            Column {
+                name: "bool".to_string(),
+                provider: Box::new(BoolProvider {}),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "float".to_string(),
+                provider: Box::new(F64Provider {
+                    min: 0.0,
+                    max: 1.1,
+                    distribution: None,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "string".to_string(),
+                provider: Box::new(AlphanumericProvider {
+                    min_length: 10,
+                    max_length: 11,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "date".to_string(),
+                provider: Box::new(DateProvider {
+                    format: "%d/%m/%Y".to_string(),
+                    after: 0,
+                    before: 10000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+            Column {
+                name: "timestamp".to_string(),
+                provider: Box::new(DatetimeProvider {
+                    format: "%Y-%m-%d %H:%M:%S".to_string(),
+                    after: 10_000_000,
+                    before: 12_000_000,
+                }),
+                presence: presence::new_from_yaml(
+                    &YamlLoader::load_from_str("name: id\npresence: 1").unwrap()[0],
+                ),
+                path: None,
+            },
+        ];
+
+        let config = get_config(
+            columns,
+            Some("target/test_generated/output_xml_all".to_string()),
+            Some(1000),
+        );
+        let output = OutputXml::new(XmlOptions {
+            attributes: vec!["id".to_string(), "date".to_string()],
+            ..Default::default()
+        });
+        match output.generate_from_config(&config) {
+            Ok(_) => (),
+            Err(_) => panic!("Error"),
+        }
+
+        let content = std::fs::read_to_string("target/test_generated/output_xml_all.xml").unwrap();
+        assert_eq!(content.matches("\n  <row").count(), 1000);
+        assert_eq!(content.matches("<timestamp>").count(), 1000);
+        // dates are written with their format
+        let dates: Vec<&str> = content.split(" date=\"").skip(1).collect();
+        assert_eq!(dates.len(), 1000);
+        for date in dates {
+            assert_eq!(&date[2..3], "/");
+            assert_eq!(&date[5..6], "/");
+        }
+    }
+}
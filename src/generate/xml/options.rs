@@ -0,0 +1,112 @@
+use log::warn;
+use yaml_rust::Yaml;
+
+const DEFAULT_ROOT_ELEMENT: &str = "rows";
+const DEFAULT_ROW_ELEMENT: &str = "row";
+
+/// XML settings of the info section
+#[derive(Debug, Clone, PartialEq)]
+pub struct XmlOptions {
+    /// Element wrapping all the rows
+    pub root_element: String,
+    /// Element of each row
+    pub row_element: String,
+    /// Columns written as attributes of the row element instead of child elements
+    pub attributes: Vec<String>,
+}
+
+impl Default for XmlOptions {
+    fn default() -> Self {
+        XmlOptions {
+            root_element: DEFAULT_ROOT_ELEMENT.to_string(),
+            row_element: DEFAULT_ROW_ELEMENT.to_string(),
+            attributes: vec![],
+        }
+    }
+}
+
+impl XmlOptions {
+    pub fn new_from_yaml(section_info: &Yaml) -> XmlOptions {
+        let mut options = XmlOptions::default();
+
+        if let Some(root_element) = parse_element(section_info, "root_element") {
+            options.root_element = root_element;
+        }
+        if let Some(row_element) = parse_element(section_info, "row_element") {
+            options.row_element = row_element;
+        }
+
+        // attributes could be a list of column names or a single column name
+        match &section_info["attributes"] {
+            Yaml::Array(values) => {
+                for value in values {
+                    match value.as_str() {
+                        Some(column) => options.attributes.push(column.to_string()),
+                        None => warn!(
+                            "XML attributes should only contain column names. {:?} is ignored.",
+                            value
+                        ),
+                    }
+                }
+            }
+            Yaml::String(column) => options.attributes.push(column.to_string()),
+            Yaml::BadValue => (),
+            _ => warn!("XML attributes should be a list of column names. They are ignored."),
+        }
+
+        options
+    }
+}
+
+fn parse_element(section_info: &Yaml, name: &str) -> Option<String> {
+    match &section_info[name] {
+        Yaml::String(element) => Some(element.to_string()),
+        Yaml::BadValue => None,
+        _ => {
+            warn!("XML {} should be a string. Default value is taken.", name);
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use yaml_rust::YamlLoader;
+
+    fn get_options(yaml: &str) -> XmlOptions {
+        let parsed_yaml = &YamlLoader::load_from_str(yaml).unwrap()[0];
+        XmlOptions::new_from_yaml(parsed_yaml)
+    }
+
+    #[test]
+    fn given_no_settings_should_return_default() {
+        assert_eq!(get_options("output_format: xml"), XmlOptions::default());
+    }
+
+    #[test]
+    fn given_settings_should_return_them() {
+        let options = get_options("root_element: users\nrow_element: user\nattributes: [id, type]");
+        assert_eq!(
+            options,
+            XmlOptions {
+                root_element: "users".to_string(),
+                row_element: "user".to_string(),
+                attributes: vec!["id".to_string(), "type".to_string()],
+            }
+        );
+
+        let options = get_options("attributes: id");
+        assert_eq!(options.attributes, vec!["id".to_string()]);
+    }
+
+    #[test]
+    fn given_invalid_settings_should_return_default() {
+        let options = get_options("root_element: [a]\nrow_element: 1\nattributes: 1");
+        assert_eq!(options, XmlOptions::default());
+
+        let options = get_options("attributes: [id, 1]");
+        assert_eq!(options.attributes, vec!["id".to_string()]);
+    }
+}
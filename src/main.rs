@@ -25,7 +25,8 @@ fn main() {
     match cli.command {
         Commands::Generate {
             path_to_config: paths_to_config,
-        } => match generate_from_paths(paths_to_config) {
+            stdout,
+        } => match generate_from_paths(paths_to_config, stdout) {
             Ok(_) => (),
             Err(e) => {
                 error!("Error: {:?}", e);
@@ -1,7 +1,7 @@
 use crate::errors::FakeLakeError;
 use crate::providers::provider::Provider;
 
-use super::integer;
+use super::{integer, long, uuid};
 
 use yaml_rust::Yaml;
 
@@ -11,6 +11,8 @@ pub fn get_corresponding_provider(
 ) -> Result<Box<dyn Provider>, FakeLakeError> {
     match provider_split.next() {
         Some("integer") => Ok(integer::new_from_yaml(column)),
+        Some("long") => Ok(long::new_from_yaml(column)),
+        Some("uuid") => Ok(uuid::new_from_yaml(column)),
         _ => Err(FakeLakeError::BadYAMLFormat("".to_string())),
     }
 }
@@ -34,6 +36,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_long_should_return_provider() {
+        let provider_name = "long";
+        let yaml_str = format!("name: id{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn given_uuid_should_return_provider() {
+        let provider_name = "uuid";
+        let yaml_str = format!("name: id{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
@@ -1,3 +1,5 @@
 pub mod builder;
 
 pub mod integer;
+pub mod long;
+pub mod uuid;
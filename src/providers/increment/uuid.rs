@@ -0,0 +1,223 @@
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::random::uuid::UUID_LENGTH;
+use crate::providers::utils::string::random_characters;
+
+use chrono::NaiveDateTime;
+use log::warn;
+use uuid::{Builder, Uuid};
+use yaml_rust::Yaml;
+
+const DEFAULT_VERSION: i64 = 7;
+const DEFAULT_START: &str = "2024-01-01 00:00:00";
+const START_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum UuidVersion {
+    /// Name based, the name being the row index in the namespace
+    V5(Uuid),
+    /// Time ordered, one millisecond after start per row
+    V7(i64),
+}
+
+#[derive(Clone)]
+pub struct IncrementUuidProvider {
+    pub version: UuidVersion,
+    /// Seed of the random bits of version 7, so that two columns do not get the same uuids
+    pub seed: u64,
+}
+
+impl Provider for IncrementUuidProvider {
+    fn value(&self, index: u32) -> Value {
+        let uuid = match self.version {
+            UuidVersion::V5(namespace) => Uuid::new_v5(&namespace, index.to_string().as_bytes()),
+            UuidVersion::V7(start) => {
+                // random bits are derived from the column seed and the index so the same rows give
+                // the same uuids
+                let mut random_bytes = [0_u8; 10];
+                fastrand::Rng::with_seed(
+                    self.seed ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15),
+                )
+                .fill(&mut random_bytes);
+                Builder::from_unix_timestamp_millis((start + index as i64) as u64, &random_bytes)
+                    .into_uuid()
+            }
+        };
+        Value::String(uuid.to_string())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(random_characters(UUID_LENGTH))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<IncrementUuidProvider> {
+    let column_name = get_column_name(column);
+
+    let version = match &column["version"] {
+        Yaml::Integer(value) if *value == 5 || *value == 7 => *value,
+        Yaml::BadValue => DEFAULT_VERSION,
+        _ => {
+            warn!(
+                "{} column: version should be 5 or 7. Default value '{}' is taken.",
+                column_name, DEFAULT_VERSION
+            );
+            DEFAULT_VERSION
+        }
+    };
+
+    let version = match version {
+        5 => {
+            let namespace = match &column["namespace"] {
+                Yaml::BadValue => Uuid::NAMESPACE_OID,
+                value => match value.as_str().and_then(|value| Uuid::parse_str(value).ok()) {
+                    Some(namespace) => namespace,
+                    None => {
+                        warn!(
+                            "{} column: namespace should be a uuid. Default OID namespace is taken.",
+                            column_name
+                        );
+                        Uuid::NAMESPACE_OID
+                    }
+                },
+            };
+            UuidVersion::V5(namespace)
+        }
+        _ => {
+            let default_start = NaiveDateTime::parse_from_str(DEFAULT_START, START_FORMAT).unwrap();
+            let start = match &column["start"] {
+                Yaml::BadValue => default_start,
+                value => match value
+                    .as_str()
+                    .and_then(|value| NaiveDateTime::parse_from_str(value, START_FORMAT).ok())
+                {
+                    Some(start) if start.and_utc().timestamp() >= 0 => start,
+                    _ => {
+                        warn!(
+                            "{} column: start should be a datetime from 1970 with format {}. Default value '{}' is taken.",
+                            column_name, START_FORMAT, DEFAULT_START
+                        );
+                        default_start
+                    }
+                },
+            };
+            UuidVersion::V7(start.and_utc().timestamp_millis())
+        }
+    };
+
+    Box::new(IncrementUuidProvider {
+        version,
+        seed: crate::rng::u64(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::provider::{Provider, Value};
+
+    use uuid::Version;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(params: &str) -> Box<IncrementUuidProvider> {
+        let yaml_str = format!("name: id{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        super::new_from_yaml(&yaml[0])
+    }
+
+    fn get_uuid(provider: &IncrementUuidProvider, index: u32) -> Uuid {
+        match provider.value(index) {
+            Value::String(value) => Uuid::parse_str(&value).unwrap(),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_string_type() {
+        let provider = generate_provider("");
+        match provider.value(0) {
+            Value::String(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_nothing_should_return_v7_from_default_start() {
+        let provider = generate_provider("");
+        let start = NaiveDateTime::parse_from_str(DEFAULT_START, START_FORMAT)
+            .unwrap()
+            .and_utc()
+            .timestamp_millis();
+        assert_eq!(provider.version, UuidVersion::V7(start));
+    }
+
+    #[test]
+    fn given_version_5_and_namespace_should_return_v5() {
+        let provider =
+            generate_provider("\nversion: 5\nnamespace: 6ba7b811-9dad-11d1-80b4-00c04fd430c8");
+        assert_eq!(provider.version, UuidVersion::V5(Uuid::NAMESPACE_URL));
+    }
+
+    #[test]
+    fn given_wrong_parameters_should_return_defaults() {
+        let provider = generate_provider("\nversion: 4");
+        assert!(matches!(provider.version, UuidVersion::V7(_)));
+        let provider = generate_provider("\nversion: 5\nnamespace: not_a_uuid");
+        assert_eq!(provider.version, UuidVersion::V5(Uuid::NAMESPACE_OID));
+        let provider = generate_provider("\nstart: 1960-01-01 00:00:00");
+        assert_eq!(generate_provider("").version, provider.version);
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_v5_should_return_name_based_uuids_of_index() {
+        let provider = generate_provider("\nversion: 5");
+        let uuid = get_uuid(&provider, 12);
+        assert_eq!(uuid.get_version(), Some(Version::Sha1));
+        assert_eq!(uuid, Uuid::new_v5(&Uuid::NAMESPACE_OID, b"12"));
+        assert_eq!(get_uuid(&provider, 12), uuid);
+        assert_ne!(get_uuid(&provider, 13), uuid);
+    }
+
+    #[test]
+    fn given_v7_should_return_ordered_uuids_from_start() {
+        let provider = generate_provider("\nstart: 2024-05-01 10:00:00");
+        let mut previous = get_uuid(&provider, 0);
+        assert_eq!(previous.get_version(), Some(Version::SortRand));
+        let (seconds, _) = previous.get_timestamp().unwrap().to_unix();
+        assert_eq!(
+            seconds as i64,
+            NaiveDateTime::parse_from_str("2024-05-01 10:00:00", START_FORMAT)
+                .unwrap()
+                .and_utc()
+                .timestamp()
+        );
+        for i in 1..1000 {
+            let uuid = get_uuid(&provider, i);
+            assert!(uuid > previous);
+            previous = uuid;
+        }
+        assert_eq!(get_uuid(&provider, 10), get_uuid(&provider, 10));
+    }
+
+    #[test]
+    fn given_two_v7_providers_should_return_different_uuids() {
+        let first = generate_provider("");
+        let second = generate_provider("");
+        for i in 0..100 {
+            assert_ne!(get_uuid(&first, i), get_uuid(&second, i));
+        }
+    }
+
+    #[test]
+    fn given_provider_should_corrupted_return_uuid_length_strings() {
+        let provider = generate_provider("");
+        for i in 0..100 {
+            match provider.corrupted_value(i) {
+                Value::String(value) => assert_eq!(value.chars().count(), UUID_LENGTH as usize),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
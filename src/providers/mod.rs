@@ -8,3 +8,4 @@ pub mod constant;
 pub mod increment;
 pub mod person;
 pub mod random;
+pub mod timeseries;
@@ -0,0 +1,217 @@
+use yaml_rust::Yaml;
+
+use log::warn;
+
+use super::get_column_name;
+use crate::providers::utils::distribution::{Distribution, Law};
+
+const DEFAULT_ZIPF_N: u64 = 1000;
+/// Shape of the 80/20 rule
+const DEFAULT_PARETO_SHAPE: f64 = 1.16;
+
+pub struct DistributionParameter {
+    /// None for the uniform distribution
+    pub value: Option<Distribution>,
+}
+
+impl DistributionParameter {
+    pub fn new(column: &Yaml) -> DistributionParameter {
+        let column_name = get_column_name(column);
+        let distribution = &column["distribution"];
+
+        let law_name = match distribution {
+            Yaml::BadValue => return DistributionParameter { value: None },
+            Yaml::String(value) => value.as_str(),
+            Yaml::Hash(_) => distribution["type"].as_str().unwrap_or_default(),
+            _ => "",
+        };
+
+        let param = |param_name: &str, default_value: f64| {
+            get_positive_param(column_name, distribution, param_name, default_value)
+        };
+        let law = match law_name.to_lowercase().as_str() {
+            "uniform" => return DistributionParameter { value: None },
+            "normal" => Law::Normal {
+                mean: get_param(column_name, distribution, "mean", 0.0),
+                stddev: param("stddev", 1.0),
+            },
+            "lognormal" => Law::LogNormal {
+                mean: get_param(column_name, distribution, "mean", 0.0),
+                stddev: param("stddev", 1.0),
+            },
+            "exponential" => Law::Exponential {
+                rate: param("rate", 1.0),
+            },
+            "poisson" => Law::Poisson {
+                lambda: param("lambda", 1.0),
+            },
+            "zipf" => Law::Zipf {
+                n: param("n", DEFAULT_ZIPF_N as f64).max(1.0) as u64,
+                exponent: param("exponent", 1.0),
+            },
+            "pareto" => Law::Pareto {
+                scale: param("scale", 1.0),
+                shape: param("shape", DEFAULT_PARETO_SHAPE),
+            },
+            "beta" => Law::Beta {
+                alpha: param("alpha", 2.0),
+                beta: param("beta", 2.0),
+            },
+            _ => {
+                warn!(
+                    "{} column: distribution type should be uniform, normal, lognormal, exponential, poisson, zipf, pareto or beta. Uniform distribution is taken.",
+                    column_name
+                );
+                return DistributionParameter { value: None };
+            }
+        };
+
+        let clamp = match &distribution["clamp"] {
+            Yaml::Boolean(value) => *value,
+            Yaml::BadValue => false,
+            _ => {
+                warn!(
+                    "{} column: distribution clamp should be a boolean. Default value 'false' is taken.",
+                    column_name
+                );
+                false
+            }
+        };
+
+        DistributionParameter {
+            value: Some(Distribution { law, clamp }),
+        }
+    }
+}
+
+fn get_param(column_name: &str, distribution: &Yaml, param_name: &str, default_value: f64) -> f64 {
+    match &distribution[param_name] {
+        Yaml::Real(value) => value.parse::<f64>().unwrap(),
+        Yaml::Integer(value) => *value as f64,
+        Yaml::BadValue => default_value,
+        _ => {
+            print_wrong_param(column_name, param_name, "a number", default_value);
+            default_value
+        }
+    }
+}
+
+fn get_positive_param(
+    column_name: &str,
+    distribution: &Yaml,
+    param_name: &str,
+    default_value: f64,
+) -> f64 {
+    match get_param(column_name, distribution, param_name, default_value) {
+        value if value > 0.0 && value.is_finite() => value,
+        _ => {
+            print_wrong_param(column_name, param_name, "a positive number", default_value);
+            default_value
+        }
+    }
+}
+
+fn print_wrong_param(column_name: &str, param_name: &str, expected: &str, default_value: f64) {
+    warn!(
+        "{} column: distribution {} should be {}. Default value '{}' is taken.",
+        column_name, param_name, expected, default_value
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust::YamlLoader;
+
+    use super::{DistributionParameter, DEFAULT_PARETO_SHAPE, DEFAULT_ZIPF_N};
+    use crate::providers::utils::distribution::{Distribution, Law};
+
+    fn generate_parameter(params: &str) -> DistributionParameter {
+        let yaml_str = format!("name: id{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        DistributionParameter::new(&yaml[0])
+    }
+
+    #[test]
+    fn given_no_distribution_should_return_uniform() {
+        assert_eq!(generate_parameter("").value, None);
+        assert_eq!(generate_parameter("\ndistribution: uniform").value, None);
+    }
+
+    #[test]
+    fn given_distribution_name_should_return_default_params() {
+        let parameter = generate_parameter("\ndistribution: zipf");
+        assert_eq!(
+            parameter.value,
+            Some(Distribution {
+                law: Law::Zipf {
+                    n: DEFAULT_ZIPF_N,
+                    exponent: 1.0
+                },
+                clamp: false
+            })
+        );
+
+        let parameter = generate_parameter("\ndistribution: Pareto");
+        assert_eq!(
+            parameter.value.unwrap().law,
+            Law::Pareto {
+                scale: 1.0,
+                shape: DEFAULT_PARETO_SHAPE
+            }
+        );
+    }
+
+    #[test]
+    fn given_distribution_params_should_use_them() {
+        let parameter = generate_parameter(
+            "\ndistribution:\n  type: normal\n  mean: -5\n  stddev: 2.5\n  clamp: true",
+        );
+        assert_eq!(
+            parameter.value,
+            Some(Distribution {
+                law: Law::Normal {
+                    mean: -5.0,
+                    stddev: 2.5
+                },
+                clamp: true
+            })
+        );
+
+        let parameter =
+            generate_parameter("\ndistribution:\n  type: beta\n  alpha: 0.5\n  beta: 3");
+        assert_eq!(
+            parameter.value.unwrap().law,
+            Law::Beta {
+                alpha: 0.5,
+                beta: 3.0
+            }
+        );
+    }
+
+    #[test]
+    fn given_wrong_params_should_use_defaults() {
+        let parameter = generate_parameter(
+            "\ndistribution:\n  type: lognormal\n  mean: abc\n  stddev: -1\n  clamp: 3",
+        );
+        assert_eq!(
+            parameter.value,
+            Some(Distribution {
+                law: Law::LogNormal {
+                    mean: 0.0,
+                    stddev: 1.0
+                },
+                clamp: false
+            })
+        );
+
+        let parameter = generate_parameter("\ndistribution:\n  type: poisson\n  lambda: 0");
+        assert_eq!(parameter.value.unwrap().law, Law::Poisson { lambda: 1.0 });
+    }
+
+    #[test]
+    fn given_unknown_distribution_should_return_uniform() {
+        assert_eq!(generate_parameter("\ndistribution: gaussian").value, None);
+        assert_eq!(generate_parameter("\ndistribution:\n  mean: 3").value, None);
+        assert_eq!(generate_parameter("\ndistribution: 3").value, None);
+    }
+}
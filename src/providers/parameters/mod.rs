@@ -1,10 +1,13 @@
 pub mod date;
 pub mod datetime;
+pub mod distribution;
 pub mod f64;
 pub mod file;
 pub mod i32;
+pub mod i64;
 pub mod percentage;
 pub mod string;
+pub mod timeline;
 pub mod urange;
 pub mod wstring;
 
@@ -0,0 +1,160 @@
+use chrono::NaiveDateTime;
+use yaml_rust::Yaml;
+
+use log::warn;
+
+use super::get_column_name;
+
+/// Regular timestamps of the rows, from start and one step apart
+pub struct TimelineParameter {
+    pub format: String,
+    /// Timestamp of the first row, in seconds
+    pub start: i64,
+    /// Seconds between two rows
+    pub step: i64,
+}
+
+/// Seconds of a step like 30, "30s", "5m", "1h" or "1d"
+fn parse_step(step: &Yaml) -> Option<i64> {
+    let seconds = match step {
+        Yaml::Integer(value) => Some(*value),
+        Yaml::String(value) => {
+            let (number, unit) = match value.char_indices().last() {
+                Some((position, unit)) if unit.is_alphabetic() => (&value[..position], unit),
+                _ => (value.as_str(), 's'),
+            };
+            let multiplier = match unit {
+                's' => 1,
+                'm' => 60,
+                'h' => 3_600,
+                'd' => 86_400,
+                _ => return None,
+            };
+            number
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .and_then(|number| number.checked_mul(multiplier))
+        }
+        _ => None,
+    };
+    seconds.filter(|seconds| *seconds > 0)
+}
+
+impl TimelineParameter {
+    pub fn new(
+        column: &Yaml,
+        default_format: &str,
+        default_start: &str,
+        default_step: i64,
+    ) -> TimelineParameter {
+        let column_name = get_column_name(column);
+
+        let format = match &column["format"] {
+            Yaml::String(value) => value.clone(),
+            Yaml::BadValue => default_format.to_string(),
+            _ => {
+                warn!(
+                    "{} column: format should be a string. Default value '{}' is taken.",
+                    column_name, default_format
+                );
+                default_format.to_string()
+            }
+        };
+
+        let default_start_value = NaiveDateTime::parse_from_str(default_start, default_format)
+            .unwrap()
+            .and_utc()
+            .timestamp();
+        let start = match &column["start"] {
+            Yaml::BadValue => default_start_value,
+            value => match value
+                .as_str()
+                .and_then(|value| NaiveDateTime::parse_from_str(value, &format).ok())
+            {
+                Some(start) => start.and_utc().timestamp(),
+                None => {
+                    warn!(
+                        "{} column: start should be a datetime with format {}. Default value '{}' is taken.",
+                        column_name, format, default_start
+                    );
+                    default_start_value
+                }
+            },
+        };
+
+        let step = match &column["step"] {
+            Yaml::BadValue => default_step,
+            value => match parse_step(value) {
+                Some(step) => step,
+                None => {
+                    warn!(
+                        "{} column: step should be a positive number of seconds, or of minutes, hours or days like 5m, 1h or 1d. Default value '{}' is taken.",
+                        column_name, default_step
+                    );
+                    default_step
+                }
+            },
+        };
+
+        TimelineParameter {
+            format,
+            start,
+            step,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use yaml_rust::YamlLoader;
+
+    use super::TimelineParameter;
+
+    const FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+    fn generate_parameter(params: &str) -> TimelineParameter {
+        let yaml_str = format!("name: ts{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        TimelineParameter::new(&yaml[0], FORMAT, "2024-01-01 00:00:00", 60)
+    }
+
+    #[test]
+    fn given_nothing_should_return_defaults() {
+        let parameter = generate_parameter("");
+        assert_eq!(parameter.format, FORMAT);
+        assert_eq!(parameter.start, 1_704_067_200);
+        assert_eq!(parameter.step, 60);
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let parameter =
+            generate_parameter("\nformat: \"%d/%m/%Y %H:%M\"\nstart: 02/01/2024 10:00\nstep: 5m");
+        assert_eq!(parameter.format, "%d/%m/%Y %H:%M");
+        assert_eq!(parameter.start, 1_704_189_600);
+        assert_eq!(parameter.step, 300);
+    }
+
+    #[test]
+    fn given_steps_should_return_seconds() {
+        for (step, seconds) in [("30", 30), ("30s", 30), ("2h", 7_200), ("1d", 86_400)] {
+            assert_eq!(
+                generate_parameter(&format!("\nstep: {}", step)).step,
+                seconds
+            );
+        }
+    }
+
+    #[test]
+    fn given_wrong_params_should_use_defaults() {
+        let parameter = generate_parameter("\nformat: 3\nstart: yesterday\nstep: -5");
+        assert_eq!(parameter.format, FORMAT);
+        assert_eq!(parameter.start, 1_704_067_200);
+        assert_eq!(parameter.step, 60);
+
+        for step in ["0", "5y", "m", "abc", "1.5"] {
+            assert_eq!(generate_parameter(&format!("\nstep: {}", step)).step, 60);
+        }
+    }
+}
@@ -10,6 +10,9 @@ use yaml_rust::Yaml;
 pub enum Value {
     Bool(bool),
     Int32(i32),
+    Int64(i64),
+    /// Unscaled value, precision and scale
+    Decimal(i128, u8, u8),
     Float64(f64),
     String(String),
     Date(NaiveDate, String),
@@ -106,6 +109,9 @@ impl ProviderBuilder {
             Some("random") => {
                 providers::random::builder::get_corresponding_provider(provider_split, column)
             }
+            Some("timeseries") => {
+                providers::timeseries::builder::get_corresponding_provider(provider_split, column)
+            }
             _ => Err(unknown_provider(provider)),
         }
     }
@@ -183,6 +189,7 @@ mod tests {
             "increment.integer",
             "person.email",
             "random.string.alphanumeric",
+            "timeseries.metric",
         ];
         for provider_name in provider_names {
             let yaml_str = format!("name: name{}provider: {}", '\n', provider_name);
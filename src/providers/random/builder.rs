@@ -1,7 +1,7 @@
 use crate::errors::FakeLakeError;
 use crate::providers::provider::Provider;
 
-use super::{bool, date, number, string};
+use super::{bool, date, number, string, uuid};
 
 use yaml_rust::Yaml;
 
@@ -14,6 +14,7 @@ pub fn get_corresponding_provider(
         Some("date") => date::builder::get_corresponding_provider(provider_split, column),
         Some("number") => number::builder::get_corresponding_provider(provider_split, column),
         Some("string") => string::builder::get_corresponding_provider(provider_split, column),
+        Some("uuid") => Ok(uuid::new_from_yaml(column)),
         _ => Err(FakeLakeError::BadYAMLFormat("".to_string())),
     }
 }
@@ -63,6 +64,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_uuid_should_return_provider() {
+        let provider_name = "uuid";
+        let yaml_str = format!("name: id{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
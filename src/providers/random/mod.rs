@@ -4,3 +4,4 @@ pub mod bool;
 pub mod date;
 pub mod number;
 pub mod string;
+pub mod uuid;
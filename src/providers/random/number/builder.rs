@@ -1,7 +1,7 @@
 use crate::errors::FakeLakeError;
 use crate::providers::provider::Provider;
 
-use super::{f64, i32};
+use super::{decimal, f64, i32, i64};
 
 use yaml_rust::Yaml;
 
@@ -10,8 +10,10 @@ pub fn get_corresponding_provider(
     column: &Yaml,
 ) -> Result<Box<dyn Provider>, FakeLakeError> {
     match provider_split.next() {
+        Some("decimal") => Ok(decimal::new_from_yaml(column)),
         Some("f64") => Ok(f64::new_from_yaml(column)),
         Some("i32") => Ok(i32::new_from_yaml(column)),
+        Some("i64") => Ok(i64::new_from_yaml(column)),
         _ => Err(FakeLakeError::BadYAMLFormat("".to_string())),
     }
 }
@@ -35,6 +37,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_i64_should_return_provider() {
+        let provider_name = "i64";
+        let yaml_str = format!("name: random_long{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_f64_should_return_provider() {
         let provider_name = "f64";
@@ -48,6 +63,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn given_decimal_should_return_provider() {
+        let provider_name = "decimal";
+        let yaml_str = format!("name: random_decimal{}provider: {}", '\n', provider_name);
+        let column = &YamlLoader::load_from_str(yaml_str.as_str()).unwrap()[0];
+
+        let provider_split = provider_name.split('.');
+        match get_corresponding_provider(provider_split, column) {
+            Ok(_) => (),
+            _ => panic!(),
+        }
+    }
+
     #[test]
     fn given_wrong_provider_should_return_error() {
         let provider_name = "not_a_provider";
@@ -0,0 +1,225 @@
+use crate::providers::parameters::get_column_name;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::decimal::{format_decimal, max_unscaled, parse_decimal};
+
+use log::warn;
+use yaml_rust::Yaml;
+
+const DEFAULT_PRECISION: u8 = 10;
+const DEFAULT_SCALE: u8 = 2;
+/// Largest precision of a decimal stored in 128 bits
+const MAX_PRECISION: u8 = 38;
+
+#[derive(Clone)]
+pub struct DecimalProvider {
+    pub precision: u8,
+    pub scale: u8,
+    /// Unscaled bounds, both included
+    pub min: i128,
+    pub max: i128,
+}
+
+impl Provider for DecimalProvider {
+    fn value(&self, _: u32) -> Value {
+        Value::Decimal(
+            crate::rng::i128(self.min..=self.max),
+            self.precision,
+            self.scale,
+        )
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        let max = max_unscaled(self.precision);
+        Value::Decimal(crate::rng::i128(-max..=max), self.precision, self.scale)
+    }
+}
+
+fn get_u8_param(column: &Yaml, param_name: &str, range: (u8, u8), default_value: u8) -> u8 {
+    match column[param_name] {
+        Yaml::Integer(value) if value >= range.0 as i64 && value <= range.1 as i64 => value as u8,
+        Yaml::BadValue => default_value,
+        _ => {
+            warn!(
+                "{} column: {} should be between {} and {}. Default value '{}' is taken.",
+                get_column_name(column),
+                param_name,
+                range.0,
+                range.1,
+                default_value
+            );
+            default_value
+        }
+    }
+}
+
+/// Unscaled value of a bound, which must fit in the precision and scale
+fn get_bound_param(column: &Yaml, param_name: &str, precision: u8, scale: u8) -> Option<i128> {
+    let text = match &column[param_name] {
+        Yaml::BadValue => return None,
+        Yaml::Integer(value) => Some(value.to_string()),
+        Yaml::Real(value) | Yaml::String(value) => Some(value.clone()),
+        _ => None,
+    };
+    match text.and_then(|text| parse_decimal(&text, scale)) {
+        Some(value) if value.abs() <= max_unscaled(precision) => Some(value),
+        _ => {
+            warn!(
+                "{} column: {} should be a decimal with precision {} and scale {}. Default is taken.",
+                get_column_name(column),
+                param_name,
+                precision,
+                scale
+            );
+            None
+        }
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<DecimalProvider> {
+    let precision = get_u8_param(column, "precision", (1, MAX_PRECISION), DEFAULT_PRECISION);
+    let scale = get_u8_param(
+        column,
+        "scale",
+        (0, precision),
+        DEFAULT_SCALE.min(precision),
+    );
+
+    let default_max = max_unscaled(precision);
+    let min = get_bound_param(column, "min", precision, scale).unwrap_or(-default_max);
+    let max = get_bound_param(column, "max", precision, scale).unwrap_or(default_max);
+
+    if min > max {
+        warn!(
+            "{} column: min is greater than max. Defaults are used ([{} and {}])",
+            get_column_name(column),
+            format_decimal(-default_max, scale),
+            format_decimal(default_max, scale)
+        );
+        return Box::new(DecimalProvider {
+            precision,
+            scale,
+            min: -default_max,
+            max: default_max,
+        });
+    }
+
+    Box::new(DecimalProvider {
+        precision,
+        scale,
+        min,
+        max,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DecimalProvider, DEFAULT_PRECISION, DEFAULT_SCALE};
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(params: &str) -> Box<DecimalProvider> {
+        let yaml_str = format!("name: amount{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        super::new_from_yaml(&yaml[0])
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_decimal_type() {
+        let provider = generate_provider("");
+        match provider.value(0) {
+            Value::Decimal(_, precision, scale) => {
+                assert_eq!(precision, DEFAULT_PRECISION);
+                assert_eq!(scale, DEFAULT_SCALE);
+            }
+            _ => panic!(),
+        };
+    }
+
+    // Validate yaml config
+    #[test]
+    fn given_no_params_should_use_defaults() {
+        let provider = generate_provider("");
+        assert_eq!(provider.precision, 10);
+        assert_eq!(provider.scale, 2);
+        assert_eq!(provider.min, -9_999_999_999);
+        assert_eq!(provider.max, 9_999_999_999);
+    }
+
+    #[test]
+    fn given_params_should_use_them() {
+        let provider = generate_provider("\nprecision: 12\nscale: 4\nmin: -10\nmax: 1000.25");
+        assert_eq!(provider.precision, 12);
+        assert_eq!(provider.scale, 4);
+        assert_eq!(provider.min, -100_000);
+        assert_eq!(provider.max, 10_002_500);
+    }
+
+    #[test]
+    fn given_wrong_precision_and_scale_should_use_defaults() {
+        let provider = generate_provider("\nprecision: 39\nscale: -1");
+        assert_eq!(provider.precision, DEFAULT_PRECISION);
+        assert_eq!(provider.scale, DEFAULT_SCALE);
+
+        let provider = generate_provider("\nprecision: 1");
+        assert_eq!(provider.scale, 1);
+    }
+
+    #[test]
+    fn given_bounds_not_fitting_should_use_defaults() {
+        let provider = generate_provider("\nprecision: 4\nscale: 2\nmin: 0.001\nmax: 100");
+        assert_eq!(provider.min, -9999);
+        assert_eq!(provider.max, 9999);
+    }
+
+    #[test]
+    fn given_inverted_min_max_should_use_defaults() {
+        let provider = generate_provider("\nmin: 10\nmax: -10");
+        assert_eq!(provider.min, -9_999_999_999);
+        assert_eq!(provider.max, 9_999_999_999);
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_bounds_should_return_values_between_them() {
+        let provider = generate_provider("\nprecision: 5\nscale: 2\nmin: 0.01\nmax: 0.03");
+        let mut values = Vec::new();
+        for i in 0..100 {
+            match provider.value(i) {
+                Value::Decimal(value, 5, 2) => values.push(value),
+                _ => panic!("Wrong type"),
+            }
+        }
+        values.sort();
+        values.dedup();
+        assert_eq!(values, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn given_max_precision_should_return_values() {
+        let provider = generate_provider("\nprecision: 38\nscale: 10");
+        match provider.value(0) {
+            Value::Decimal(value, 38, 10) => assert!(value.abs() < 10_i128.pow(38)),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    #[test]
+    fn given_small_interval_should_corrupted_return_random() {
+        let provider = generate_provider("\nprecision: 10\nscale: 2\nmin: 0\nmax: 1");
+
+        let mut count_random = 0;
+        for i in 0..100 {
+            match provider.corrupted_value(i) {
+                Value::Decimal(value, 10, 2) => {
+                    assert!(value.abs() < 10_i128.pow(10));
+                    if !(0..=100).contains(&value) {
+                        count_random += 1;
+                    }
+                }
+                _ => panic!("Wrong type"),
+            }
+        }
+        assert!(count_random >= 99);
+    }
+}
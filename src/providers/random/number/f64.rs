@@ -1,6 +1,8 @@
+use crate::providers::parameters::distribution::DistributionParameter;
 use crate::providers::parameters::f64::F64Parameter;
 use crate::providers::parameters::get_column_name;
 use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::distribution::Distribution;
 
 use log::warn;
 use yaml_rust::Yaml;
@@ -12,11 +14,16 @@ const DEFAULT_MAX: f64 = f64::MAX;
 pub struct F64Provider {
     pub min: f64,
     pub max: f64,
+    /// None to draw uniformly between min and max
+    pub distribution: Option<Distribution>,
 }
 
 impl Provider for F64Provider {
     fn value(&self, _: u32) -> Value {
-        Value::Float64(crate::rng::f64_range(self.min..self.max))
+        match &self.distribution {
+            Some(distribution) => Value::Float64(distribution.sample(self.min, self.max)),
+            None => Value::Float64(crate::rng::f64_range(self.min..self.max)),
+        }
     }
     fn corrupted_value(&self, _: u32) -> Value {
         Value::Float64(crate::rng::f64_range(f64::MIN..f64::MAX))
@@ -26,6 +33,7 @@ impl Provider for F64Provider {
 pub fn new_from_yaml(column: &Yaml) -> Box<F64Provider> {
     let yaml_min = F64Parameter::new(column, "min", DEFAULT_MIN).value;
     let yaml_max = F64Parameter::new(column, "max", DEFAULT_MAX).value;
+    let distribution = DistributionParameter::new(column).value;
 
     if yaml_min >= yaml_max {
         warn!(
@@ -37,11 +45,13 @@ pub fn new_from_yaml(column: &Yaml) -> Box<F64Provider> {
         Box::new(F64Provider {
             min: DEFAULT_MIN,
             max: DEFAULT_MAX,
+            distribution,
         })
     } else {
         Box::new(F64Provider {
             min: yaml_min,
             max: yaml_max,
+            distribution,
         })
     }
 }
@@ -138,4 +148,27 @@ mod tests {
 
         assert!(count_random_float >= 99);
     }
+
+    #[test]
+    fn given_distribution_should_return_clamped_values_of_it() {
+        let yaml_str =
+            "name: id\nmin: 0\nmax: 1\ndistribution:\n  type: normal\n  mean: 1\n  clamp: true";
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let provider = super::new_from_yaml(&yaml[0]);
+
+        let mut count_max = 0;
+        for i in 0..1000 {
+            match provider.value(i) {
+                Value::Float64(value) => {
+                    assert!((0.0..=1.0).contains(&value));
+                    if value == 1.0 {
+                        count_max += 1;
+                    }
+                }
+                _ => panic!("Should not happen"),
+            }
+        }
+        // half of the values are above the mean
+        assert!(count_max > 400);
+    }
 }
@@ -1,6 +1,8 @@
+use crate::providers::parameters::distribution::DistributionParameter;
 use crate::providers::parameters::get_column_name;
 use crate::providers::parameters::i32::I32Parameter;
 use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::distribution::Distribution;
 
 use log::warn;
 use yaml_rust::Yaml;
@@ -12,11 +14,22 @@ const DEFAULT_MAX: i32 = i32::MAX;
 pub struct I32Provider {
     pub min: i32,
     pub max: i32,
+    /// None to draw uniformly between min and max
+    pub distribution: Option<Distribution>,
 }
 
 impl Provider for I32Provider {
     fn value(&self, _: u32) -> Value {
-        Value::Int32(crate::rng::i32(self.min..self.max))
+        match &self.distribution {
+            // integers stay in [min, max[ as with the uniform distribution only when clamped,
+            // unclamped values out of the i32 range are saturated to i32::MIN or i32::MAX
+            Some(distribution) => Value::Int32(
+                distribution
+                    .sample(self.min as f64, (self.max - 1) as f64)
+                    .round() as i32,
+            ),
+            None => Value::Int32(crate::rng::i32(self.min..self.max)),
+        }
     }
     fn corrupted_value(&self, _: u32) -> Value {
         Value::Int32(crate::rng::i32(i32::MIN..i32::MAX))
@@ -26,6 +39,7 @@ impl Provider for I32Provider {
 pub fn new_from_yaml(column: &Yaml) -> Box<I32Provider> {
     let yaml_min = I32Parameter::new(column, "min", DEFAULT_MIN).value;
     let yaml_max = I32Parameter::new(column, "max", DEFAULT_MAX).value;
+    let distribution = DistributionParameter::new(column).value;
 
     if yaml_min >= yaml_max {
         warn!(
@@ -37,11 +51,13 @@ pub fn new_from_yaml(column: &Yaml) -> Box<I32Provider> {
         Box::new(I32Provider {
             min: DEFAULT_MIN,
             max: DEFAULT_MAX,
+            distribution,
         })
     } else {
         Box::new(I32Provider {
             min: yaml_min,
             max: yaml_max,
+            distribution,
         })
     }
 }
@@ -154,4 +170,44 @@ mod tests {
 
         assert!(count_random_int >= 99);
     }
+
+    #[test]
+    fn given_distribution_should_return_clamped_values_of_it() {
+        let yaml_str =
+            "name: id\nmin: 1\nmax: 11\ndistribution:\n  type: zipf\n  n: 100\n  clamp: true";
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let provider = super::new_from_yaml(&yaml[0]);
+
+        let mut values = Vec::new();
+        for i in 0..1000 {
+            match provider.value(i) {
+                Value::Int32(value) => values.push(value),
+                _ => panic!("Should not happen"),
+            }
+        }
+        assert!(values.iter().all(|value| (1..=10).contains(value)));
+        let count = |rank: i32| values.iter().filter(|value| **value == rank).count();
+        assert!(count(1) > count(2));
+        assert!(count(10) > count(9));
+    }
+
+    #[test]
+    fn given_distribution_should_return_values_out_of_min_max_unless_clamped() {
+        let values = |clamp: bool| {
+            let yaml_str = format!(
+                "name: id\nmin: 0\nmax: 10\ndistribution:\n  type: normal\n  mean: 5\n  stddev: 50\n  clamp: {}",
+                clamp
+            );
+            let yaml = YamlLoader::load_from_str(&yaml_str).unwrap();
+            let provider = super::new_from_yaml(&yaml[0]);
+            (0..1000)
+                .map(|i| match provider.value(i) {
+                    Value::Int32(value) => value,
+                    _ => panic!("Should not happen"),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert!(values(false).iter().any(|value| !(0..10).contains(value)));
+        assert!(values(true).iter().all(|value| (0..=9).contains(value)));
+    }
 }
@@ -0,0 +1,201 @@
+use crate::providers::parameters::distribution::DistributionParameter;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::i64::I64Parameter;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::distribution::Distribution;
+
+use log::warn;
+use yaml_rust::Yaml;
+
+const DEFAULT_MIN: i64 = i64::MIN;
+const DEFAULT_MAX: i64 = i64::MAX;
+
+#[derive(Clone)]
+pub struct I64Provider {
+    pub min: i64,
+    pub max: i64,
+    /// None to draw uniformly between min and max
+    pub distribution: Option<Distribution>,
+}
+
+impl Provider for I64Provider {
+    fn value(&self, _: u32) -> Value {
+        match &self.distribution {
+            // integers stay in [min, max[ as with the uniform distribution only when clamped,
+            // unclamped values out of the i64 range are saturated to i64::MIN or i64::MAX
+            Some(distribution) => Value::Int64(
+                distribution
+                    .sample(self.min as f64, (self.max - 1) as f64)
+                    .round() as i64,
+            ),
+            None => Value::Int64(crate::rng::i64(self.min..self.max)),
+        }
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::Int64(crate::rng::i64(i64::MIN..i64::MAX))
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<I64Provider> {
+    let yaml_min = I64Parameter::new(column, "min", DEFAULT_MIN).value;
+    let yaml_max = I64Parameter::new(column, "max", DEFAULT_MAX).value;
+    let distribution = DistributionParameter::new(column).value;
+
+    if yaml_min >= yaml_max {
+        warn!(
+            "Column {} min is not less or equal to max option. Default are used ([{} and {}[)",
+            get_column_name(column),
+            DEFAULT_MIN,
+            DEFAULT_MAX
+        );
+        Box::new(I64Provider {
+            min: DEFAULT_MIN,
+            max: DEFAULT_MAX,
+            distribution,
+        })
+    } else {
+        Box::new(I64Provider {
+            min: yaml_min,
+            max: yaml_max,
+            distribution,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{I64Provider, DEFAULT_MAX, DEFAULT_MIN};
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(min: Option<&str>, max: Option<&str>) -> Box<I64Provider> {
+        let yaml_min = match min {
+            Some(value) => format!("{}min: {}", "\n", value),
+            None => String::new(),
+        };
+        let yaml_max = match max {
+            Some(value) => format!("{}max: {}", "\n", value),
+            None => String::new(),
+        };
+
+        let yaml_str = format!("name: id{}{}", yaml_min, yaml_max);
+
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        super::new_from_yaml(&yaml[0])
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_parquet_type() {
+        let provider = generate_provider(None, None);
+        match provider.value(0) {
+            Value::Int64(_) => (),
+            _ => panic!(),
+        };
+    }
+
+    // Validate yaml config
+    #[test]
+    fn given_no_params_should_use_default() {
+        let provider = generate_provider(None, None);
+
+        assert_eq!(provider.min, DEFAULT_MIN);
+        assert_eq!(provider.max, DEFAULT_MAX);
+    }
+
+    #[test]
+    fn given_normal_params_should_use_params() {
+        let provider = generate_provider(Some("-100"), Some("100"));
+
+        assert_eq!(provider.min, -100);
+        assert_eq!(provider.max, 100);
+    }
+
+    #[test]
+    fn given_no_max_param_should_use_default() {
+        let provider = generate_provider(Some("-100"), None);
+
+        assert_eq!(provider.min, -100);
+        assert_eq!(provider.max, DEFAULT_MAX);
+    }
+
+    #[test]
+    fn given_no_min_param_should_use_default() {
+        let provider = generate_provider(None, Some("100"));
+
+        assert_eq!(provider.min, DEFAULT_MIN);
+        assert_eq!(provider.max, 100);
+    }
+
+    #[test]
+    fn given_params_out_of_i32_range_should_use_params() {
+        let provider = generate_provider(Some("-10000000000"), Some("10000000000"));
+
+        assert_eq!(provider.min, -10_000_000_000);
+        assert_eq!(provider.max, 10_000_000_000);
+    }
+
+    #[test]
+    fn given_inverted_min_max_params_should_use_default() {
+        let provider = generate_provider(Some("100"), Some("-100"));
+
+        assert_eq!(provider.min, DEFAULT_MIN);
+        assert_eq!(provider.max, DEFAULT_MAX);
+    }
+
+    #[test]
+    fn given_small_interval_should_corrupted_return_random() {
+        let provider = generate_provider(Some("-100"), Some("100"));
+
+        let mut count_random_int = 0;
+        for i in 0..100 {
+            let value = match provider.corrupted_value(i) {
+                Value::Int64(res) => res,
+                _ => panic!("Should not happen"),
+            };
+
+            if !(-100..=100).contains(&value) {
+                count_random_int += 1;
+            }
+        }
+
+        assert!(count_random_int >= 99);
+    }
+
+    #[test]
+    fn given_distribution_should_return_values_of_it() {
+        let yaml_str =
+            "name: id\nmin: 0\nmax: 100\ndistribution:\n  type: poisson\n  lambda: 1000000";
+        let yaml = YamlLoader::load_from_str(yaml_str).unwrap();
+        let provider = super::new_from_yaml(&yaml[0]);
+
+        for i in 0..100 {
+            match provider.value(i) {
+                // without clamping, values may be out of min and max
+                Value::Int64(value) => assert!((990_000..1_010_000).contains(&value)),
+                _ => panic!("Should not happen"),
+            }
+        }
+    }
+
+    #[test]
+    fn given_distribution_should_return_values_out_of_min_max_unless_clamped() {
+        let values = |clamp: bool| {
+            let yaml_str = format!(
+                "name: id\nmin: 0\nmax: 10\ndistribution:\n  type: normal\n  mean: 5\n  stddev: 50\n  clamp: {}",
+                clamp
+            );
+            let yaml = YamlLoader::load_from_str(&yaml_str).unwrap();
+            let provider = super::new_from_yaml(&yaml[0]);
+            (0..1000)
+                .map(|i| match provider.value(i) {
+                    Value::Int64(value) => value,
+                    _ => panic!("Should not happen"),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert!(values(false).iter().any(|value| !(0..10).contains(value)));
+        assert!(values(true).iter().all(|value| (0..=9).contains(value)));
+    }
+}
@@ -1,4 +1,6 @@
 pub mod builder;
 
+pub mod decimal;
 pub mod f64;
 pub mod i32;
+pub mod i64;
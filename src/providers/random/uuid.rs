@@ -0,0 +1,90 @@
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::string::random_characters;
+
+use uuid::Builder;
+use yaml_rust::Yaml;
+
+/// Length of the hyphenated text of a uuid
+pub const UUID_LENGTH: u32 = 36;
+
+#[derive(Clone)]
+pub struct RandomUuidProvider {}
+
+impl Provider for RandomUuidProvider {
+    fn value(&self, _: u32) -> Value {
+        let uuid = Builder::from_random_bytes(crate::rng::u128().to_le_bytes()).into_uuid();
+        Value::String(uuid.to_string())
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::String(random_characters(UUID_LENGTH))
+    }
+}
+
+pub fn new_from_yaml(_: &Yaml) -> Box<RandomUuidProvider> {
+    Box::new(RandomUuidProvider {})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::provider::{Provider, Value};
+
+    use uuid::{Uuid, Version};
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider() -> Box<RandomUuidProvider> {
+        let yaml = YamlLoader::load_from_str("name: id").unwrap();
+        super::new_from_yaml(&yaml[0])
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_string_type() {
+        let provider = generate_provider();
+        match provider.value(0) {
+            Value::String(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_provider_should_return_v4_uuids() {
+        let provider = generate_provider();
+        let mut values = Vec::new();
+        for i in 0..100 {
+            match provider.value(i) {
+                Value::String(value) => {
+                    assert_eq!(value.len(), UUID_LENGTH as usize);
+                    let uuid = Uuid::parse_str(&value).unwrap();
+                    assert_eq!(uuid.get_version(), Some(Version::Random));
+                    values.push(value);
+                }
+                _ => panic!("Wrong type"),
+            }
+        }
+        values.sort();
+        values.dedup();
+        assert_eq!(values.len(), 100);
+    }
+
+    #[test]
+    fn given_seed_should_return_same_uuids() {
+        let provider = generate_provider();
+        crate::rng::initialize_rng(Some(42));
+        let first = provider.value(0);
+        crate::rng::initialize_rng(Some(42));
+        assert_eq!(provider.value(0), first);
+    }
+
+    #[test]
+    fn given_provider_should_corrupted_return_uuid_length_strings() {
+        let provider = generate_provider();
+        for i in 0..100 {
+            match provider.corrupted_value(i) {
+                Value::String(value) => assert_eq!(value.chars().count(), UUID_LENGTH as usize),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+}
@@ -0,0 +1,328 @@
+use super::timestamp::{DEFAULT_FORMAT, DEFAULT_START, DEFAULT_STEP};
+use crate::providers::parameters::f64::F64Parameter;
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::timeline::TimelineParameter;
+use crate::providers::provider::{Provider, Value};
+use crate::providers::utils::distribution::standard_normal;
+
+use log::warn;
+use std::f64::consts::PI;
+use yaml_rust::Yaml;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+const DEFAULT_DAILY_PEAK: f64 = 12.0;
+/// Wednesday
+const DEFAULT_WEEKLY_PEAK: f64 = 3.0;
+/// The 1st of January 1970 is a Thursday, 3 days after a Monday
+const EPOCH_DAY_OF_WEEK: f64 = 3.0;
+/// The random walk is built on the rows from 0 to 2^32, which covers every u32 index
+const RANDOM_WALK_ROWS: u64 = 1 << 32;
+/// Keys of the noise of rows, after the ones of the random walk
+const NOISE_KEYS_OFFSET: u64 = RANDOM_WALK_ROWS + 1;
+
+#[derive(Clone)]
+pub struct TimeseriesMetricProvider {
+    /// Timestamp of the first row, in seconds
+    pub start: i64,
+    /// Seconds between two rows
+    pub step: i64,
+    /// Value at start
+    pub base: f64,
+    /// Change per day
+    pub trend: f64,
+    pub daily_amplitude: f64,
+    /// Hour of the daily maximum, from 0 to 24
+    pub daily_peak: f64,
+    pub weekly_amplitude: f64,
+    /// Day of the weekly maximum at noon, from 1 for Monday to 7 for Sunday
+    pub weekly_peak: f64,
+    /// Standard deviation of the random walk between two rows
+    pub random_walk: f64,
+    /// Standard deviation of the gaussian noise of each row
+    pub noise: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Seed of the random walk and noise, so that a row always has the same value
+    pub seed: u64,
+}
+
+impl TimeseriesMetricProvider {
+    /// Standard normal value only depending on the seed and the key
+    fn gaussian(&self, key: u64) -> f64 {
+        let mut rng = fastrand::Rng::with_seed(self.seed ^ key.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        standard_normal(&mut rng)
+    }
+
+    /// Random walk at a row, computed as a Brownian bridge refined between the first and the last
+    /// rows: each midpoint only depends on the two ends of its interval, so a row is found in 32
+    /// halvings without computing the rows before it
+    fn random_walk(&self, index: u32) -> f64 {
+        let index = index as u64;
+        let (mut low, mut high) = (0, RANDOM_WALK_ROWS);
+        let (mut low_value, mut high_value) = (
+            0.0,
+            self.random_walk * (high as f64).sqrt() * self.gaussian(high),
+        );
+        while high - low > 1 {
+            let middle = (low + high) / 2;
+            let middle_value = (low_value + high_value) / 2.0
+                + self.random_walk * ((high - low) as f64).sqrt() / 2.0 * self.gaussian(middle);
+            if index >= middle {
+                (low, low_value) = (middle, middle_value);
+            } else {
+                (high, high_value) = (middle, middle_value);
+            }
+        }
+        low_value
+    }
+
+    fn seasonality(&self, timestamp: i64) -> f64 {
+        let mut seasonality = 0.0;
+        if self.daily_amplitude != 0.0 {
+            let hour = timestamp.rem_euclid(SECONDS_PER_DAY as i64) as f64 / 3_600.0;
+            seasonality +=
+                self.daily_amplitude * (2.0 * PI * (hour - self.daily_peak) / 24.0).cos();
+        }
+        if self.weekly_amplitude != 0.0 {
+            // days since Monday 00:00, and days since Monday 00:00 of the peak at noon
+            let day = (timestamp as f64 / SECONDS_PER_DAY + EPOCH_DAY_OF_WEEK).rem_euclid(7.0);
+            let peak = self.weekly_peak - 0.5;
+            seasonality += self.weekly_amplitude * (2.0 * PI * (day - peak) / 7.0).cos();
+        }
+        seasonality
+    }
+}
+
+impl Provider for TimeseriesMetricProvider {
+    fn value(&self, index: u32) -> Value {
+        let elapsed = (index as i64).saturating_mul(self.step);
+        let mut value = self.base
+            + self.trend * elapsed as f64 / SECONDS_PER_DAY
+            + self.seasonality(self.start.saturating_add(elapsed));
+        if self.random_walk > 0.0 {
+            value += self.random_walk(index);
+        }
+        if self.noise > 0.0 {
+            value += self.noise * self.gaussian(NOISE_KEYS_OFFSET + index as u64);
+        }
+        Value::Float64(value.clamp(self.min, self.max))
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::Float64(crate::rng::f64_range(f64::MIN..f64::MAX))
+    }
+}
+
+fn get_bounded_param(
+    column: &Yaml,
+    param_name: &str,
+    range: (f64, f64),
+    default_value: f64,
+) -> f64 {
+    match F64Parameter::new(column, param_name, default_value).value {
+        value if value >= range.0 && value <= range.1 => value,
+        _ => {
+            warn!(
+                "{} column: {} should be between {} and {}. Default value '{}' is taken.",
+                get_column_name(column),
+                param_name,
+                range.0,
+                range.1,
+                default_value
+            );
+            default_value
+        }
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<TimeseriesMetricProvider> {
+    let timeline = TimelineParameter::new(column, DEFAULT_FORMAT, DEFAULT_START, DEFAULT_STEP);
+
+    let mut min = F64Parameter::new(column, "min", f64::MIN).value;
+    let mut max = F64Parameter::new(column, "max", f64::MAX).value;
+    if min > max {
+        warn!(
+            "{} column: min is greater than max. Values are not bounded.",
+            get_column_name(column)
+        );
+        (min, max) = (f64::MIN, f64::MAX);
+    }
+
+    Box::new(TimeseriesMetricProvider {
+        start: timeline.start,
+        step: timeline.step,
+        base: F64Parameter::new(column, "base", 0.0).value,
+        trend: F64Parameter::new(column, "trend", 0.0).value,
+        daily_amplitude: F64Parameter::new(column, "daily_amplitude", 0.0).value,
+        daily_peak: get_bounded_param(column, "daily_peak", (0.0, 24.0), DEFAULT_DAILY_PEAK),
+        weekly_amplitude: F64Parameter::new(column, "weekly_amplitude", 0.0).value,
+        weekly_peak: get_bounded_param(column, "weekly_peak", (1.0, 7.0), DEFAULT_WEEKLY_PEAK),
+        random_walk: get_bounded_param(column, "random_walk", (0.0, f64::MAX), 0.0),
+        noise: get_bounded_param(column, "noise", (0.0, f64::MAX), 0.0),
+        min,
+        max,
+        seed: crate::rng::u64(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::provider::{Provider, Value};
+
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(params: &str) -> Box<TimeseriesMetricProvider> {
+        let yaml_str = format!("name: cpu{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        super::new_from_yaml(&yaml[0])
+    }
+
+    fn get_value(provider: &TimeseriesMetricProvider, index: u32) -> f64 {
+        match provider.value(index) {
+            Value::Float64(value) => value,
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_float_type() {
+        let provider = generate_provider("");
+        match provider.value(0) {
+            Value::Float64(_) => (),
+            _ => panic!(),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_nothing_should_return_flat_metric() {
+        let provider = generate_provider("");
+        assert_eq!(provider.step, DEFAULT_STEP);
+        assert_eq!(provider.daily_peak, DEFAULT_DAILY_PEAK);
+        assert_eq!(provider.weekly_peak, DEFAULT_WEEKLY_PEAK);
+        for i in 0..100 {
+            assert_eq!(get_value(&provider, i), 0.0);
+        }
+    }
+
+    #[test]
+    fn given_wrong_params_should_use_defaults() {
+        let provider = generate_provider(
+            "\ndaily_peak: 25\nweekly_peak: 0\nrandom_walk: -1\nnoise: -2\nmin: 10\nmax: 0",
+        );
+        assert_eq!(provider.daily_peak, DEFAULT_DAILY_PEAK);
+        assert_eq!(provider.weekly_peak, DEFAULT_WEEKLY_PEAK);
+        assert_eq!(provider.random_walk, 0.0);
+        assert_eq!(provider.noise, 0.0);
+        assert_eq!(provider.min, f64::MIN);
+        assert_eq!(provider.max, f64::MAX);
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_trend_should_return_values_changing_per_day() {
+        let provider = generate_provider("\nbase: 100\ntrend: 2.5\nstep: 1h");
+        assert_eq!(get_value(&provider, 0), 100.0);
+        assert_eq!(get_value(&provider, 24), 102.5);
+        assert_eq!(get_value(&provider, 240), 125.0);
+    }
+
+    #[test]
+    fn given_daily_amplitude_should_return_daily_cycle_with_peak() {
+        let provider = generate_provider(
+            "\nstart: 2024-01-01 00:00:00\nstep: 1h\nbase: 50\ndaily_amplitude: 10\ndaily_peak: 14",
+        );
+        assert!((get_value(&provider, 14) - 60.0).abs() < 1e-9);
+        assert!((get_value(&provider, 2) - 40.0).abs() < 1e-9);
+        assert!((get_value(&provider, 38) - 60.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn given_weekly_amplitude_should_return_weekly_cycle_with_peak() {
+        // 2024-01-01 is a Monday, the peak is on Saturday at noon
+        let provider = generate_provider(
+            "\nstart: 2024-01-01 00:00:00\nstep: 12h\nweekly_amplitude: 5\nweekly_peak: 6",
+        );
+        assert!((get_value(&provider, 11) - 5.0).abs() < 1e-9);
+        assert!((get_value(&provider, 25) - 5.0).abs() < 1e-9);
+        assert!(get_value(&provider, 4) < 0.0);
+    }
+
+    #[test]
+    fn given_min_and_max_should_return_bounded_values() {
+        let provider =
+            generate_provider("\nbase: 50\ntrend: 10\nstep: 1d\nnoise: 20\nmin: 0\nmax: 100");
+        for i in 0..100 {
+            assert!((0.0..=100.0).contains(&get_value(&provider, i)));
+        }
+        assert_eq!(get_value(&provider, 99), 100.0);
+    }
+
+    #[test]
+    fn given_noise_should_return_gaussian_values_around_base() {
+        let provider = generate_provider("\nbase: 10\nnoise: 2");
+        let values: Vec<f64> = (0..20_000).map(|i| get_value(&provider, i)).collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let variance = values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / values.len() as f64;
+        assert!((mean - 10.0).abs() < 0.1);
+        assert!((variance.sqrt() - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn given_random_walk_should_return_steps_of_stddev() {
+        let provider = generate_provider("\nrandom_walk: 1");
+        assert_eq!(get_value(&provider, 0), 0.0);
+        let values: Vec<f64> = (0..20_001).map(|i| get_value(&provider, i)).collect();
+        let steps: Vec<f64> = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let mean = steps.iter().sum::<f64>() / steps.len() as f64;
+        let variance =
+            steps.iter().map(|step| (step - mean).powi(2)).sum::<f64>() / steps.len() as f64;
+        assert!(mean.abs() < 0.05);
+        assert!((variance.sqrt() - 1.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn given_index_should_return_same_value_in_any_order() {
+        let provider = generate_provider("\nrandom_walk: 0.5\nnoise: 1\ndaily_amplitude: 3");
+        let forward: Vec<f64> = (0..100).map(|i| get_value(&provider, i)).collect();
+        let backward: Vec<f64> = (0..100).rev().map(|i| get_value(&provider, i)).collect();
+        assert_eq!(forward, backward.into_iter().rev().collect::<Vec<f64>>());
+        assert_eq!(
+            get_value(&provider, u32::MAX),
+            get_value(&provider.clone(), u32::MAX)
+        );
+    }
+
+    #[test]
+    fn given_seed_should_return_same_values() {
+        crate::rng::initialize_rng(Some(42));
+        let first = generate_provider("\nrandom_walk: 1\nnoise: 1");
+        crate::rng::initialize_rng(Some(42));
+        let second = generate_provider("\nrandom_walk: 1\nnoise: 1");
+        for i in 0..100 {
+            assert_eq!(get_value(&first, i), get_value(&second, i));
+        }
+    }
+
+    #[test]
+    fn given_provider_should_corrupted_return_float() {
+        let provider = generate_provider("\nmin: 0\nmax: 1");
+        let mut count_random = 0;
+        for i in 0..100 {
+            match provider.corrupted_value(i) {
+                Value::Float64(value) => {
+                    if !(0.0..=1.0).contains(&value) {
+                        count_random += 1;
+                    }
+                }
+                _ => panic!("Wrong type"),
+            }
+        }
+        assert!(count_random >= 99);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod builder;
+
+pub mod metric;
+pub mod timestamp;
@@ -0,0 +1,198 @@
+use crate::providers::parameters::get_column_name;
+use crate::providers::parameters::timeline::TimelineParameter;
+use crate::providers::provider::{Provider, Value};
+
+use chrono::{DateTime, Utc};
+use log::warn;
+use std::borrow::Cow;
+use yaml_rust::Yaml;
+
+pub const DEFAULT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+pub const DEFAULT_START: &str = "2024-01-01 00:00:00";
+/// One row per minute
+pub const DEFAULT_STEP: i64 = 60;
+
+/// Parameters taken from the column named by timestamp_column
+const TIMELINE_KEYS: [&str; 3] = ["format", "start", "step"];
+
+const MIN_TIMESTAMP: DateTime<Utc> = DateTime::<Utc>::MIN_UTC;
+const MAX_TIMESTAMP: DateTime<Utc> = DateTime::<Utc>::MAX_UTC;
+
+#[derive(Clone)]
+pub struct TimeseriesTimestampProvider {
+    pub format: String,
+    pub start: i64,
+    pub step: i64,
+}
+
+impl Provider for TimeseriesTimestampProvider {
+    fn value(&self, index: u32) -> Value {
+        let timestamp = self
+            .start
+            .saturating_add((index as i64).saturating_mul(self.step));
+        Value::Timestamp(
+            DateTime::from_timestamp(timestamp, 0).unwrap_or(MAX_TIMESTAMP),
+            self.format.clone(),
+        )
+    }
+    fn corrupted_value(&self, _: u32) -> Value {
+        Value::Timestamp(
+            DateTime::from_timestamp(
+                crate::rng::i64(MIN_TIMESTAMP.timestamp()..MAX_TIMESTAMP.timestamp()),
+                0,
+            )
+            .unwrap(),
+            self.format.clone(),
+        )
+    }
+}
+
+pub fn new_from_yaml(column: &Yaml) -> Box<TimeseriesTimestampProvider> {
+    let timeline = TimelineParameter::new(column, DEFAULT_FORMAT, DEFAULT_START, DEFAULT_STEP);
+
+    Box::new(TimeseriesTimestampProvider {
+        format: timeline.format,
+        start: timeline.start,
+        step: timeline.step,
+    })
+}
+
+/// Column completed with the format, start and step of the column named by its timestamp_column
+/// parameter, so that its values follow the timestamps of this column
+pub fn with_timestamp_column<'a>(column: &'a Yaml, columns: &[Yaml]) -> Cow<'a, Yaml> {
+    let timestamp_column = match &column["timestamp_column"] {
+        Yaml::String(value) => value,
+        _ => return Cow::Borrowed(column),
+    };
+
+    let (mut completed, referenced) = match (
+        column.as_hash(),
+        columns
+            .iter()
+            .find(|other| other["name"].as_str() == Some(timestamp_column.as_str())),
+    ) {
+        (Some(completed), Some(referenced)) => (completed.clone(), referenced),
+        _ => {
+            warn!(
+                "{} column: timestamp_column {} is not a column. It is ignored.",
+                get_column_name(column),
+                timestamp_column
+            );
+            return Cow::Borrowed(column);
+        }
+    };
+
+    for key in TIMELINE_KEYS {
+        let value = &referenced[key];
+        let key = Yaml::String(key.to_string());
+        if !value.is_badvalue() && !completed.contains_key(&key) {
+            completed.insert(key, value.clone());
+        }
+    }
+    Cow::Owned(Yaml::Hash(completed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::provider::{Provider, Value};
+
+    use chrono::NaiveDateTime;
+    use yaml_rust::YamlLoader;
+
+    fn generate_provider(params: &str) -> Box<TimeseriesTimestampProvider> {
+        let yaml_str = format!("name: ts{}", params);
+        let yaml = YamlLoader::load_from_str(yaml_str.as_str()).unwrap();
+        super::new_from_yaml(&yaml[0])
+    }
+
+    fn get_timestamp(provider: &TimeseriesTimestampProvider, index: u32) -> String {
+        match provider.value(index) {
+            Value::Timestamp(value, format) => value.format(&format).to_string(),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    // Parquet type
+    #[test]
+    fn given_nothing_should_return_timestamp_type() {
+        let provider = generate_provider("");
+        match provider.value(0) {
+            Value::Timestamp(_, _) => (),
+            _ => panic!(),
+        }
+    }
+
+    // Validate YAML file
+    #[test]
+    fn given_nothing_should_return_defaults() {
+        let provider = generate_provider("");
+        assert_eq!(provider.format, DEFAULT_FORMAT);
+        assert_eq!(
+            provider.start,
+            NaiveDateTime::parse_from_str(DEFAULT_START, DEFAULT_FORMAT)
+                .unwrap()
+                .and_utc()
+                .timestamp()
+        );
+        assert_eq!(provider.step, DEFAULT_STEP);
+    }
+
+    // Validate value calculation
+    #[test]
+    fn given_start_and_step_should_return_regular_timestamps() {
+        let provider = generate_provider("\nstart: 2024-05-01 10:00:00\nstep: 15m");
+        assert_eq!(get_timestamp(&provider, 0), "2024-05-01 10:00:00");
+        assert_eq!(get_timestamp(&provider, 1), "2024-05-01 10:15:00");
+        assert_eq!(get_timestamp(&provider, 96), "2024-05-02 10:00:00");
+    }
+
+    #[test]
+    fn given_last_index_should_return_timestamp() {
+        let provider = generate_provider("\nstep: 1d");
+        match provider.value(u32::MAX) {
+            Value::Timestamp(_, _) => (),
+            _ => panic!("Wrong type"),
+        }
+    }
+
+    #[test]
+    fn given_provider_should_corrupted_return_timestamps() {
+        let provider = generate_provider("");
+        for i in 0..100 {
+            match provider.corrupted_value(i) {
+                Value::Timestamp(_, _) => (),
+                _ => panic!("Wrong type"),
+            }
+        }
+    }
+
+    // with_timestamp_column
+    fn get_columns(yaml_str: &str) -> Vec<Yaml> {
+        YamlLoader::load_from_str(yaml_str).unwrap()[0]
+            .as_vec()
+            .unwrap()
+            .clone()
+    }
+
+    #[test]
+    fn given_timestamp_column_should_take_its_timeline() {
+        let columns = get_columns(
+            "- name: ts\n  start: 2024-05-01 10:00:00\n  step: 5m\n- name: cpu\n  timestamp_column: ts\n  step: 10m",
+        );
+        let column = with_timestamp_column(&columns[1], &columns);
+        assert_eq!(column["start"].as_str(), Some("2024-05-01 10:00:00"));
+        // parameters of the column are kept
+        assert_eq!(column["step"].as_str(), Some("10m"));
+        assert!(column["format"].is_badvalue());
+    }
+
+    #[test]
+    fn given_no_or_unknown_timestamp_column_should_return_column() {
+        let columns = get_columns(
+            "- name: ts\n  start: 2024-05-01 10:00:00\n- name: cpu\n- name: memory\n  timestamp_column: unknown",
+        );
+        assert_eq!(*with_timestamp_column(&columns[1], &columns), columns[1]);
+        assert_eq!(*with_timestamp_column(&columns[2], &columns), columns[2]);
+    }
+}
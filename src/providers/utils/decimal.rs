@@ -0,0 +1,113 @@
+/// Largest unscaled value of a decimal with the given precision
+pub fn max_unscaled(precision: u8) -> i128 {
+    10_i128.pow(precision as u32) - 1
+}
+
+/// Exact text of an unscaled decimal value, with scale digits after the point
+pub fn format_decimal(value: i128, scale: u8) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let sign = if value < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{}{}", sign, digits);
+    }
+    let digits = format!("{:0>width$}", digits, width = scale as usize + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - scale as usize);
+    format!("{}{}.{}", sign, integer, fraction)
+}
+
+/// Unscaled value of a decimal text, None when it has more than scale fractional digits
+pub fn parse_decimal(value: &str, scale: u8) -> Option<i128> {
+    let (negative, value) = match value.strip_prefix('-') {
+        Some(value) => (true, value),
+        None => (false, value.strip_prefix('+').unwrap_or(value)),
+    };
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if (integer.is_empty() && fraction.is_empty())
+        || fraction.len() > scale as usize
+        || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let digits = format!("{}{:0<width$}", integer, fraction, width = scale as usize);
+    let unscaled: i128 = match digits.is_empty() {
+        true => 0,
+        false => digits.parse().ok()?,
+    };
+    match negative {
+        true => Some(-unscaled),
+        false => Some(unscaled),
+    }
+}
+
+/// Shortest big-endian two's complement bytes of an unscaled value,
+/// as decimals are stored by Avro and Iceberg
+pub fn unscaled_bytes(value: i128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let mut start = 0;
+    // a leading byte can be dropped when it only repeats the sign of the next one
+    while start < bytes.len() - 1
+        && ((bytes[start] == 0x00 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_decimal, max_unscaled, parse_decimal, unscaled_bytes};
+
+    #[test]
+    fn given_precision_should_return_max_unscaled() {
+        assert_eq!(max_unscaled(1), 9);
+        assert_eq!(max_unscaled(5), 99_999);
+        assert_eq!(max_unscaled(38), 10_i128.pow(38) - 1);
+    }
+
+    #[test]
+    fn given_unscaled_value_should_format_it_exactly() {
+        assert_eq!(format_decimal(12345, 2), "123.45");
+        assert_eq!(format_decimal(-5, 2), "-0.05");
+        assert_eq!(format_decimal(0, 3), "0.000");
+        assert_eq!(format_decimal(-42, 0), "-42");
+        assert_eq!(
+            format_decimal(-max_unscaled(38), 10),
+            "-9999999999999999999999999999.9999999999"
+        );
+    }
+
+    #[test]
+    fn given_decimal_text_should_return_unscaled_value() {
+        assert_eq!(parse_decimal("123.45", 2), Some(12345));
+        assert_eq!(parse_decimal("-0.5", 2), Some(-50));
+        assert_eq!(parse_decimal("+7", 2), Some(700));
+        assert_eq!(parse_decimal(".5", 1), Some(5));
+        assert_eq!(parse_decimal("10", 0), Some(10));
+    }
+
+    #[test]
+    fn given_wrong_decimal_text_should_return_none() {
+        assert_eq!(parse_decimal("1.234", 2), None);
+        assert_eq!(parse_decimal("1e5", 2), None);
+        assert_eq!(parse_decimal("abc", 2), None);
+        assert_eq!(parse_decimal("-", 2), None);
+        assert_eq!(parse_decimal(".", 2), None);
+        assert_eq!(parse_decimal("1.2.3", 2), None);
+    }
+
+    #[test]
+    fn given_unscaled_value_should_return_shortest_bytes() {
+        assert_eq!(unscaled_bytes(0), vec![0x00]);
+        assert_eq!(unscaled_bytes(127), vec![0x7f]);
+        assert_eq!(unscaled_bytes(128), vec![0x00, 0x80]);
+        assert_eq!(unscaled_bytes(-1), vec![0xff]);
+        assert_eq!(unscaled_bytes(-128), vec![0x80]);
+        assert_eq!(unscaled_bytes(-129), vec![0xff, 0x7f]);
+        assert_eq!(unscaled_bytes(i128::MIN).len(), 16);
+    }
+}
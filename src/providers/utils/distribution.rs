@@ -0,0 +1,361 @@
+use std::f64::consts::PI;
+
+/// Probability law of the values drawn by numeric providers
+#[derive(Clone, Debug, PartialEq)]
+pub enum Law {
+    Normal {
+        mean: f64,
+        stddev: f64,
+    },
+    /// mean and stddev of the logarithm of the values
+    LogNormal {
+        mean: f64,
+        stddev: f64,
+    },
+    Exponential {
+        rate: f64,
+    },
+    Poisson {
+        lambda: f64,
+    },
+    /// Ranks from 1 to n, the rank k having a weight of 1 / k^exponent
+    Zipf {
+        n: u64,
+        exponent: f64,
+    },
+    Pareto {
+        scale: f64,
+        shape: f64,
+    },
+    /// Values between 0 and 1, scaled to the min and max of the provider
+    Beta {
+        alpha: f64,
+        beta: f64,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Distribution {
+    pub law: Law,
+    /// Values out of the min and max of the provider are brought back to them
+    pub clamp: bool,
+}
+
+impl Distribution {
+    pub fn sample(&self, min: f64, max: f64) -> f64 {
+        let value = match self.law {
+            Law::Normal { mean, stddev } => mean + stddev * crate::rng::with_rng(standard_normal),
+            Law::LogNormal { mean, stddev } => {
+                (mean + stddev * crate::rng::with_rng(standard_normal)).exp()
+            }
+            Law::Exponential { rate } => -(1.0 - crate::rng::f64()).ln() / rate,
+            Law::Poisson { lambda } => poisson(lambda),
+            Law::Zipf { n, exponent } => zipf(n, exponent),
+            Law::Pareto { scale, shape } => scale * (1.0 - crate::rng::f64()).powf(-1.0 / shape),
+            Law::Beta { alpha, beta } => {
+                let x = gamma(alpha);
+                let value = x / (x + gamma(beta));
+                match (max - min).is_finite() {
+                    true => min + value * (max - min),
+                    false => value,
+                }
+            }
+        };
+        match self.clamp {
+            true => value.clamp(min, max),
+            false => value,
+        }
+    }
+}
+
+/// Box-Muller transform
+pub fn standard_normal(rng: &mut fastrand::Rng) -> f64 {
+    let u1 = 1.0 - rng.f64();
+    let u2 = rng.f64();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+/// Marsaglia and Tsang method, boosted for shapes lower than 1
+fn gamma(shape: f64) -> f64 {
+    if shape < 1.0 {
+        let u = 1.0 - crate::rng::f64();
+        return gamma(shape + 1.0) * u.powf(1.0 / shape);
+    }
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / (9.0 * d).sqrt();
+    loop {
+        let z = crate::rng::with_rng(standard_normal);
+        let v = 1.0 + c * z;
+        if v <= 0.0 {
+            continue;
+        }
+        let v = v * v * v;
+        let u = 1.0 - crate::rng::f64();
+        if u < 1.0 - 0.0331 * z.powi(4) || u.ln() < 0.5 * z * z + d * (1.0 - v + v.ln()) {
+            return d * v;
+        }
+    }
+}
+
+/// ln(k!), exact for small k and from the Stirling series otherwise
+fn ln_factorial(k: f64) -> f64 {
+    if k < 10.0 {
+        return (2..=k as u64).map(|i| (i as f64).ln()).sum();
+    }
+    let x = k + 1.0;
+    (x - 0.5) * x.ln() - x + 0.5 * (2.0 * PI).ln() + 1.0 / (12.0 * x) - 1.0 / (360.0 * x.powi(3))
+        + 1.0 / (1260.0 * x.powi(5))
+}
+
+/// Multiplication of uniforms for small lambdas,
+/// transformed rejection with squeeze of Hörmann (PTRS) otherwise
+fn poisson(lambda: f64) -> f64 {
+    if lambda < 30.0 {
+        let limit = (-lambda).exp();
+        let mut k = 0.0;
+        let mut product = crate::rng::f64();
+        while product > limit {
+            k += 1.0;
+            product *= crate::rng::f64();
+        }
+        return k;
+    }
+
+    let sqrt_lambda = lambda.sqrt();
+    let ln_lambda = lambda.ln();
+    let b = 0.931 + 2.53 * sqrt_lambda;
+    let a = -0.059 + 0.02483 * b;
+    let inv_alpha = 1.1239 + 1.1328 / (b - 3.4);
+    let v_r = 0.9277 - 3.6224 / (b - 2.0);
+    loop {
+        let u = crate::rng::f64() - 0.5;
+        let v = crate::rng::f64();
+        let us = 0.5 - u.abs();
+        let k = ((2.0 * a / us + b) * u + lambda + 0.43).floor();
+        if us >= 0.07 && v <= v_r {
+            return k;
+        }
+        if k < 0.0 || (us < 0.013 && v > us) {
+            continue;
+        }
+        if v.ln() + inv_alpha.ln() - (a / (us * us) + b).ln()
+            <= -lambda + k * ln_lambda - ln_factorial(k)
+        {
+            return k;
+        }
+    }
+}
+
+/// Rejection inversion sampling of Hörmann and Derflinger
+fn zipf(n: u64, exponent: f64) -> f64 {
+    // (e^x - 1) / x and ln(1 + x) / x, with their Taylor series near 0
+    let helper_exp = |x: f64| match x.abs() > 1e-8 {
+        true => x.exp_m1() / x,
+        false => 1.0 + x / 2.0 * (1.0 + x / 3.0 * (1.0 + x / 4.0)),
+    };
+    let helper_ln = |x: f64| match x.abs() > 1e-8 {
+        true => x.ln_1p() / x,
+        false => 1.0 - x * (0.5 - x * (1.0 / 3.0 - x / 4.0)),
+    };
+    let h = |x: f64| (-exponent * x.ln()).exp();
+    let h_integral = |x: f64| {
+        let ln_x = x.ln();
+        helper_exp((1.0 - exponent) * ln_x) * ln_x
+    };
+    let h_integral_inverse = |x: f64| {
+        let t = (x * (1.0 - exponent)).max(-1.0);
+        (helper_ln(t) * x).exp()
+    };
+
+    let n = n as f64;
+    let h_integral_x1 = h_integral(1.5) - 1.0;
+    let h_integral_n = h_integral(n + 0.5);
+    let s = 2.0 - h_integral_inverse(h_integral(2.5) - h(2.0));
+    loop {
+        let u = h_integral_n + crate::rng::f64() * (h_integral_x1 - h_integral_n);
+        let x = h_integral_inverse(u);
+        let k = (x + 0.5).floor().clamp(1.0, n);
+        if k - x <= s || u >= h_integral(k + 0.5) - h(k) {
+            return k;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ln_factorial, Distribution, Law};
+
+    fn samples(law: Law, clamp: bool, min: f64, max: f64) -> Vec<f64> {
+        let distribution = Distribution { law, clamp };
+        (0..20_000).map(|_| distribution.sample(min, max)).collect()
+    }
+
+    fn mean(values: &[f64]) -> f64 {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+
+    fn variance(values: &[f64]) -> f64 {
+        let mean = mean(values);
+        values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / values.len() as f64
+    }
+
+    #[test]
+    fn given_normal_should_return_values_of_mean_and_stddev() {
+        let values = samples(
+            Law::Normal {
+                mean: 50.0,
+                stddev: 10.0,
+            },
+            false,
+            0.0,
+            1.0,
+        );
+        assert!((mean(&values) - 50.0).abs() < 0.5);
+        assert!((variance(&values).sqrt() - 10.0).abs() < 0.5);
+    }
+
+    #[test]
+    fn given_log_normal_should_return_positive_values_of_median_exp_mean() {
+        let mut values = samples(
+            Law::LogNormal {
+                mean: 2.0,
+                stddev: 0.5,
+            },
+            false,
+            0.0,
+            1.0,
+        );
+        assert!(values.iter().all(|value| *value > 0.0));
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert!((values[values.len() / 2] - 2.0_f64.exp()).abs() < 0.2);
+    }
+
+    #[test]
+    fn given_exponential_should_return_values_of_mean_inverse_rate() {
+        let values = samples(Law::Exponential { rate: 0.5 }, false, 0.0, 1.0);
+        assert!(values.iter().all(|value| *value >= 0.0));
+        assert!((mean(&values) - 2.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn given_poisson_should_return_integers_of_mean_and_variance_lambda() {
+        for lambda in [4.0, 100.0] {
+            let values = samples(Law::Poisson { lambda }, false, 0.0, 1.0);
+            assert!(values
+                .iter()
+                .all(|value| *value >= 0.0 && value.fract() == 0.0));
+            assert!((mean(&values) - lambda).abs() < lambda * 0.02);
+            assert!((variance(&values) - lambda).abs() < lambda * 0.1);
+        }
+    }
+
+    #[test]
+    fn given_zipf_should_return_ranks_with_decreasing_frequencies() {
+        let values = samples(
+            Law::Zipf {
+                n: 100,
+                exponent: 1.0,
+            },
+            false,
+            0.0,
+            1.0,
+        );
+        assert!(values
+            .iter()
+            .all(|value| (1.0..=100.0).contains(value) && value.fract() == 0.0));
+        let count = |rank: f64| values.iter().filter(|value| **value == rank).count() as f64;
+        // the first rank is twice as frequent as the second one
+        assert!((count(1.0) / count(2.0) - 2.0).abs() < 0.2);
+        assert!(count(1.0) > count(10.0) * 5.0);
+    }
+
+    #[test]
+    fn given_pareto_should_return_values_from_scale_with_few_big_ones() {
+        let mut values = samples(
+            Law::Pareto {
+                scale: 10.0,
+                shape: 1.16,
+            },
+            false,
+            0.0,
+            1.0,
+        );
+        assert!(values.iter().all(|value| *value >= 10.0));
+        values.sort_by(|a, b| b.partial_cmp(a).unwrap());
+        // the biggest 20% of the values hold around 80% of the total
+        let total: f64 = values.iter().sum();
+        let top: f64 = values[..values.len() / 5].iter().sum();
+        assert!(top / total > 0.6);
+    }
+
+    #[test]
+    fn given_beta_should_return_values_scaled_to_min_and_max() {
+        let values = samples(
+            Law::Beta {
+                alpha: 2.0,
+                beta: 6.0,
+            },
+            false,
+            100.0,
+            200.0,
+        );
+        assert!(values.iter().all(|value| (100.0..=200.0).contains(value)));
+        // mean of alpha / (alpha + beta)
+        assert!((mean(&values) - 125.0).abs() < 1.0);
+
+        let values = samples(
+            Law::Beta {
+                alpha: 0.5,
+                beta: 0.5,
+            },
+            false,
+            f64::MIN,
+            f64::MAX,
+        );
+        assert!(values.iter().all(|value| (0.0..=1.0).contains(value)));
+    }
+
+    #[test]
+    fn given_clamp_should_return_values_between_min_and_max() {
+        let values = samples(
+            Law::Normal {
+                mean: 0.0,
+                stddev: 10.0,
+            },
+            true,
+            -5.0,
+            5.0,
+        );
+        assert!(values.iter().all(|value| (-5.0..=5.0).contains(value)));
+        assert!(values.contains(&-5.0));
+        assert!(values.contains(&5.0));
+    }
+
+    #[test]
+    fn given_seed_should_return_same_values() {
+        let distribution = Distribution {
+            law: Law::Zipf {
+                n: 1000,
+                exponent: 1.2,
+            },
+            clamp: false,
+        };
+        crate::rng::initialize_rng(Some(42));
+        let first: Vec<f64> = (0..100).map(|_| distribution.sample(0.0, 1.0)).collect();
+        crate::rng::initialize_rng(Some(42));
+        let second: Vec<f64> = (0..100).map(|_| distribution.sample(0.0, 1.0)).collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn given_k_should_return_ln_factorial() {
+        assert_eq!(ln_factorial(0.0), 0.0);
+        assert!((ln_factorial(5.0) - 120_f64.ln()).abs() < 1e-12);
+        let exact: f64 = (2..=20).map(|i| (i as f64).ln()).sum();
+        assert!((ln_factorial(20.0) - exact).abs() < 1e-10);
+    }
+}
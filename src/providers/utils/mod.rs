@@ -1 +1,3 @@
+pub mod decimal;
+pub mod distribution;
 pub mod string;
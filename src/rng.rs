@@ -51,6 +51,21 @@ pub fn i64(range: std::ops::Range<i64>) -> i64 {
     with_rng(|rng| rng.i64(range))
 }
 
+/// Generate a random i128 in the given inclusive range
+pub fn i128(range: std::ops::RangeInclusive<i128>) -> i128 {
+    with_rng(|rng| rng.i128(range))
+}
+
+/// Generate a random u128
+pub fn u128() -> u128 {
+    with_rng(|rng| rng.u128(..))
+}
+
+/// Generate a random u64
+pub fn u64() -> u64 {
+    with_rng(|rng| rng.u64(..))
+}
+
 /// Generate a random u32 in the given range
 pub fn u32(range: std::ops::Range<u32>) -> u32 {
     with_rng(|rng| rng.u32(range))
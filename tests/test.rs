@@ -25,6 +25,14 @@ mod tests {
         fs::remove_dir_all("target/test_generated").ok();
         fs::remove_file("output.csv").ok();
         fs::remove_file("output.json").ok();
+        fs::remove_file("output.avro").ok();
+        fs::remove_file("output.orc").ok();
+        fs::remove_file("output.arrow").ok();
+        fs::remove_file("output.sql").ok();
+        fs::remove_file("output.copy").ok();
+        fs::remove_file("output.xml").ok();
+        fs::remove_file("output.txt").ok();
+        fs::remove_file("output.xlsx").ok();
         fs::remove_file("target/csv_deterministic_test.csv").ok();
         fs::remove_file("target/csv_deterministic_test_2.csv").ok();
         fs::remove_file("target/csv_no_seed_test.csv").ok();
@@ -69,7 +77,7 @@ mod tests {
             .assert()
             .failure()
             .stderr(predicate::str::contains(format!(
-                "Usage: {} generate <PATH_TO_CONFIG>",
+                "Usage: {} generate [OPTIONS] <PATH_TO_CONFIG>",
                 FAKELAKE_COMMAND_NAME
             )));
 
@@ -171,6 +179,133 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn given_generate_partitioned_file_should_write_partition_directories(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/partitioned_parquet.yaml"))
+            .assert()
+            .success();
+
+        for country in ["FR", "US"] {
+            let path = format!(
+                "target/test_generated/partitioned/country={}/part-00000.parquet",
+                country
+            );
+            assert!(Path::new(&path).exists(), "Partition file was not created");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_delta_table_should_write_transaction_log(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        // an existing table is not overwritten
+        fs::remove_dir_all("target/test_generated/delta_table").ok();
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/delta_table.yaml"))
+            .assert()
+            .success();
+
+        let log = fs::read_to_string(
+            "target/test_generated/delta_table/_delta_log/00000000000000000000.json",
+        )?;
+        assert!(
+            log.contains("decimal(10,2)"),
+            "Decimal type is not in the log"
+        );
+        for country in ["FR", "US"] {
+            let path = format!("country={}/part-00000.parquet", country);
+            assert!(log.contains(&path), "Partition file is not in the log");
+            assert!(Path::new("target/test_generated/delta_table")
+                .join(&path)
+                .exists());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_iceberg_table_should_write_metadata() -> Result<(), Box<dyn std::error::Error>>
+    {
+        // an existing table is not overwritten
+        fs::remove_dir_all("target/test_generated/iceberg_table").ok();
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/iceberg_table.yaml"))
+            .assert()
+            .success();
+
+        let table = Path::new("target/test_generated/iceberg_table");
+        assert_eq!(
+            fs::read_to_string(table.join("metadata/version-hint.text"))?,
+            "1"
+        );
+        let metadata = fs::read_to_string(table.join("metadata/v1.metadata.json"))?;
+        assert!(metadata.contains("\"transform\":\"identity\""));
+        for country in ["FR", "US"] {
+            let path = table.join(format!("data/country={}/part-00000.parquet", country));
+            assert!(path.exists(), "Partition file was not created");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_split_file_should_write_multiple_files(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/split_parquet.yaml"))
+            .assert()
+            .success();
+
+        for index in 0..3 {
+            let path = format!("target/test_generated/split-{:05}.parquet", index);
+            assert!(Path::new(&path).exists(), "Split file was not created");
+        }
+        assert!(!Path::new("target/test_generated/split-00003.parquet").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_parquet_with_writer_options_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/parquet_writer_options.yaml"))
+            .assert()
+            .success();
+
+        assert!(Path::new("target/test_generated/parquet_writer_options.parquet").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_parquet_with_uuid_columns_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/parquet_uuid.yaml"))
+            .assert()
+            .success();
+
+        assert!(Path::new("target/test_generated/parquet_uuid.parquet").exists());
+
+        Ok(())
+    }
+
     #[test]
     fn given_generate_one_json_file_with_verbose_should_succeed(
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -185,6 +320,256 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn given_generate_one_compressed_json_file_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/one_row_json_gzip.yaml"))
+            .assert()
+            .success();
+
+        assert!(Path::new("target/test_generated/one_row_gzip.json.gz").exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_stdout_flag_should_write_to_stdout() -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg("--stdout")
+            .arg(Path::new("tests/one_row_csv.yaml"))
+            .assert()
+            .success()
+            .stdout(predicate::eq("id\n0\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_csv_dialect_should_write_dialect() -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg("--stdout")
+            .arg(Path::new("tests/csv_dialect.yaml"))
+            .assert()
+            .success()
+            .stdout(predicate::eq(
+                "0,NULL\r\n1,'it\\'s'\r\n2,NULL\r\n3,'it\\'s'\r\n",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_timeseries_should_write_metrics_of_timestamps(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg("--stdout")
+            .arg(Path::new("tests/timeseries_csv.yaml"))
+            .assert()
+            .success()
+            .stdout(predicate::str::starts_with(
+                "ts,requests,cpu\n2024-05-01 10:00:00,110,",
+            ))
+            .stdout(predicate::str::contains("\n2024-05-01 11:00:00,110.659"))
+            .stdout(predicate::str::contains("\n2024-05-01 12:00:00,110.660"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_dotted_names_should_write_nested_json() -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/nested_json.yaml"))
+            .assert()
+            .success()
+            .stdout(predicate::eq(
+                "{\"address\":{\"city\":\"Paris\",\"zip_code\":\"75001\"},\"id\":0}\n",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_dash_output_name_should_write_to_stdout() -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg(Path::new("tests/one_row_stdout.yaml"))
+            .assert()
+            .success()
+            .stdout(predicate::eq("{\"id\":0}\n{\"id\":1}\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_avro_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_avro.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_orc_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_orc.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_arrow_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_arrow.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_sql_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_sql.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_pg_copy_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_pg_copy.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_xml_file_should_write_elements_and_attributes(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg("--stdout")
+            .arg(Path::new("tests/one_row_xml.yaml"))
+            .assert()
+            .success()
+            .stdout(predicate::eq(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<users>\n  <user id=\"0\">\n    <name>Tom &amp; Jerry</name>\n  </user>\n</users>\n",
+            ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_fixed_width_file_should_pad_and_truncate(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("generate")
+            .arg("--stdout")
+            .arg(Path::new("tests/one_row_fixed_width.yaml"))
+            .assert()
+            .success()
+            .stdout(predicate::eq("00000Fakela\n00001Fakela\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_xlsx_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_xlsx.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_sqlite_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_sqlite.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_protobuf_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_protobuf.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
+    #[test]
+    fn given_generate_one_msgpack_file_with_verbose_should_succeed(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut cmd = Command::cargo_bin("fakelake")?;
+
+        cmd.arg("-v")
+            .arg("generate")
+            .arg(Path::new("tests/one_row_msgpack.yaml"))
+            .assert()
+            .success();
+
+        Ok(())
+    }
+
     #[test]
     fn given_same_seed_should_generate_identical_output() -> Result<(), Box<dyn std::error::Error>>
     {